@@ -0,0 +1,563 @@
+//! Command handlers for the change lifecycle subcommands.
+//!
+//! Provides `run_init`, which scaffolds a new change from the change templates,
+//! `run_approve`, which records a tamper-evident approval, `run_archive`,
+//! which merges a change's deltas into the specs and moves the change folder
+//! into the archive, and `run_conflicts`, which reports requirements touched by
+//! more than one active change.
+
+use std::path::Path;
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::cli::output::{print_json, OutputFormat};
+use crate::config::Config;
+use crate::core::approval::{approve_change, timestamp_now, Approval};
+use crate::core::archive::{archive_change, today, ArchiveResult, SpecUpdate};
+use crate::core::change::{init_change, ChangeInitOptions, ChangeScaffold};
+use crate::core::conflict::{find_conflicts, Conflict};
+use crate::error::{Error, Result};
+
+/// JSON output of the `change init` command.
+#[derive(Debug, Serialize)]
+struct InitResponse {
+    /// The change ID.
+    change_id: String,
+    /// The created change directory.
+    change_dir: String,
+    /// The created files, relative to the change directory.
+    files: Vec<String>,
+}
+
+/// JSON output of the `change approve` command.
+#[derive(Debug, Serialize)]
+struct ApproveResponse {
+    /// The change ID.
+    change_id: String,
+    /// The recorded approval.
+    #[serde(flatten)]
+    approval: Approval,
+}
+
+/// JSON output of the `change archive` command.
+#[derive(Debug, Serialize)]
+struct ArchiveResponse {
+    /// The change ID.
+    change_id: String,
+    /// The folder the change was moved to.
+    archive_path: String,
+    /// The specs updated by the change.
+    updates: Vec<SpecUpdateJson>,
+}
+
+/// A spec updated by an archive in JSON output.
+#[derive(Debug, Serialize)]
+struct SpecUpdateJson {
+    /// The capability name.
+    capability: String,
+    /// Whether the spec was created by the archive.
+    created: bool,
+    /// Number of requirements added.
+    added: usize,
+    /// Number of requirements replaced.
+    modified: usize,
+    /// Number of requirements removed.
+    removed: usize,
+    /// Number of requirements renamed.
+    renamed: usize,
+}
+
+impl From<&SpecUpdate> for SpecUpdateJson {
+    fn from(update: &SpecUpdate) -> Self {
+        Self {
+            capability: update.capability.clone(),
+            created: update.created,
+            added: update.added,
+            modified: update.modified,
+            removed: update.removed,
+            renamed: update.renamed,
+        }
+    }
+}
+
+/// JSON output of the `change conflicts` command.
+#[derive(Debug, Serialize)]
+struct ConflictsResponse {
+    /// Requirements touched by more than one active change.
+    conflicts: Vec<ConflictJson>,
+}
+
+/// A conflicting requirement in JSON output.
+#[derive(Debug, Serialize)]
+struct ConflictJson {
+    /// The capability the requirement belongs to.
+    capability: String,
+    /// The requirement name.
+    requirement: String,
+    /// The changes touching the requirement.
+    changes: Vec<ConflictTouchJson>,
+}
+
+/// A change's operation on a conflicting requirement in JSON output.
+#[derive(Debug, Serialize)]
+struct ConflictTouchJson {
+    /// The change ID.
+    change_id: String,
+    /// The operation: "added", "modified", "removed" or "renamed".
+    operation: String,
+    /// The requirement name as written in the delta.
+    name: String,
+}
+
+impl From<&Conflict> for ConflictJson {
+    fn from(conflict: &Conflict) -> Self {
+        Self {
+            capability: conflict.capability.clone(),
+            requirement: conflict.requirement.clone(),
+            changes: conflict
+                .touches
+                .iter()
+                .map(|t| ConflictTouchJson {
+                    change_id: t.change_id.clone(),
+                    operation: t.operation.label().to_lowercase(),
+                    name: t.name.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Format the result of scaffolding a change for display.
+///
+/// # Arguments
+/// * `id` - The change ID
+/// * `scaffold` - The scaffold result from Core
+///
+/// # Returns
+/// A formatted string ready for terminal display (no ANSI colors).
+///
+/// # Example Output
+/// ```text
+/// Created change add-2fa in specs/_changes/add-2fa
+/// - proposal.md
+/// - tasks.md
+/// - specs/auth/spec.md
+/// ```
+fn format_scaffold(id: &str, scaffold: &ChangeScaffold) -> String {
+    let mut output = format!(
+        "Created change {} in {}\n",
+        id,
+        scaffold.change_dir.display()
+    );
+    for file in &scaffold.files {
+        output.push_str(&format!("- {}\n", file));
+    }
+    output.trim_end().to_string()
+}
+
+/// Format the operation counts of a spec update, e.g. `+1 ~2 -1 >1`.
+fn format_update_counts(update: &SpecUpdate) -> String {
+    let mut parts = Vec::new();
+    if update.added > 0 {
+        parts.push(format!("+{}", update.added));
+    }
+    if update.modified > 0 {
+        parts.push(format!("~{}", update.modified));
+    }
+    if update.removed > 0 {
+        parts.push(format!("-{}", update.removed));
+    }
+    if update.renamed > 0 {
+        parts.push(format!(">{}", update.renamed));
+    }
+    parts.join(" ")
+}
+
+/// Format the result of an archive for display.
+///
+/// # Arguments
+/// * `result` - The archive result from Core
+/// * `archive_folder` - The configured archive folder (for display)
+///
+/// # Returns
+/// A formatted string ready for terminal display (no ANSI colors).
+///
+/// # Example Output
+/// ```text
+/// Archived add-2fa to specs/_archive/2025-01-15-add-2fa
+///
+/// Specs updated:
+/// - auth  +1 ~1
+/// - mfa   +2 (new)
+///
+/// Run 'spox index' to refresh the search index.
+/// ```
+fn format_archive_result(result: &ArchiveResult, archive_folder: &str) -> String {
+    let archive_path = Path::new(archive_folder).join(&result.archive_name);
+    let mut output = format!(
+        "Archived {} to {}\n",
+        result.change_id,
+        archive_path.display()
+    );
+
+    if result.updates.is_empty() {
+        output.push_str("\nNo spec deltas to apply.\n");
+        return output.trim_end().to_string();
+    }
+
+    output.push_str("\nSpecs updated:\n");
+    let max_name_len = result
+        .updates
+        .iter()
+        .map(|u| u.capability.len())
+        .max()
+        .unwrap_or(0);
+
+    for update in &result.updates {
+        let created = if update.created { " (new)" } else { "" };
+        output.push_str(&format!(
+            "- {:<width$}  {}{}\n",
+            update.capability,
+            format_update_counts(update),
+            created,
+            width = max_name_len
+        ));
+    }
+
+    output.push_str("\nRun 'spox index' to refresh the search index.");
+    output
+}
+
+/// Format the conflicts between active changes for display.
+///
+/// # Arguments
+/// * `conflicts` - The conflicts from Core
+///
+/// # Returns
+/// A formatted string ready for terminal display (no ANSI colors).
+///
+/// # Example Output
+/// ```text
+/// Conflicts:
+/// - auth: User Login
+///   - add-2fa       MODIFIED
+///   - remove-login  REMOVED
+/// ```
+/// Or if empty: `No conflicts between active changes.`
+fn format_conflicts(conflicts: &[Conflict]) -> String {
+    if conflicts.is_empty() {
+        return "No conflicts between active changes.".to_string();
+    }
+
+    let mut output = String::from("Conflicts:\n");
+    for conflict in conflicts {
+        output.push_str(&format!(
+            "- {}: {}\n",
+            conflict.capability, conflict.requirement
+        ));
+
+        let max_id_len = conflict
+            .touches
+            .iter()
+            .map(|t| t.change_id.len())
+            .max()
+            .unwrap_or(0);
+        for touch in &conflict.touches {
+            let renamed = if touch.name != conflict.requirement {
+                format!(" ({})", touch.name)
+            } else {
+                String::new()
+            };
+            output.push_str(&format!(
+                "  - {:<width$}  {}{}\n",
+                touch.change_id,
+                touch.operation.label(),
+                renamed,
+                width = max_id_len
+            ));
+        }
+    }
+
+    output.trim_end().to_string()
+}
+
+/// Run the `change init` command.
+///
+/// Creates `<changes_folder>/<id>/` from the templates in `.spox/templates/change/`.
+///
+/// # Arguments
+/// * `id` - The change ID to create
+/// * `capabilities` - Capabilities to create delta spec stubs for
+/// * `design` - Whether to also create design.md
+/// * `format` - Output format; JSON prints the change directory and files
+///
+/// # Returns
+/// Returns `Ok(())` on success, or an error if the change cannot be created.
+pub fn run_init(
+    id: String,
+    capabilities: Vec<String>,
+    design: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let config_path = Path::new(".spox/config.toml");
+    let config = Config::load(config_path)?;
+
+    let options = ChangeInitOptions {
+        capabilities,
+        design,
+    };
+    let scaffold = init_change(Path::new(""), &config, &id, &options)
+        .map_err(|e| Error::Other(e.to_string()))?;
+
+    if format.is_json() {
+        return print_json(&InitResponse {
+            change_id: id,
+            change_dir: scaffold.change_dir.display().to_string(),
+            files: scaffold.files,
+        });
+    }
+    println!("{}", format_scaffold(&id, &scaffold));
+
+    Ok(())
+}
+
+/// Determine the approver when `--by` is not given.
+///
+/// Uses `git config user.name`, falling back to the `USER`/`USERNAME` variables.
+fn default_approver() -> Option<String> {
+    let from_git = Command::new("git")
+        .args(["config", "user.name"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+    from_git
+        .into_iter()
+        .chain(std::env::var("USER").ok())
+        .chain(std::env::var("USERNAME").ok())
+        .find(|name| !name.is_empty())
+}
+
+/// Run the `change approve` command.
+///
+/// Writes `approval.toml` into the change folder with the approver, a timestamp
+/// and content hashes of proposal.md and all delta specs.
+///
+/// # Arguments
+/// * `id` - The change ID to approve
+/// * `by` - The approver name (auto-detected if not given)
+/// * `format` - Output format; JSON prints the approval record
+///
+/// # Returns
+/// Returns `Ok(())` on success, or an error if the change cannot be approved.
+pub fn run_approve(id: String, by: Option<String>, format: OutputFormat) -> Result<()> {
+    let config_path = Path::new(".spox/config.toml");
+    let config = Config::load(config_path)?;
+
+    let approver = by.or_else(default_approver).ok_or_else(|| {
+        Error::Other("Could not determine approver; pass --by <name>".to_string())
+    })?;
+
+    let approval = approve_change(Path::new(""), &config, &id, &approver, &timestamp_now())
+        .map_err(|e| Error::Other(e.to_string()))?;
+
+    if format.is_json() {
+        return print_json(&ApproveResponse {
+            change_id: id,
+            approval,
+        });
+    }
+    println!(
+        "Approved {} by {} at {}\nContent hash: {}",
+        id, approval.approver, approval.approved_at, approval.content_hash
+    );
+
+    Ok(())
+}
+
+/// Run the `change archive` command.
+///
+/// Applies the change's delta specs to the specs in the spec folder and moves
+/// the change to `<archive_folder>/YYYY-MM-DD-<id>`.
+///
+/// # Arguments
+/// * `id` - The change ID to archive
+/// * `force` - Archive even if validation fails or tasks are incomplete
+/// * `format` - Output format; JSON prints the archive path and spec updates
+///
+/// # Returns
+/// Returns `Ok(())` on success, or an error if the change cannot be archived.
+pub fn run_archive(id: String, force: bool, format: OutputFormat) -> Result<()> {
+    let config_path = Path::new(".spox/config.toml");
+    let config = Config::load(config_path)?;
+
+    let result = archive_change(Path::new(""), &config, &id, &today(), force)
+        .map_err(|e| Error::Other(e.to_string()))?;
+
+    if format.is_json() {
+        return print_json(&ArchiveResponse {
+            archive_path: Path::new(config.archive_folder())
+                .join(&result.archive_name)
+                .display()
+                .to_string(),
+            change_id: result.change_id,
+            updates: result.updates.iter().map(SpecUpdateJson::from).collect(),
+        });
+    }
+    println!(
+        "{}",
+        format_archive_result(&result, config.archive_folder())
+    );
+
+    Ok(())
+}
+
+/// Run the `change conflicts` command.
+///
+/// Reports requirements that more than one active change adds, modifies,
+/// removes or renames, and exits with code 1 if there are any.
+///
+/// # Arguments
+/// * `format` - Output format; JSON prints a `{ "conflicts": [...] }` object
+///
+/// # Returns
+/// Returns `Ok(())` if there are no conflicts, or an error if the changes
+/// cannot be read.
+pub fn run_conflicts(format: OutputFormat) -> Result<()> {
+    let config_path = Path::new(".spox/config.toml");
+    let config = Config::load(config_path)?;
+
+    let conflicts = find_conflicts(Path::new(config.changes_folder()))
+        .map_err(|e| Error::Other(e.to_string()))?;
+
+    if format.is_json() {
+        print_json(&ConflictsResponse {
+            conflicts: conflicts.iter().map(ConflictJson::from).collect(),
+        })?;
+    } else {
+        println!("{}", format_conflicts(&conflicts));
+    }
+
+    if !conflicts.is_empty() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::conflict::RequirementTouch;
+    use crate::core::markdown::DeltaOp;
+
+    // ==================== Unit Tests for format_scaffold ====================
+
+    #[test]
+    fn test_format_scaffold_lists_files() {
+        let scaffold = ChangeScaffold {
+            change_dir: std::path::PathBuf::from("specs/_changes/add-2fa"),
+            files: vec!["proposal.md".to_string(), "specs/auth/spec.md".to_string()],
+        };
+
+        let output = format_scaffold("add-2fa", &scaffold);
+
+        assert_eq!(
+            output,
+            "Created change add-2fa in specs/_changes/add-2fa\n- proposal.md\n- specs/auth/spec.md"
+        );
+    }
+
+    // ==================== Unit Tests for format_archive_result ====================
+
+    #[test]
+    fn test_format_archive_result_lists_updates() {
+        let result = ArchiveResult {
+            change_id: "add-2fa".to_string(),
+            archive_name: "2025-01-15-add-2fa".to_string(),
+            updates: vec![
+                SpecUpdate {
+                    capability: "auth".to_string(),
+                    added: 1,
+                    modified: 1,
+                    ..Default::default()
+                },
+                SpecUpdate {
+                    capability: "mfa".to_string(),
+                    created: true,
+                    added: 2,
+                    ..Default::default()
+                },
+            ],
+        };
+
+        let output = format_archive_result(&result, "specs/_archive");
+
+        assert!(output.starts_with("Archived add-2fa to specs/_archive/2025-01-15-add-2fa"));
+        assert!(output.contains("- auth  +1 ~1\n"));
+        assert!(output.contains("- mfa   +2 (new)\n"));
+        assert!(output.ends_with("Run 'spox index' to refresh the search index."));
+    }
+
+    #[test]
+    fn test_format_archive_result_without_deltas() {
+        let result = ArchiveResult {
+            change_id: "chore".to_string(),
+            archive_name: "2025-01-15-chore".to_string(),
+            updates: vec![],
+        };
+
+        let output = format_archive_result(&result, "specs/_archive");
+
+        assert!(output.contains("No spec deltas to apply."));
+        assert!(!output.contains("spox index"));
+    }
+
+    #[test]
+    fn test_format_update_counts_all_operations() {
+        let update = SpecUpdate {
+            capability: "auth".to_string(),
+            created: false,
+            added: 1,
+            modified: 2,
+            removed: 3,
+            renamed: 4,
+        };
+
+        assert_eq!(format_update_counts(&update), "+1 ~2 -3 >4");
+    }
+
+    // ==================== Unit Tests for format_conflicts ====================
+
+    #[test]
+    fn test_format_conflicts_empty() {
+        assert_eq!(
+            format_conflicts(&[]),
+            "No conflicts between active changes."
+        );
+    }
+
+    #[test]
+    fn test_format_conflicts_lists_changes() {
+        let touch = |change_id: &str, operation, name: &str| RequirementTouch {
+            change_id: change_id.to_string(),
+            operation,
+            name: name.to_string(),
+        };
+        let conflicts = vec![Conflict {
+            capability: "auth".to_string(),
+            requirement: "User Login".to_string(),
+            touches: vec![
+                touch("add-2fa", DeltaOp::Modified, "User Login"),
+                touch("rename", DeltaOp::Renamed, "User Login -> Sign In"),
+            ],
+        }];
+
+        assert_eq!(
+            format_conflicts(&conflicts),
+            "Conflicts:\n- auth: User Login\n  - add-2fa  MODIFIED\n  - rename   RENAMED (User Login -> Sign In)"
+        );
+    }
+}
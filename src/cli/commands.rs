@@ -6,12 +6,19 @@
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
+use super::output::{OutputFormat, ReportOutput};
+use crate::core::index::SearchMode;
+
 /// Spec-driven development for humans and AI
 #[derive(Parser, Debug)]
 #[command(name = "spox")]
 #[command(version)]
 #[command(about = "Spec-driven development for humans and AI")]
 pub struct Cli {
+    /// Output format (json prints the MCP response structures)
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -26,7 +33,11 @@ pub enum Commands {
     },
 
     /// Show project dashboard (human-friendly)
-    Show,
+    Show {
+        /// Redraw the dashboard when files change, until interrupted
+        #[arg(long)]
+        watch: bool,
+    },
 
     /// Manage specs
     #[command(subcommand)]
@@ -36,16 +47,56 @@ pub enum Commands {
     #[command(subcommand)]
     Change(ChangeCommands),
 
+    /// Manage the tasks of changes
+    #[command(subcommand)]
+    Task(TaskCommands),
+
     /// Manage configuration
     #[command(subcommand)]
     Config(ConfigCommands),
 
     /// Build semantic search index
-    Index,
+    Index {
+        /// Check the index without building it; exit non-zero if it is missing or stale
+        #[arg(long)]
+        check: bool,
+    },
+
+    /// Search specs and changes by meaning and keywords
+    Search {
+        /// The search query
+        query: String,
+
+        /// Number of results to show
+        #[arg(long, default_value_t = 10)]
+        top_k: usize,
+
+        /// Only show results of this spec (repeatable)
+        #[arg(long = "spec", value_name = "ID")]
+        spec_ids: Vec<String>,
+
+        /// Ranking: semantic, keyword or hybrid
+        #[arg(long, default_value_t = SearchMode::Hybrid)]
+        mode: SearchMode,
+
+        /// Print JSON (same as --format json)
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Format specs and delta specs to the spec template layout
+    Fmt {
+        /// Check formatting without writing; exit non-zero if files need formatting
+        #[arg(long)]
+        check: bool,
+    },
 
     /// MCP server operations
     #[command(subcommand)]
     Mcp(McpCommands),
+
+    /// Start the language server over stdio (for editors)
+    Lsp,
 }
 
 #[derive(Subcommand, Debug)]
@@ -67,6 +118,14 @@ pub enum SpecCommands {
         /// Strict mode - warnings also cause failure
         #[arg(long)]
         strict: bool,
+
+        /// Print a SARIF or JUnit XML report instead of text
+        #[arg(long, value_enum)]
+        output: Option<ReportOutput>,
+
+        /// Re-validate when files change, until interrupted
+        #[arg(long, conflicts_with = "output")]
+        watch: bool,
     },
 }
 
@@ -76,6 +135,14 @@ pub enum ChangeCommands {
     Init {
         /// Change ID (required)
         id: String,
+
+        /// Create a delta spec stub for this capability (repeatable)
+        #[arg(long = "capability", value_name = "CAPABILITY")]
+        capabilities: Vec<String>,
+
+        /// Also create design.md
+        #[arg(long)]
+        design: bool,
     },
 
     /// List all changes
@@ -99,10 +166,50 @@ pub enum ChangeCommands {
         /// Strict mode - warnings also cause failure
         #[arg(long)]
         strict: bool,
+
+        /// Print a SARIF or JUnit XML report instead of text
+        #[arg(long, value_enum)]
+        output: Option<ReportOutput>,
+
+        /// Re-validate when files change, until interrupted
+        #[arg(long, conflicts_with = "output")]
+        watch: bool,
+    },
+
+    /// Approve a change and record a content hash of its proposal and deltas
+    Approve {
+        /// Change ID to approve
+        id: String,
+
+        /// Approver name (defaults to git user.name, then $USER)
+        #[arg(long)]
+        by: Option<String>,
+    },
+
+    /// Report requirements changed by more than one active change
+    Conflicts,
+
+    /// Archive a change and apply its deltas to the specs
+    Archive {
+        /// Change ID to archive
+        id: String,
+
+        /// Archive even if validation fails or tasks are incomplete
+        #[arg(long)]
+        force: bool,
     },
+}
 
-    /// Approve a change
-    Approve,
+#[derive(Subcommand, Debug)]
+pub enum TaskCommands {
+    /// Check off a task in the tasks.md of a change
+    Done {
+        /// Change ID
+        change: String,
+
+        /// Task number, e.g. 1.2 or 1.2.1
+        number: String,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -4,12 +4,17 @@
 //!
 //! - `commands.rs` - Argument parsing with clap
 //! - `render.rs` - Terminal output formatting (colors, boxes, progress bars)
+//! - `output.rs` - Output format selection (`--format text|json`, `--output sarif|junit`)
 //!
 //! The CLI layer delegates to Core for business logic and formats output
 //! for the terminal.
 
 pub mod commands;
+pub mod output;
 pub mod render;
 
 // Re-export key types for convenient access from main
-pub use commands::{ChangeCommands, Cli, Commands, ConfigCommands, McpCommands, SpecCommands};
+pub use commands::{
+    ChangeCommands, Cli, Commands, ConfigCommands, McpCommands, SpecCommands, TaskCommands,
+};
+pub use output::OutputFormat;
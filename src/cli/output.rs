@@ -0,0 +1,88 @@
+//! Output format selection for CLI commands.
+//!
+//! Commands print hand-formatted text by default. With `--format json` they
+//! print the same response structures the MCP server returns, so scripts get a
+//! stable schema instead of screen-scraping terminal output. The validate
+//! commands can also print SARIF or JUnit XML reports with `--output`.
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+
+/// Output format of a CLI command.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// Pretty-printed JSON
+    Json,
+}
+
+impl OutputFormat {
+    /// Whether JSON output was requested.
+    pub fn is_json(self) -> bool {
+        self == OutputFormat::Json
+    }
+}
+
+/// Report format of the validate commands for CI integrations.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportOutput {
+    /// SARIF 2.1.0 log for code review annotations
+    Sarif,
+    /// JUnit XML with one testcase per spec or change
+    Junit,
+}
+
+/// Serialize a value as pretty-printed JSON.
+///
+/// # Errors
+///
+/// Returns `Error::Other` if the value cannot be serialized.
+pub fn to_json<T: Serialize>(value: &T) -> Result<String> {
+    serde_json::to_string_pretty(value)
+        .map_err(|e| Error::Other(format!("Failed to serialize output: {}", e)))
+}
+
+/// Print a value as pretty-printed JSON to stdout.
+///
+/// # Errors
+///
+/// Returns `Error::Other` if the value cannot be serialized.
+pub fn print_json<T: Serialize>(value: &T) -> Result<()> {
+    println!("{}", to_json(value)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Sample {
+        id: String,
+        count: usize,
+    }
+
+    #[test]
+    fn test_output_format_defaults_to_text() {
+        assert_eq!(OutputFormat::default(), OutputFormat::Text);
+        assert!(!OutputFormat::Text.is_json());
+        assert!(OutputFormat::Json.is_json());
+    }
+
+    #[test]
+    fn test_to_json_is_pretty_printed() {
+        let sample = Sample {
+            id: "auth".to_string(),
+            count: 2,
+        };
+
+        assert_eq!(
+            to_json(&sample).unwrap(),
+            "{\n  \"id\": \"auth\",\n  \"count\": 2\n}"
+        );
+    }
+}
@@ -78,11 +78,7 @@ pub fn dim(text: &str) -> String {
 /// A 20-character wide progress bar using block characters.
 pub fn progress_bar(completed: usize, total: usize) -> String {
     let width = 20;
-    let filled = if total > 0 {
-        (completed * width) / total
-    } else {
-        0
-    };
+    let filled = (completed * width).checked_div(total).unwrap_or(0);
     let empty = width - filled;
     format!("{}{}", "\u{2588}".repeat(filled), "\u{2591}".repeat(empty))
 }
@@ -97,11 +93,7 @@ pub fn progress_bar(completed: usize, total: usize) -> String {
 /// A 20-character wide progress bar with green filled and dim empty.
 pub fn colored_progress_bar(completed: usize, total: usize) -> String {
     let width = 20;
-    let filled = if total > 0 {
-        (completed * width) / total
-    } else {
-        0
-    };
+    let filled = (completed * width).checked_div(total).unwrap_or(0);
     let empty = width - filled;
 
     let filled_part = "\u{2588}".repeat(filled);
@@ -1,7 +1,7 @@
 use std::fs;
 use std::path::Path;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::error::{Error, Result};
 
@@ -11,7 +11,7 @@ const VALID_SYSTEM_TEMPLATES: &[&str] = &[
 ];
 
 /// Paths configuration section.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PathsConfig {
     /// Path to the specs folder (e.g., "specs/").
     pub spec_folder: String,
@@ -22,7 +22,7 @@ pub struct PathsConfig {
 }
 
 /// Rules configuration section.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RulesConfig {
     /// System templates to include (e.g., ["mcp", "global", "coding"]).
     pub system: Vec<String>,
@@ -31,15 +31,51 @@ pub struct RulesConfig {
     pub custom: Vec<String>,
 }
 
+/// Embedding backend for the semantic search index.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingBackend {
+    /// Neural embeddings from fastembed (the model is downloaded on first use).
+    #[default]
+    Fastembed,
+    /// Dependency-free hashed term-frequency vectors that work offline.
+    Hashing,
+}
+
+impl EmbeddingBackend {
+    /// The backend name as written in the config file.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EmbeddingBackend::Fastembed => "fastembed",
+            EmbeddingBackend::Hashing => "hashing",
+        }
+    }
+}
+
+/// Search configuration section (optional).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SearchConfig {
+    /// Embedding backend (defaults to fastembed).
+    #[serde(default)]
+    pub backend: EmbeddingBackend,
+    /// Directory with a local ONNX model and tokenizer files for the fastembed
+    /// backend, relative to the project root (downloads the default model if unset).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_path: Option<String>,
+}
+
 /// Configuration for Spec Oxide.
 ///
 /// Loaded from `.spox/config.toml`.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Config {
     /// Paths configuration.
     pub paths: PathsConfig,
     /// Rules configuration.
     pub rules: RulesConfig,
+    /// Search configuration (defaults apply when the section is missing).
+    #[serde(default)]
+    pub search: SearchConfig,
 }
 
 impl Config {
@@ -116,6 +152,16 @@ impl Config {
     pub fn archive_folder(&self) -> &str {
         &self.paths.archive_folder
     }
+
+    /// Get the embedding backend of the search index.
+    pub fn embedding_backend(&self) -> EmbeddingBackend {
+        self.search.backend
+    }
+
+    /// Get the local embedding model directory, if configured.
+    pub fn model_path(&self) -> Option<&str> {
+        self.search.model_path.as_deref()
+    }
 }
 
 #[cfg(test)]
@@ -229,6 +275,103 @@ system = ["mcp"]
         assert!(config.rules.custom.is_empty());
     }
 
+    // ==================== Tests for [search] section ====================
+
+    #[test]
+    fn test_load_config_search_defaults_to_fastembed() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+[paths]
+spec_folder = "specs/"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#
+        )
+        .unwrap();
+
+        let config = Config::load(file.path()).unwrap();
+        assert_eq!(config.embedding_backend(), EmbeddingBackend::Fastembed);
+    }
+
+    #[test]
+    fn test_load_config_search_backend() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+[paths]
+spec_folder = "specs/"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+
+[search]
+backend = "hashing"
+"#
+        )
+        .unwrap();
+
+        let config = Config::load(file.path()).unwrap();
+        assert_eq!(config.embedding_backend(), EmbeddingBackend::Hashing);
+        assert_eq!(config.model_path(), None);
+    }
+
+    #[test]
+    fn test_load_config_search_model_path() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+[paths]
+spec_folder = "specs/"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+
+[search]
+model_path = "models/all-MiniLM-L6-v2"
+"#
+        )
+        .unwrap();
+
+        let config = Config::load(file.path()).unwrap();
+        assert_eq!(config.embedding_backend(), EmbeddingBackend::Fastembed);
+        assert_eq!(config.model_path(), Some("models/all-MiniLM-L6-v2"));
+    }
+
+    #[test]
+    fn test_load_config_invalid_search_backend() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+[paths]
+spec_folder = "specs/"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+
+[search]
+backend = "openai"
+"#
+        )
+        .unwrap();
+
+        let result = Config::load(file.path());
+        assert!(matches!(result, Err(Error::ConfigParse(_))));
+    }
+
     // ==================== Tests for validation errors ====================
 
     #[test]
@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use crate::cli::output::{print_json, OutputFormat};
 use crate::config::Config;
 use crate::error::Result;
 
@@ -7,11 +8,12 @@ use crate::error::Result;
 ///
 /// Loads and displays the current project configuration from `.spox/config.toml`.
 /// If `paths_only` is true, only displays the `[paths]` section.
+/// With JSON output, prints the configuration (or the paths section) as an object.
 ///
 /// # Errors
 ///
 /// Returns an error if the configuration file does not exist or cannot be parsed.
-pub fn run_show(paths_only: bool) -> Result<()> {
+pub fn run_show(paths_only: bool, format: OutputFormat) -> Result<()> {
     let config_path = Path::new(".spox/config.toml");
     let config = Config::load(config_path)?;
 
@@ -26,6 +28,14 @@ pub fn run_show(paths_only: bool) -> Result<()> {
         return Err(e);
     }
 
+    if format.is_json() {
+        return if paths_only {
+            print_json(&config.paths)
+        } else {
+            print_json(&config)
+        };
+    }
+
     if paths_only {
         // Display paths section only
         println!("[paths]");
@@ -66,6 +76,12 @@ pub fn run_show(paths_only: bool) -> Result<()> {
                     .join(", ")
             );
         }
+        println!();
+        println!("[search]");
+        println!("  backend = \"{}\"", config.embedding_backend().as_str());
+        if let Some(model_path) = config.model_path() {
+            println!("  model_path = \"{}\"", model_path);
+        }
     }
 
     Ok(())
@@ -88,7 +104,7 @@ mod tests {
         env::set_current_dir(temp_dir.path()).unwrap();
 
         // Run the command - should fail since no config exists
-        let result = run_show(false);
+        let result = run_show(false, OutputFormat::Text);
 
         // Restore original directory before assertions to avoid leaving cwd in temp dir
         let restore_result = env::set_current_dir(&original_dir);
@@ -120,7 +136,7 @@ system = ["mcp"]
         env::set_current_dir(temp_dir.path()).unwrap();
 
         // Run the command - should succeed
-        let result = run_show(false);
+        let result = run_show(false, OutputFormat::Text);
 
         // Restore original directory before assertions to avoid leaving cwd in temp dir
         let restore_result = env::set_current_dir(&original_dir);
@@ -153,7 +169,7 @@ custom = ["my-rules.md"]
         env::set_current_dir(temp_dir.path()).unwrap();
 
         // Run the command with paths_only=true - should succeed
-        let result = run_show(true);
+        let result = run_show(true, OutputFormat::Text);
 
         // Restore original directory before assertions to avoid leaving cwd in temp dir
         let restore_result = env::set_current_dir(&original_dir);
@@ -186,7 +202,7 @@ custom = ["project-rules.md", "team-rules.md"]
         env::set_current_dir(temp_dir.path()).unwrap();
 
         // Run the command - should succeed
-        let result = run_show(false);
+        let result = run_show(false, OutputFormat::Text);
 
         // Restore original directory before assertions to avoid leaving cwd in temp dir
         let restore_result = env::set_current_dir(&original_dir);
@@ -218,7 +234,7 @@ system = []
         env::set_current_dir(temp_dir.path()).unwrap();
 
         // Run the command - should fail due to validation
-        let result = run_show(false);
+        let result = run_show(false, OutputFormat::Text);
 
         // Restore original directory before any assertions to avoid leaving cwd in temp dir
         let restore_result = env::set_current_dir(&original_dir);
@@ -250,7 +266,7 @@ system = ["mcp", "invalid-template"]
         env::set_current_dir(temp_dir.path()).unwrap();
 
         // Run the command - should fail due to validation
-        let result = run_show(false);
+        let result = run_show(false, OutputFormat::Text);
 
         // Restore original directory before any assertions to avoid leaving cwd in temp dir
         let restore_result = env::set_current_dir(&original_dir);
@@ -0,0 +1,467 @@
+//! Change approval records for Spec Oxide Core.
+//!
+//! Approving a change writes `approval.toml` into the change folder. The record
+//! holds the approver, a timestamp, and SHA-256 hashes of proposal.md and every
+//! delta spec, so any edit made after approval can be detected.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::error::{Error, Result};
+use crate::config::Config;
+use crate::validate::change::validate_change_content;
+
+/// Name of the approval record inside a change folder.
+pub const APPROVAL_FILE: &str = "approval.toml";
+
+/// A recorded approval of a change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Approval {
+    /// Who approved the change.
+    pub approver: String,
+    /// When the change was approved (RFC 3339, UTC).
+    pub approved_at: String,
+    /// SHA-256 over all approved files and their hashes.
+    pub content_hash: String,
+    /// SHA-256 of each approved file, keyed by path relative to the change folder.
+    pub files: BTreeMap<String, String>,
+}
+
+/// Approval state of a change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApprovalStatus {
+    /// No approval record exists.
+    #[default]
+    Unapproved,
+    /// The approval record matches the current files.
+    Approved,
+    /// Approved files were edited after approval (or the record is unreadable).
+    Stale,
+}
+
+impl ApprovalStatus {
+    /// Human-readable label for list and dashboard output.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ApprovalStatus::Unapproved => "unapproved",
+            ApprovalStatus::Approved => "approved",
+            ApprovalStatus::Stale => "approval stale",
+        }
+    }
+}
+
+/// Approve a change and write its approval record.
+///
+/// # Arguments
+///
+/// * `project_root` - The root directory of the project
+/// * `config` - The loaded configuration with folder paths
+/// * `change_id` - The change ID (folder name in the changes folder)
+/// * `approver` - Who approves the change
+/// * `approved_at` - The approval timestamp (RFC 3339)
+///
+/// # Errors
+///
+/// Returns an error if the change does not exist, fails validation, or the
+/// approval record cannot be written.
+pub fn approve_change(
+    project_root: &Path,
+    config: &Config,
+    change_id: &str,
+    approver: &str,
+    approved_at: &str,
+) -> Result<Approval> {
+    let change_dir = project_root.join(config.changes_folder()).join(change_id);
+    if !change_dir.is_dir() {
+        return Err(Error::Other(format!("Change '{}' not found", change_id)));
+    }
+
+    let spec_folder = project_root.join(config.spec_folder());
+    record_approval(&change_dir, &spec_folder, approver, approved_at)
+}
+
+/// Validate a change directory and write its approval record.
+///
+/// Deltas are cross-checked against the specs in `spec_folder`. An existing
+/// record is replaced. If validation fails, the existing record is kept unchanged.
+///
+/// # Errors
+///
+/// Returns an error if the change fails validation or the record cannot be written.
+pub fn record_approval(
+    change_dir: &Path,
+    spec_folder: &Path,
+    approver: &str,
+    approved_at: &str,
+) -> Result<Approval> {
+    // Ignore the previous record so a stale approval can be renewed
+    let validation = validate_change_content(change_dir, spec_folder);
+    if !validation.report.is_valid() {
+        return Err(Error::Other(format!(
+            "Change failed validation with {} error(s); fix them before approving",
+            validation.report.errors
+        )));
+    }
+
+    let files = hash_approved_files(change_dir)?;
+    let approval = Approval {
+        approver: approver.to_string(),
+        approved_at: approved_at.to_string(),
+        content_hash: combined_hash(approver, approved_at, &files),
+        files,
+    };
+
+    let content = toml::to_string(&approval)
+        .map_err(|e| Error::Other(format!("Failed to serialize approval: {}", e)))?;
+    fs::write(change_dir.join(APPROVAL_FILE), content)
+        .map_err(|e| Error::Other(format!("Failed to write {}: {}", APPROVAL_FILE, e)))?;
+
+    Ok(approval)
+}
+
+/// Load the approval record of a change, if any.
+///
+/// # Errors
+///
+/// Returns an error if the record exists but cannot be read or parsed.
+pub fn load_approval(change_dir: &Path) -> Result<Option<Approval>> {
+    let path = change_dir.join(APPROVAL_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| Error::Other(format!("Failed to read {}: {}", APPROVAL_FILE, e)))?;
+    let approval = toml::from_str(&content)
+        .map_err(|e| Error::Other(format!("Invalid {}: {}", APPROVAL_FILE, e)))?;
+
+    Ok(Some(approval))
+}
+
+/// List the approved files whose content differs from the approval record.
+///
+/// Files that were added or deleted since approval are reported as well.
+///
+/// # Returns
+///
+/// Paths relative to the change folder, sorted; empty if nothing changed.
+///
+/// # Errors
+///
+/// Returns an error if the record itself was edited (its content hash does not
+/// match its file hashes) or the files cannot be read.
+pub fn changed_since_approval(change_dir: &Path, approval: &Approval) -> Result<Vec<String>> {
+    if approval.content_hash
+        != combined_hash(&approval.approver, &approval.approved_at, &approval.files)
+    {
+        return Err(Error::Other(format!(
+            "{} was edited: content hash does not match the approved files",
+            APPROVAL_FILE
+        )));
+    }
+
+    let current = hash_approved_files(change_dir)?;
+
+    let mut changed: Vec<String> = current
+        .iter()
+        .filter(|(path, hash)| approval.files.get(*path) != Some(hash))
+        .map(|(path, _)| path.clone())
+        .chain(
+            approval
+                .files
+                .keys()
+                .filter(|path| !current.contains_key(*path))
+                .cloned(),
+        )
+        .collect();
+    changed.sort();
+
+    Ok(changed)
+}
+
+/// Determine the approval state of a change.
+pub fn approval_status(change_dir: &Path) -> ApprovalStatus {
+    match load_approval(change_dir) {
+        Ok(None) => ApprovalStatus::Unapproved,
+        Ok(Some(approval)) => match changed_since_approval(change_dir, &approval) {
+            Ok(changed) if changed.is_empty() => ApprovalStatus::Approved,
+            _ => ApprovalStatus::Stale,
+        },
+        Err(_) => ApprovalStatus::Stale,
+    }
+}
+
+/// Current UTC time in RFC 3339 format, used as the approval timestamp.
+pub fn timestamp_now() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// Hash proposal.md and every `specs/<capability>/spec.md` of a change.
+fn hash_approved_files(change_dir: &Path) -> Result<BTreeMap<String, String>> {
+    let mut files = BTreeMap::new();
+
+    let proposal = change_dir.join("proposal.md");
+    if proposal.exists() {
+        files.insert("proposal.md".to_string(), hash_file(&proposal)?);
+    }
+
+    let specs_dir = change_dir.join("specs");
+    if let Ok(entries) = fs::read_dir(&specs_dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let spec_path = entry.path().join("spec.md");
+            if spec_path.is_file() {
+                let capability = entry.file_name().to_string_lossy().to_string();
+                files.insert(
+                    format!("specs/{}/spec.md", capability),
+                    hash_file(&spec_path)?,
+                );
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Combine the approver, the approval time and per-file hashes into a single content hash.
+fn combined_hash(approver: &str, approved_at: &str, files: &BTreeMap<String, String>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(approver.as_bytes());
+    hasher.update([0]);
+    hasher.update(approved_at.as_bytes());
+    hasher.update([b'\n']);
+    for (path, hash) in files {
+        hasher.update(path.as_bytes());
+        hasher.update([0]);
+        hasher.update(hash.as_bytes());
+        hasher.update([b'\n']);
+    }
+    to_hex(&hasher.finalize())
+}
+
+fn hash_file(path: &Path) -> Result<String> {
+    let content = fs::read(path)
+        .map_err(|e| Error::Other(format!("Failed to read {}: {}", path.display(), e)))?;
+    Ok(to_hex(&Sha256::digest(&content)))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const PROPOSAL: &str = "# Change: Add 2FA\n\n## Why\n\nUsers need a second factor to protect their accounts from credential theft.\n\n## What Changes\n\n- Add two-factor authentication\n";
+
+    const DELTA: &str = "## ADDED Requirements\n\n### Requirement: Two-Factor Auth\n\nThe system SHALL require a one-time code.\n\n#### Scenario: Code accepted\n\n- **WHEN** user enters a valid code\n- **THEN** user is authenticated\n";
+
+    fn create_project() -> (TempDir, Config) {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("specs/auth")).unwrap();
+        fs::write(
+            temp_dir.path().join("specs/auth/spec.md"),
+            "# auth Specification\n\n## Purpose\nAuthentication.\n\n## Requirements\n",
+        )
+        .unwrap();
+
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+        fs::create_dir_all(change_dir.join("specs/auth")).unwrap();
+        fs::write(change_dir.join("proposal.md"), PROPOSAL).unwrap();
+        fs::write(
+            change_dir.join("tasks.md"),
+            "# Tasks\n- [ ] 1.1 Implement\n",
+        )
+        .unwrap();
+        fs::write(change_dir.join("specs/auth/spec.md"), DELTA).unwrap();
+
+        let config: Config = toml::from_str(
+            r#"
+[paths]
+spec_folder = "specs/"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#,
+        )
+        .unwrap();
+
+        (temp_dir, config)
+    }
+
+    // ==================== approve_change tests ====================
+
+    #[test]
+    fn test_approve_change_writes_record() {
+        let (temp_dir, config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+
+        let approval = approve_change(
+            temp_dir.path(),
+            &config,
+            "add-2fa",
+            "Jane Doe",
+            "2025-01-15T10:30:00Z",
+        )
+        .unwrap();
+
+        assert_eq!(approval.approver, "Jane Doe");
+        assert_eq!(
+            approval.files.keys().collect::<Vec<_>>(),
+            vec!["proposal.md", "specs/auth/spec.md"]
+        );
+        assert_eq!(approval.content_hash.len(), 64);
+        assert_eq!(load_approval(&change_dir).unwrap(), Some(approval));
+        assert_eq!(approval_status(&change_dir), ApprovalStatus::Approved);
+    }
+
+    #[test]
+    fn test_approve_change_rejects_invalid_change() {
+        let (temp_dir, config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+        fs::remove_file(change_dir.join("tasks.md")).unwrap();
+
+        let result = approve_change(temp_dir.path(), &config, "add-2fa", "Jane", "now");
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("failed validation"));
+        assert!(!change_dir.join(APPROVAL_FILE).exists());
+    }
+
+    #[test]
+    fn test_failed_reapproval_keeps_previous_record() {
+        let (temp_dir, config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+        let approval = approve_change(temp_dir.path(), &config, "add-2fa", "Jane", "now").unwrap();
+        fs::remove_file(change_dir.join("tasks.md")).unwrap();
+
+        let result = approve_change(temp_dir.path(), &config, "add-2fa", "John", "later");
+
+        assert!(result.is_err());
+        assert_eq!(load_approval(&change_dir).unwrap(), Some(approval));
+    }
+
+    #[test]
+    fn test_approve_change_not_found() {
+        let (temp_dir, config) = create_project();
+
+        let result = approve_change(temp_dir.path(), &config, "missing", "Jane", "now");
+
+        assert!(result.unwrap_err().to_string().contains("not found"));
+    }
+
+    // ==================== approval_status tests ====================
+
+    #[test]
+    fn test_status_unapproved_without_record() {
+        let (temp_dir, _config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+
+        assert_eq!(approval_status(&change_dir), ApprovalStatus::Unapproved);
+    }
+
+    #[test]
+    fn test_edit_after_approval_is_detected() {
+        let (temp_dir, config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+        let approval = approve_change(temp_dir.path(), &config, "add-2fa", "Jane", "now").unwrap();
+
+        fs::write(
+            change_dir.join("specs/auth/spec.md"),
+            DELTA.replace("one-time code", "hardware key"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            changed_since_approval(&change_dir, &approval).unwrap(),
+            vec!["specs/auth/spec.md"]
+        );
+        assert_eq!(approval_status(&change_dir), ApprovalStatus::Stale);
+    }
+
+    #[test]
+    fn test_added_and_removed_delta_specs_are_detected() {
+        let (temp_dir, config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+        let approval = approve_change(temp_dir.path(), &config, "add-2fa", "Jane", "now").unwrap();
+
+        fs::remove_dir_all(change_dir.join("specs/auth")).unwrap();
+        fs::create_dir_all(change_dir.join("specs/mfa")).unwrap();
+        fs::write(change_dir.join("specs/mfa/spec.md"), DELTA).unwrap();
+
+        assert_eq!(
+            changed_since_approval(&change_dir, &approval).unwrap(),
+            vec!["specs/auth/spec.md", "specs/mfa/spec.md"]
+        );
+    }
+
+    #[test]
+    fn test_tasks_edits_do_not_invalidate_approval() {
+        let (temp_dir, config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+        approve_change(temp_dir.path(), &config, "add-2fa", "Jane", "now").unwrap();
+
+        fs::write(
+            change_dir.join("tasks.md"),
+            "# Tasks\n- [x] 1.1 Implement\n",
+        )
+        .unwrap();
+
+        assert_eq!(approval_status(&change_dir), ApprovalStatus::Approved);
+    }
+
+    #[test]
+    fn test_tampered_record_is_stale() {
+        let (temp_dir, config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+        let mut approval =
+            approve_change(temp_dir.path(), &config, "add-2fa", "Jane", "now").unwrap();
+
+        approval.content_hash = "0".repeat(64);
+        fs::write(
+            change_dir.join(APPROVAL_FILE),
+            toml::to_string(&approval).unwrap(),
+        )
+        .unwrap();
+
+        assert!(changed_since_approval(&change_dir, &approval).is_err());
+        assert_eq!(approval_status(&change_dir), ApprovalStatus::Stale);
+    }
+
+    #[test]
+    fn test_edited_approver_is_stale() {
+        let (temp_dir, config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+        let mut approval =
+            approve_change(temp_dir.path(), &config, "add-2fa", "Jane", "now").unwrap();
+
+        approval.approver = "Mallory".to_string();
+        fs::write(
+            change_dir.join(APPROVAL_FILE),
+            toml::to_string(&approval).unwrap(),
+        )
+        .unwrap();
+
+        assert!(changed_since_approval(&change_dir, &approval).is_err());
+        assert_eq!(approval_status(&change_dir), ApprovalStatus::Stale);
+    }
+
+    #[test]
+    fn test_unreadable_record_is_stale() {
+        let (temp_dir, _config) = create_project();
+        let change_dir = temp_dir.path().join("specs/_changes/add-2fa");
+        fs::write(change_dir.join(APPROVAL_FILE), "not = [valid").unwrap();
+
+        assert!(load_approval(&change_dir).is_err());
+        assert_eq!(approval_status(&change_dir), ApprovalStatus::Stale);
+    }
+}
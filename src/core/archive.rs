@@ -0,0 +1,736 @@
+//! Change archiving for Spec Oxide Core.
+//!
+//! This module applies the delta specs of a change (ADDED, MODIFIED, REMOVED and
+//! RENAMED requirements) to the source-of-truth specs and moves the change folder
+//! into the archive.
+//!
+//! Deltas are merged at the level of requirement blocks: a requirement that is not
+//! touched by a delta is written back exactly as it was read, so archiving never
+//! reformats unrelated parts of a spec.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::error::{Error, Result};
+use super::format::render_spec;
+use super::markdown::{Document, SectionKind};
+use super::spec::ParsedSpec;
+use crate::config::Config;
+use crate::show::change::{parse_delta_specs, DeltaItem, DeltaOp};
+use crate::validate::change::validate_change_against_specs;
+
+/// Counts of the delta operations applied to a single spec.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecUpdate {
+    /// The capability name (spec folder name).
+    pub capability: String,
+    /// Whether the spec did not exist before and was created by the archive.
+    pub created: bool,
+    /// Number of requirements added.
+    pub added: usize,
+    /// Number of requirements replaced.
+    pub modified: usize,
+    /// Number of requirements removed.
+    pub removed: usize,
+    /// Number of requirements renamed.
+    pub renamed: usize,
+}
+
+/// The outcome of archiving a change.
+#[derive(Debug, Clone)]
+pub struct ArchiveResult {
+    /// The archived change ID.
+    pub change_id: String,
+    /// The folder name inside the archive folder (`YYYY-MM-DD-<id>`).
+    pub archive_name: String,
+    /// The specs updated by the change, sorted by capability.
+    pub updates: Vec<SpecUpdate>,
+}
+
+/// Archive a change: apply its deltas to the specs and move it to the archive.
+///
+/// All deltas are merged in memory first; specs are only written once every
+/// capability merged cleanly, and the change folder is only moved after the
+/// specs have been written.
+///
+/// # Arguments
+///
+/// * `project_root` - The root directory of the project
+/// * `config` - The loaded configuration with folder paths
+/// * `change_id` - The change ID (folder name in the changes folder)
+/// * `date` - The archive date in `YYYY-MM-DD` format
+/// * `force` - Archive even if validation fails or tasks are incomplete
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The change does not exist or the archive target already exists
+/// - The change fails validation or has incomplete tasks (unless `force` is set)
+/// - A delta cannot be applied to its target spec
+/// - Files cannot be read, written or moved
+pub fn archive_change(
+    project_root: &Path,
+    config: &Config,
+    change_id: &str,
+    date: &str,
+    force: bool,
+) -> Result<ArchiveResult> {
+    let change_dir = project_root.join(config.changes_folder()).join(change_id);
+    if !change_dir.is_dir() {
+        return Err(Error::Other(format!("Change '{}' not found", change_id)));
+    }
+
+    let archive_name = format!("{}-{}", date, change_id);
+    let archive_dir = project_root.join(config.archive_folder());
+    let archive_path = archive_dir.join(&archive_name);
+    if archive_path.exists() {
+        return Err(Error::Other(format!(
+            "Archive target already exists: {}",
+            archive_path.display()
+        )));
+    }
+
+    if !force {
+        check_ready(
+            &change_dir,
+            &project_root.join(config.spec_folder()),
+            change_id,
+        )?;
+    }
+
+    // Merge every delta group before touching any file
+    let specs_dir = change_dir.join("specs");
+    let groups = if specs_dir.is_dir() {
+        parse_delta_specs(&specs_dir).map_err(Error::Other)?
+    } else {
+        Vec::new()
+    };
+
+    let spec_folder = project_root.join(config.spec_folder());
+    let mut writes: Vec<(PathBuf, String)> = Vec::new();
+    let mut updates = Vec::new();
+
+    for group in &groups {
+        let spec_path = spec_folder.join(&group.capability).join("spec.md");
+        let created = !spec_path.exists();
+        let content = if created {
+            new_spec_content(&group.capability, change_id)
+        } else {
+            fs::read_to_string(&spec_path).map_err(|e| {
+                Error::Other(format!("Failed to read {}: {}", spec_path.display(), e))
+            })?
+        };
+
+        let (merged, mut update) = apply_deltas(&content, &group.items)
+            .map_err(|e| Error::Other(format!("{}: {}", group.capability, e)))?;
+        update.capability = group.capability.clone();
+        update.created = created;
+
+        writes.push((spec_path, merged));
+        updates.push(update);
+    }
+
+    for (path, content) in &writes {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                Error::Other(format!("Failed to create {}: {}", parent.display(), e))
+            })?;
+        }
+        fs::write(path, content)
+            .map_err(|e| Error::Other(format!("Failed to write {}: {}", path.display(), e)))?;
+    }
+
+    fs::create_dir_all(&archive_dir)
+        .map_err(|e| Error::Other(format!("Failed to create archive folder: {}", e)))?;
+    fs::rename(&change_dir, &archive_path)
+        .map_err(|e| Error::Other(format!("Failed to move change to archive: {}", e)))?;
+
+    Ok(ArchiveResult {
+        change_id: change_id.to_string(),
+        archive_name,
+        updates,
+    })
+}
+
+/// Apply delta items to spec content and return the merged content.
+///
+/// Operations are applied in the order RENAMED, REMOVED, MODIFIED, ADDED, so a
+/// MODIFIED delta refers to a requirement by its new name after a rename.
+///
+/// # Arguments
+///
+/// * `content` - The current content of the target spec.md
+/// * `items` - The delta items for this capability
+///
+/// # Returns
+///
+/// The merged spec content and the counts of applied operations.
+///
+/// # Errors
+///
+/// Returns an error if a delta conflicts with the target spec, e.g. a MODIFIED or
+/// REMOVED requirement does not exist, or an ADDED requirement already exists.
+pub fn apply_deltas(content: &str, items: &[DeltaItem]) -> Result<(String, SpecUpdate)> {
+    let mut doc = SpecDocument::parse(content)?;
+    let mut update = SpecUpdate::default();
+
+    for item in items.iter().filter(|i| i.operation == DeltaOp::Renamed) {
+        let (from, to) = parse_rename(&item.name).ok_or_else(|| {
+            Error::Other(format!(
+                "RENAMED requirement \"{}\" must use the form \"Old Name -> New Name\"",
+                item.name
+            ))
+        })?;
+        let idx = doc.find(from).ok_or_else(|| {
+            Error::Other(format!("RENAMED requirement \"{}\" does not exist", from))
+        })?;
+        if doc.find(to).is_some() {
+            return Err(Error::Other(format!(
+                "Cannot rename \"{}\": requirement \"{}\" already exists",
+                from, to
+            )));
+        }
+        doc.requirements[idx].rename(to);
+        update.renamed += 1;
+    }
+
+    for item in items.iter().filter(|i| i.operation == DeltaOp::Removed) {
+        let idx = doc.find(&item.name).ok_or_else(|| {
+            Error::Other(format!(
+                "REMOVED requirement \"{}\" does not exist",
+                item.name
+            ))
+        })?;
+        doc.requirements.remove(idx);
+        update.removed += 1;
+    }
+
+    for item in items.iter().filter(|i| i.operation == DeltaOp::Modified) {
+        let idx = doc.find(&item.name).ok_or_else(|| {
+            Error::Other(format!(
+                "MODIFIED requirement \"{}\" does not exist",
+                item.name
+            ))
+        })?;
+        doc.requirements[idx] = RequirementBlock::from_delta(item);
+        update.modified += 1;
+    }
+
+    for item in items.iter().filter(|i| i.operation == DeltaOp::Added) {
+        if doc.find(&item.name).is_some() {
+            return Err(Error::Other(format!(
+                "ADDED requirement \"{}\" already exists",
+                item.name
+            )));
+        }
+        doc.requirements.push(RequirementBlock::from_delta(item));
+        update.added += 1;
+    }
+
+    Ok((doc.render(), update))
+}
+
+/// Split a RENAMED requirement name (`Old -> New` or `Old → New`) into its parts.
+pub fn parse_rename(name: &str) -> Option<(&str, &str)> {
+    let (from, to) = name.split_once("->").or_else(|| name.split_once('→'))?;
+    let (from, to) = (from.trim(), to.trim());
+    if from.is_empty() || to.is_empty() {
+        None
+    } else {
+        Some((from, to))
+    }
+}
+
+/// Today's date in `YYYY-MM-DD` format, used to name archive folders.
+pub fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// Refuse to archive a change that is invalid or has open tasks.
+fn check_ready(change_dir: &Path, spec_folder: &Path, change_id: &str) -> Result<()> {
+    let validation = validate_change_against_specs(change_dir, spec_folder);
+
+    if !validation.report.is_valid() {
+        return Err(Error::Other(format!(
+            "Change '{}' failed validation with {} error(s); run 'spox change validate {}' or use --force",
+            change_id, validation.report.errors, change_id
+        )));
+    }
+
+    if let Some(stats) = validation.task_stats {
+        if stats.completed < stats.total {
+            return Err(Error::Other(format!(
+                "Change '{}' has incomplete tasks ({}/{} completed); complete them or use --force",
+                change_id, stats.completed, stats.total
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Skeleton for a spec created by archiving a change for a new capability.
+fn new_spec_content(capability: &str, change_id: &str) -> String {
+    render_spec(&ParsedSpec {
+        id: capability.to_string(),
+        title: format!("{} Specification", capability),
+        purpose: format!(
+            "TBD - created by archiving change {}. Update Purpose after archive.",
+            change_id
+        ),
+        requirements: Vec::new(),
+    })
+}
+
+// =============================================================================
+// Requirement block model
+// =============================================================================
+
+/// A spec split into the parts that surround its requirement blocks.
+#[derive(Debug)]
+struct SpecDocument {
+    /// Everything up to and including the `## Requirements` section intro.
+    head: Vec<String>,
+    /// The requirement blocks in document order.
+    requirements: Vec<RequirementBlock>,
+    /// Everything from the first level-2 header after the requirements.
+    tail: Vec<String>,
+}
+
+/// A `### Requirement:` block with its raw lines (header included).
+#[derive(Debug)]
+struct RequirementBlock {
+    name: String,
+    lines: Vec<String>,
+}
+
+impl RequirementBlock {
+    fn from_delta(item: &DeltaItem) -> Self {
+        Self {
+            name: item.name.clone(),
+            lines: item.content.lines().map(|l| l.to_string()).collect(),
+        }
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+        if let Some(header) = self.lines.first_mut() {
+            *header = format!("### Requirement: {}", name);
+        }
+    }
+}
+
+impl SpecDocument {
+    fn parse(content: &str) -> Result<Self> {
+        let lines: Vec<&str> = content.lines().collect();
+        let doc = Document::parse(content);
+
+        let section = doc.section(SectionKind::Requirements).ok_or_else(|| {
+            Error::Other("Target spec is missing a '## Requirements' section".to_string())
+        })?;
+
+        // Line indexes (0-indexed) where each part starts
+        let section_start = section.heading.span.start.line - 1;
+        let tail_start = doc
+            .headings
+            .iter()
+            .find(|h| h.level <= 2 && h.span.start.line - 1 > section_start)
+            .map_or(lines.len(), |h| h.span.start.line - 1);
+        let starts: Vec<usize> = section
+            .requirements
+            .iter()
+            .map(|r| r.span.start.line - 1)
+            .collect();
+
+        let to_strings = |range: &[&str]| range.iter().map(|l| l.to_string()).collect();
+        let head_end = starts.first().copied().unwrap_or(tail_start);
+
+        let requirements = section
+            .requirements
+            .iter()
+            .enumerate()
+            .map(|(i, req)| {
+                let end = starts.get(i + 1).copied().unwrap_or(tail_start);
+                RequirementBlock {
+                    name: req.name.clone(),
+                    lines: to_strings(&lines[starts[i]..end]),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            head: to_strings(&lines[..head_end]),
+            requirements,
+            tail: to_strings(&lines[tail_start..]),
+        })
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.requirements.iter().position(|r| r.name == name.trim())
+    }
+
+    fn render(&self) -> String {
+        let mut parts: Vec<String> = vec![join_trimmed(&self.head)];
+        parts.extend(self.requirements.iter().map(|r| join_trimmed(&r.lines)));
+        if !self.tail.is_empty() {
+            parts.push(join_trimmed(&self.tail));
+        }
+
+        let mut output = parts.join("\n\n");
+        output.push('\n');
+        output
+    }
+}
+
+/// Join lines, dropping trailing blank lines.
+fn join_trimmed(lines: &[String]) -> String {
+    let end = lines
+        .iter()
+        .rposition(|l| !l.trim().is_empty())
+        .map_or(0, |i| i + 1);
+    lines[..end].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const TARGET_SPEC: &str = r#"# Auth Specification
+
+## Purpose
+
+This spec defines authentication requirements for the system.
+
+## Requirements
+
+### Requirement: User Login
+
+The system SHALL allow users to login with email and password.
+
+#### Scenario: Successful login
+
+- **WHEN** user provides valid email and password
+- **THEN** user is authenticated
+- **AND** session token is returned
+
+### Requirement: User Logout
+
+The system SHALL allow users to logout.
+
+#### Scenario: Logout clears session
+
+- **WHEN** authenticated user requests logout
+- **THEN** session is invalidated
+"#;
+
+    fn item(operation: DeltaOp, name: &str, content: &str) -> DeltaItem {
+        DeltaItem {
+            operation,
+            name: name.to_string(),
+            text: String::new(),
+            scenarios: Vec::new(),
+            content: content.to_string(),
+        }
+    }
+
+    fn create_project(tasks: &str, delta: &str) -> (TempDir, Config) {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        let spec_dir = root.join("specs/auth");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(spec_dir.join("spec.md"), TARGET_SPEC).unwrap();
+
+        let change_dir = root.join("specs/_changes/add-2fa");
+        fs::create_dir_all(change_dir.join("specs/auth")).unwrap();
+        fs::write(
+            change_dir.join("proposal.md"),
+            "# Change: Add 2FA\n\n## Why\n\nUsers need a second factor to protect their accounts from credential theft.\n\n## What Changes\n\n- Add two-factor authentication\n",
+        )
+        .unwrap();
+        fs::write(change_dir.join("tasks.md"), tasks).unwrap();
+        fs::write(change_dir.join("specs/auth/spec.md"), delta).unwrap();
+
+        let config: Config = toml::from_str(
+            r#"
+[paths]
+spec_folder = "specs/"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#,
+        )
+        .unwrap();
+
+        (temp_dir, config)
+    }
+
+    const ADDED_DELTA: &str = r#"## ADDED Requirements
+
+### Requirement: Two-Factor Auth
+
+The system SHALL require a one-time code after password login.
+
+#### Scenario: Code accepted
+
+- **WHEN** user enters a valid code
+- **THEN** user is authenticated
+"#;
+
+    // ==================== apply_deltas tests ====================
+
+    #[test]
+    fn test_apply_added_appends_requirement() {
+        let added = item(
+            DeltaOp::Added,
+            "Two-Factor Auth",
+            "### Requirement: Two-Factor Auth\n\nThe system SHALL require 2FA.",
+        );
+
+        let (merged, update) = apply_deltas(TARGET_SPEC, &[added]).unwrap();
+
+        assert_eq!(update.added, 1);
+        assert!(
+            merged.ends_with("### Requirement: Two-Factor Auth\n\nThe system SHALL require 2FA.\n")
+        );
+        assert!(merged.contains("### Requirement: User Logout"));
+    }
+
+    #[test]
+    fn test_apply_added_rejects_existing_requirement() {
+        let added = item(
+            DeltaOp::Added,
+            "User Login",
+            "### Requirement: User Login\n\nDuplicate.",
+        );
+
+        let result = apply_deltas(TARGET_SPEC, &[added]);
+
+        assert!(result.unwrap_err().to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_apply_modified_replaces_block_verbatim() {
+        let modified = item(
+            DeltaOp::Modified,
+            "User Login",
+            "### Requirement: User Login\n\nThe system SHALL allow login with email or username.\n\n#### Scenario: Username login\n\n- **WHEN** user provides a username\n- **THEN** user is authenticated",
+        );
+
+        let (merged, update) = apply_deltas(TARGET_SPEC, &[modified]).unwrap();
+
+        assert_eq!(update.modified, 1);
+        assert!(merged.contains("email or username"));
+        assert!(merged.contains("#### Scenario: Username login"));
+        assert!(!merged.contains("#### Scenario: Successful login"));
+        // Untouched requirement keeps its position after the modified one
+        let login = merged.find("User Login").unwrap();
+        let logout = merged.find("User Logout").unwrap();
+        assert!(login < logout);
+    }
+
+    #[test]
+    fn test_apply_modified_missing_requirement_fails() {
+        let modified = item(
+            DeltaOp::Modified,
+            "Password Reset",
+            "### Requirement: Password Reset\n\nText.",
+        );
+
+        let result = apply_deltas(TARGET_SPEC, &[modified]);
+
+        assert!(result.unwrap_err().to_string().contains("does not exist"));
+    }
+
+    #[test]
+    fn test_apply_removed_drops_requirement() {
+        let removed = item(DeltaOp::Removed, "User Logout", "");
+
+        let (merged, update) = apply_deltas(TARGET_SPEC, &[removed]).unwrap();
+
+        assert_eq!(update.removed, 1);
+        assert!(!merged.contains("User Logout"));
+        assert!(!merged.contains("Logout clears session"));
+        assert!(merged.contains("User Login"));
+    }
+
+    #[test]
+    fn test_apply_renamed_updates_header_and_keeps_body() {
+        let renamed = item(DeltaOp::Renamed, "User Logout -> Sign Out", "");
+
+        let (merged, update) = apply_deltas(TARGET_SPEC, &[renamed]).unwrap();
+
+        assert_eq!(update.renamed, 1);
+        assert!(merged.contains("### Requirement: Sign Out"));
+        assert!(!merged.contains("### Requirement: User Logout"));
+        assert!(merged.contains("#### Scenario: Logout clears session"));
+    }
+
+    #[test]
+    fn test_apply_renamed_then_modified_uses_new_name() {
+        let renamed = item(DeltaOp::Renamed, "User Logout → Sign Out", "");
+        let modified = item(
+            DeltaOp::Modified,
+            "Sign Out",
+            "### Requirement: Sign Out\n\nThe system SHALL sign users out everywhere.",
+        );
+
+        let (merged, _) = apply_deltas(TARGET_SPEC, &[modified, renamed]).unwrap();
+
+        assert!(merged.contains("sign users out everywhere"));
+        assert!(!merged.contains("Logout clears session"));
+    }
+
+    #[test]
+    fn test_apply_renamed_collision_fails() {
+        let renamed = item(DeltaOp::Renamed, "User Logout -> User Login", "");
+
+        let result = apply_deltas(TARGET_SPEC, &[renamed]);
+
+        assert!(result.unwrap_err().to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_apply_preserves_untouched_formatting() {
+        let spec = "# Spec\n\n## Purpose\nWhy.\n\n## Requirements\n\n### Requirement: Kept\nThe system SHALL keep   odd   spacing.\n\n* **WHEN** x\n* **THEN** y\n\n## Notes\n\nTrailing section.\n";
+        let added = item(
+            DeltaOp::Added,
+            "New",
+            "### Requirement: New\nThe system SHALL add.",
+        );
+
+        let (merged, _) = apply_deltas(spec, &[added]).unwrap();
+
+        assert_eq!(
+            merged,
+            "# Spec\n\n## Purpose\nWhy.\n\n## Requirements\n\n### Requirement: Kept\nThe system SHALL keep   odd   spacing.\n\n* **WHEN** x\n* **THEN** y\n\n### Requirement: New\nThe system SHALL add.\n\n## Notes\n\nTrailing section.\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_missing_requirements_section_fails() {
+        let result = apply_deltas("# Spec\n\n## Purpose\nWhy.\n", &[]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_rename_variants() {
+        assert_eq!(parse_rename("Old -> New"), Some(("Old", "New")));
+        assert_eq!(parse_rename("Old → New"), Some(("Old", "New")));
+        assert_eq!(parse_rename("Old"), None);
+        assert_eq!(parse_rename("Old -> "), None);
+    }
+
+    // ==================== archive_change tests ====================
+
+    #[test]
+    fn test_archive_change_applies_deltas_and_moves_folder() {
+        let (temp_dir, config) = create_project("# Tasks\n- [x] 1.1 Implement\n", ADDED_DELTA);
+        let root = temp_dir.path();
+
+        let result = archive_change(root, &config, "add-2fa", "2025-01-15", false).unwrap();
+
+        assert_eq!(result.archive_name, "2025-01-15-add-2fa");
+        assert_eq!(result.updates.len(), 1);
+        assert_eq!(result.updates[0].capability, "auth");
+        assert_eq!(result.updates[0].added, 1);
+        assert!(!result.updates[0].created);
+
+        let spec = fs::read_to_string(root.join("specs/auth/spec.md")).unwrap();
+        assert!(spec.contains("### Requirement: Two-Factor Auth"));
+        assert!(!root.join("specs/_changes/add-2fa").exists());
+        assert!(root
+            .join("specs/_archive/2025-01-15-add-2fa/proposal.md")
+            .exists());
+    }
+
+    #[test]
+    fn test_archive_change_creates_new_capability() {
+        let (temp_dir, config) = create_project("# Tasks\n- [x] 1.1 Implement\n", ADDED_DELTA);
+        let root = temp_dir.path();
+        let change_dir = root.join("specs/_changes/add-2fa");
+        fs::rename(change_dir.join("specs/auth"), change_dir.join("specs/mfa")).unwrap();
+        let proposal = fs::read_to_string(change_dir.join("proposal.md")).unwrap();
+        fs::write(
+            change_dir.join("proposal.md"),
+            format!("{}\n## Impact\n- New capabilities: mfa\n", proposal),
+        )
+        .unwrap();
+
+        let result = archive_change(root, &config, "add-2fa", "2025-01-15", false).unwrap();
+
+        assert!(result.updates[0].created);
+        let spec = fs::read_to_string(root.join("specs/mfa/spec.md")).unwrap();
+        assert!(spec.starts_with(
+            "# mfa Specification\n\n## Purpose\nTBD - created by archiving change add-2fa. Update Purpose after archive.\n\n## Requirements\n"
+        ));
+        assert!(spec.contains("### Requirement: Two-Factor Auth"));
+    }
+
+    #[test]
+    fn test_archive_change_refuses_incomplete_tasks() {
+        let (temp_dir, config) = create_project("# Tasks\n- [ ] 1.1 Implement\n", ADDED_DELTA);
+        let root = temp_dir.path();
+
+        let result = archive_change(root, &config, "add-2fa", "2025-01-15", false);
+
+        assert!(result.unwrap_err().to_string().contains("incomplete tasks"));
+        assert!(root.join("specs/_changes/add-2fa").exists());
+    }
+
+    #[test]
+    fn test_archive_change_force_skips_readiness_checks() {
+        let (temp_dir, config) = create_project("# Tasks\n- [ ] 1.1 Implement\n", ADDED_DELTA);
+        let root = temp_dir.path();
+
+        let result = archive_change(root, &config, "add-2fa", "2025-01-15", true);
+
+        assert!(result.is_ok(), "Expected Ok, got {:?}", result);
+        assert!(root.join("specs/_archive/2025-01-15-add-2fa").exists());
+    }
+
+    #[test]
+    fn test_archive_change_conflict_leaves_everything_untouched() {
+        let delta =
+            "## REMOVED Requirements\n\n### Requirement: Password Reset\n\n**Reason**: Gone\n";
+        let (temp_dir, config) = create_project("# Tasks\n- [x] 1.1 Implement\n", delta);
+        let root = temp_dir.path();
+
+        let result = archive_change(root, &config, "add-2fa", "2025-01-15", true);
+
+        assert!(result.unwrap_err().to_string().contains("auth"));
+        assert_eq!(
+            fs::read_to_string(root.join("specs/auth/spec.md")).unwrap(),
+            TARGET_SPEC
+        );
+        assert!(root.join("specs/_changes/add-2fa").exists());
+    }
+
+    #[test]
+    fn test_archive_change_refuses_delta_that_does_not_fit_target() {
+        let delta =
+            "## REMOVED Requirements\n\n### Requirement: Password Reset\n\n**Reason**: Gone\n";
+        let (temp_dir, config) = create_project("# Tasks\n- [x] 1.1 Implement\n", delta);
+        let root = temp_dir.path();
+
+        let result = archive_change(root, &config, "add-2fa", "2025-01-15", false);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("failed validation"));
+        assert!(root.join("specs/_changes/add-2fa").exists());
+    }
+
+    #[test]
+    fn test_archive_change_not_found() {
+        let (temp_dir, config) = create_project("# Tasks\n- [x] 1.1 Implement\n", ADDED_DELTA);
+
+        let result = archive_change(temp_dir.path(), &config, "missing", "2025-01-15", false);
+
+        assert!(result.unwrap_err().to_string().contains("not found"));
+    }
+}
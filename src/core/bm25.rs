@@ -0,0 +1,196 @@
+//! BM25 keyword scoring for the search index.
+//!
+//! Embeddings match by meaning but miss exact identifiers such as config keys
+//! and command names (`archive_folder`, `spox mcp serve`). The index therefore
+//! also stores the term counts of every indexed text, and `Bm25` ranks them
+//! against the terms of a query with the Okapi BM25 formula.
+//!
+//! Terms are lowercased words. Identifiers joined by `_` or `-` are kept as a
+//! whole and additionally split into their parts, so `archive_folder` matches
+//! both the exact key and the words "archive" and "folder". Unlike the hashing
+//! embedder, terms are not stemmed: keyword search is for exact matches.
+
+use std::collections::{BTreeMap, HashMap};
+
+/// Term frequency saturation.
+const K1: f32 = 1.2;
+
+/// Document length normalization.
+const B: f32 = 0.75;
+
+/// Number of occurrences of each term in a text.
+pub type TermCounts = BTreeMap<String, u32>;
+
+/// The terms of a text, in order, with repetitions.
+pub fn terms(text: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+
+    for word in text.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-')) {
+        let word = word.trim_matches(|c| c == '_' || c == '-').to_lowercase();
+        if word.is_empty() {
+            continue;
+        }
+
+        let parts: Vec<&str> = word
+            .split(['_', '-'])
+            .filter(|part| !part.is_empty())
+            .collect();
+        if parts.len() > 1 {
+            terms.extend(parts.iter().map(|part| part.to_string()));
+        }
+        terms.push(word);
+    }
+
+    terms
+}
+
+/// Count the terms of a text.
+pub fn term_counts(text: &str) -> TermCounts {
+    let mut counts = TermCounts::new();
+    for term in terms(text) {
+        *counts.entry(term).or_default() += 1;
+    }
+    counts
+}
+
+/// BM25 statistics of a collection of documents.
+pub struct Bm25<'a> {
+    /// Number of documents.
+    documents: usize,
+    /// Average number of terms per document.
+    average_length: f32,
+    /// Number of documents containing each term.
+    document_frequency: HashMap<&'a str, usize>,
+}
+
+impl<'a> Bm25<'a> {
+    /// Collect the statistics of the documents to score.
+    pub fn new(documents: &[&'a TermCounts]) -> Self {
+        let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+        let mut total_length = 0;
+
+        for counts in documents {
+            total_length += length(counts);
+            for term in counts.keys() {
+                *document_frequency.entry(term).or_default() += 1;
+            }
+        }
+
+        let average_length = if documents.is_empty() {
+            0.0
+        } else {
+            total_length as f32 / documents.len() as f32
+        };
+
+        Self {
+            documents: documents.len(),
+            average_length,
+            document_frequency,
+        }
+    }
+
+    /// Score a document against the terms of a query.
+    ///
+    /// # Returns
+    ///
+    /// 0.0 if the document contains none of the query terms, a positive score
+    /// otherwise. Scores are only comparable within one collection.
+    pub fn score(&self, query_terms: &[String], document: &TermCounts) -> f32 {
+        if self.average_length == 0.0 {
+            return 0.0;
+        }
+
+        let length_ratio = length(document) as f32 / self.average_length;
+        query_terms
+            .iter()
+            .filter_map(|term| {
+                let frequency = *document.get(term)? as f32;
+                let idf = self.idf(term);
+                Some(idf * frequency * (K1 + 1.0) / (frequency + K1 * (1.0 - B + B * length_ratio)))
+            })
+            .sum()
+    }
+
+    /// Inverse document frequency; always positive, so every match counts.
+    fn idf(&self, term: &str) -> f32 {
+        let containing = self.document_frequency.get(term).copied().unwrap_or(0) as f32;
+        let documents = self.documents as f32;
+        (1.0 + (documents - containing + 0.5) / (containing + 0.5)).ln()
+    }
+}
+
+/// Number of terms in a document.
+fn length(counts: &TermCounts) -> u32 {
+    counts.values().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== terms tests ====================
+
+    #[test]
+    fn test_terms_lowercases_words() {
+        assert_eq!(
+            terms("Run `spox MCP serve`."),
+            ["run", "spox", "mcp", "serve"]
+        );
+    }
+
+    #[test]
+    fn test_terms_keeps_identifiers_and_their_parts() {
+        assert_eq!(
+            terms("Set archive_folder and --top-k"),
+            [
+                "set",
+                "archive",
+                "folder",
+                "archive_folder",
+                "and",
+                "top",
+                "k",
+                "top-k"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_term_counts_counts_repetitions() {
+        let counts = term_counts("login, login and logout");
+        assert_eq!(counts.get("login"), Some(&2));
+        assert_eq!(counts.get("logout"), Some(&1));
+    }
+
+    // ==================== Bm25 tests ====================
+
+    #[test]
+    fn test_bm25_scores_only_matching_documents() {
+        let config = term_counts("The archive_folder setting moves archived changes.");
+        let login = term_counts("Users log in with a password.");
+        let bm25 = Bm25::new(&[&config, &login]);
+        let query = terms("archive_folder");
+
+        assert!(bm25.score(&query, &config) > 0.0);
+        assert_eq!(bm25.score(&query, &login), 0.0);
+    }
+
+    #[test]
+    fn test_bm25_prefers_rare_terms() {
+        let serve = term_counts("Run spox mcp serve to start the server.");
+        let spox = term_counts("spox validates the specs.");
+        let other = term_counts("spox formats the specs.");
+        let bm25 = Bm25::new(&[&serve, &spox, &other]);
+
+        let query = terms("mcp serve");
+        assert!(bm25.score(&query, &serve) > 0.0);
+        assert_eq!(bm25.score(&query, &spox), 0.0);
+        assert!(bm25.idf("mcp") > bm25.idf("spox"));
+    }
+
+    #[test]
+    fn test_bm25_empty_collection_scores_zero() {
+        let bm25 = Bm25::new(&[]);
+        assert_eq!(bm25.score(&terms("login"), &term_counts("login")), 0.0);
+    }
+}
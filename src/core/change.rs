@@ -0,0 +1,703 @@
+//! Change scaffolding and authoring for Spec Oxide Core.
+//!
+//! This module creates new change folders from the change templates that
+//! `spox init` installs into `.spox/templates/change/`, and edits the
+//! proposal and delta specs of existing changes in the template layout.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::error::{Error, Result};
+use super::format::{render_delta, render_requirement};
+use super::markdown::{DeltaOp, Document};
+use super::spec::ParsedRequirement;
+use crate::config::Config;
+use crate::show::change::{parse_delta_content, DeltaItem};
+
+/// Location of the change templates relative to the project root.
+pub const CHANGE_TEMPLATES_DIR: &str = ".spox/templates/change";
+
+/// Placeholder in the change templates that is replaced with the change ID.
+const CHANGE_ID_PLACEHOLDER: &str = "[change-id]";
+
+/// Options for scaffolding a new change.
+#[derive(Debug, Clone, Default)]
+pub struct ChangeInitOptions {
+    /// Capabilities to create delta spec stubs for.
+    pub capabilities: Vec<String>,
+    /// Whether to create a design.md.
+    pub design: bool,
+}
+
+/// The outcome of scaffolding a change.
+#[derive(Debug, Clone)]
+pub struct ChangeScaffold {
+    /// The change directory that was created.
+    pub change_dir: PathBuf,
+    /// The created files, relative to the change directory.
+    pub files: Vec<String>,
+}
+
+/// Create a new change folder from the change templates.
+///
+/// Creates `<changes_folder>/<id>/` with proposal.md, tasks.md, an optional
+/// design.md and one `specs/<capability>/spec.md` delta stub per capability.
+/// Occurrences of `[change-id]` in the templates are replaced with the ID.
+///
+/// # Arguments
+///
+/// * `project_root` - The root directory of the project
+/// * `config` - The loaded configuration with folder paths
+/// * `id` - The change ID (kebab-case)
+/// * `options` - Capabilities and optional files to create
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The ID or a capability name is not kebab-case
+/// - A change with this ID already exists in the changes or archive folder
+/// - A template is missing (run `spox init` to install them)
+/// - Files cannot be written
+pub fn init_change(
+    project_root: &Path,
+    config: &Config,
+    id: &str,
+    options: &ChangeInitOptions,
+) -> Result<ChangeScaffold> {
+    validate_name("change ID", id)?;
+    for capability in &options.capabilities {
+        validate_name("capability", capability)?;
+    }
+
+    let change_dir = project_root.join(config.changes_folder()).join(id);
+    if change_dir.exists() {
+        return Err(Error::Other(format!("Change '{}' already exists", id)));
+    }
+
+    let archive_dir = project_root.join(config.archive_folder());
+    if let Some(archived) = find_archived(&archive_dir, id) {
+        return Err(Error::Other(format!(
+            "Change '{}' already exists in the archive as '{}'",
+            id, archived
+        )));
+    }
+
+    // Render every file before creating anything on disk
+    let templates_dir = project_root.join(CHANGE_TEMPLATES_DIR);
+    let mut files: Vec<(String, String)> = vec![
+        (
+            "proposal.md".to_string(),
+            render_template(&templates_dir, "proposal.md", id)?,
+        ),
+        (
+            "tasks.md".to_string(),
+            render_template(&templates_dir, "tasks.md", id)?,
+        ),
+    ];
+    if options.design {
+        files.push((
+            "design.md".to_string(),
+            render_template(&templates_dir, "design.md", id)?,
+        ));
+    }
+    if !options.capabilities.is_empty() {
+        let spec = render_template(&templates_dir, "spec.md", id)?;
+        for capability in &options.capabilities {
+            files.push((format!("specs/{}/spec.md", capability), spec.clone()));
+        }
+    }
+
+    for (name, content) in &files {
+        let path = change_dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                Error::Other(format!("Failed to create {}: {}", parent.display(), e))
+            })?;
+        }
+        fs::write(&path, content)
+            .map_err(|e| Error::Other(format!("Failed to write {}: {}", path.display(), e)))?;
+    }
+
+    Ok(ChangeScaffold {
+        change_dir,
+        files: files.into_iter().map(|(name, _)| name).collect(),
+    })
+}
+
+/// Set the `# Change:` title of a change's proposal.md.
+///
+/// Replaces the first level-1 heading, or adds one at the top.
+///
+/// # Errors
+///
+/// Returns an error if the title is empty or spans several lines, or if
+/// proposal.md cannot be read or written.
+pub fn set_proposal_title(change_dir: &Path, title: &str) -> Result<()> {
+    let title = title.trim();
+    if title.is_empty() || title.contains('\n') {
+        return Err(Error::Other(
+            "Proposal title must be a single non-empty line".to_string(),
+        ));
+    }
+
+    let path = change_dir.join("proposal.md");
+    let content = read_change_file(&path)?;
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let heading = format!("# Change: {}", title);
+    match Document::parse(&content).title {
+        Some(old) => lines[old.span.start.line - 1] = heading,
+        None => lines.splice(0..0, [heading, String::new()]).for_each(drop),
+    }
+
+    write_change_file(&path, &join_lines(&lines))
+}
+
+/// Replace the body of a level-2 section of a change's proposal.md.
+///
+/// The section is matched by its heading, case-insensitively; a missing
+/// section is appended. As in the proposal template, the content is written
+/// directly below the heading, followed by a blank line before the next one.
+///
+/// # Arguments
+///
+/// * `change_dir` - The change directory
+/// * `section` - The section heading, such as `Why` or `What Changes`
+/// * `content` - The new section body; `###` subheadings are allowed
+///
+/// # Errors
+///
+/// Returns an error if the section name is empty, if the content contains
+/// `#` or `##` headings, or if proposal.md cannot be read or written.
+pub fn set_proposal_section(change_dir: &Path, section: &str, content: &str) -> Result<()> {
+    let section = section.trim().trim_start_matches('#').trim();
+    if section.is_empty() || section.contains('\n') {
+        return Err(Error::Other(
+            "Section name must be a single non-empty line".to_string(),
+        ));
+    }
+    let body = Document::parse(content);
+    if body.title.is_some() || !body.sections.is_empty() {
+        return Err(Error::Other(format!(
+            "Content of section '{}' must not contain # or ## headings",
+            section
+        )));
+    }
+
+    let path = change_dir.join("proposal.md");
+    let proposal = read_change_file(&path)?;
+    let mut lines: Vec<String> = proposal.lines().map(str::to_string).collect();
+    let body_lines = content.trim().lines().map(str::to_string);
+
+    match Document::parse(&proposal).section_named(section) {
+        Some(existing) => {
+            let heading = existing.heading.span.start.line - 1;
+            let last = existing.span.end.line - 1;
+            lines.splice(heading + 1..=last, body_lines);
+        }
+        None => {
+            while lines.last().is_some_and(|line| line.trim().is_empty()) {
+                lines.pop();
+            }
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push(format!("## {}", section));
+            lines.extend(body_lines);
+        }
+    }
+
+    write_change_file(&path, &join_lines(&lines))
+}
+
+/// Add a requirement to the delta spec of a capability in a change.
+///
+/// Creates `specs/<capability>/spec.md` in the change directory if needed. A
+/// requirement with the same operation and name is replaced, so the call can
+/// be repeated to revise it. The delta spec is rewritten in the template
+/// layout, keeping the other requirement blocks as written.
+///
+/// # Arguments
+///
+/// * `change_dir` - The change directory
+/// * `capability` - The capability (spec ID) the delta applies to
+/// * `op` - The delta operation
+/// * `requirement` - The requirement; for RENAMED, its name is `Old -> New`
+///
+/// # Returns
+///
+/// The path of the delta spec.
+///
+/// # Errors
+///
+/// Returns an error if the capability is not kebab-case, the requirement or a
+/// scenario has no name, or the delta spec cannot be read or written.
+pub fn add_delta_requirement(
+    change_dir: &Path,
+    capability: &str,
+    op: DeltaOp,
+    requirement: &ParsedRequirement,
+) -> Result<PathBuf> {
+    validate_name("capability", capability)?;
+    if requirement.name.trim().is_empty() || requirement.name.contains('\n') {
+        return Err(Error::Other(
+            "Requirement name must be a single non-empty line".to_string(),
+        ));
+    }
+    if requirement
+        .scenarios
+        .iter()
+        .any(|s| s.name.trim().is_empty())
+    {
+        return Err(Error::Other(format!(
+            "Every scenario of requirement '{}' needs a name",
+            requirement.name
+        )));
+    }
+
+    let path = change_dir.join("specs").join(capability).join("spec.md");
+    let mut items = if path.exists() {
+        parse_delta_content(&read_change_file(&path)?)
+    } else {
+        Vec::new()
+    };
+
+    let item = DeltaItem {
+        operation: op,
+        name: requirement.name.trim().to_string(),
+        text: requirement.description.clone(),
+        scenarios: requirement
+            .scenarios
+            .iter()
+            .map(|s| s.name.clone())
+            .collect(),
+        content: render_requirement(requirement),
+    };
+    match items
+        .iter_mut()
+        .find(|i| i.operation == op && i.name == item.name)
+    {
+        Some(existing) => *existing = item,
+        None => items.push(item),
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| Error::Other(format!("Failed to create {}: {}", parent.display(), e)))?;
+    }
+    write_change_file(&path, &render_delta(&items))?;
+    Ok(path)
+}
+
+/// Read a file of a change.
+fn read_change_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| Error::Other(format!("Failed to read {}: {}", path.display(), e)))
+}
+
+/// Write a file of a change.
+fn write_change_file(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content)
+        .map_err(|e| Error::Other(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+/// Join lines into file content with a trailing newline.
+fn join_lines(lines: &[String]) -> String {
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+/// Check that a change ID or capability name is kebab-case.
+///
+/// Names must be non-empty, use only lowercase letters, digits and hyphens,
+/// and must not start or end with a hyphen.
+pub fn validate_name(kind: &str, name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Other(format!(
+            "Invalid {} '{}': use kebab-case (lowercase letters, digits and hyphens)",
+            kind, name
+        )))
+    }
+}
+
+/// Find an archived change with the given ID (`YYYY-MM-DD-<id>` or `<id>`).
+fn find_archived(archive_dir: &Path, id: &str) -> Option<String> {
+    let entries = fs::read_dir(archive_dir).ok()?;
+
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .find(|name| name == id || strip_date_prefix(name) == Some(id))
+}
+
+/// Strip a `YYYY-MM-DD-` prefix from an archive folder name.
+fn strip_date_prefix(name: &str) -> Option<&str> {
+    let (date, rest) = (name.get(..11)?, name.get(11..)?);
+    let bytes = date.as_bytes();
+    let is_date = bytes.iter().enumerate().all(|(i, b)| match i {
+        4 | 7 | 10 => *b == b'-',
+        _ => b.is_ascii_digit(),
+    });
+    is_date.then_some(rest)
+}
+
+/// Read a change template and substitute the change ID.
+fn render_template(templates_dir: &Path, name: &str, id: &str) -> Result<String> {
+    let path = templates_dir.join(name);
+    let template = fs::read_to_string(&path).map_err(|_| {
+        Error::Other(format!(
+            "Change template not found: {} (run 'spox init' to install templates)",
+            path.display()
+        ))
+    })?;
+
+    let mut content = template.replace(CHANGE_ID_PLACEHOLDER, id);
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_project() -> (TempDir, Config) {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        let templates = root.join(CHANGE_TEMPLATES_DIR);
+        fs::create_dir_all(&templates).unwrap();
+        fs::write(templates.join("proposal.md"), "# Change: [change-id]\n").unwrap();
+        fs::write(
+            templates.join("tasks.md"),
+            "# Tasks [change-id]\n\n- [ ] 1.1 Task",
+        )
+        .unwrap();
+        fs::write(templates.join("design.md"), "## Context\n").unwrap();
+        fs::write(templates.join("spec.md"), "## ADDED Requirements\n").unwrap();
+        fs::create_dir_all(root.join("specs/_changes")).unwrap();
+        fs::create_dir_all(root.join("specs/_archive")).unwrap();
+
+        let config: Config = toml::from_str(
+            r#"
+[paths]
+spec_folder = "specs/"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#,
+        )
+        .unwrap();
+
+        (temp_dir, config)
+    }
+
+    // ==================== init_change tests ====================
+
+    #[test]
+    fn test_init_change_creates_required_files() {
+        let (temp_dir, config) = create_project();
+        let root = temp_dir.path();
+
+        let scaffold =
+            init_change(root, &config, "add-2fa", &ChangeInitOptions::default()).unwrap();
+
+        assert_eq!(scaffold.files, vec!["proposal.md", "tasks.md"]);
+        let change_dir = root.join("specs/_changes/add-2fa");
+        assert_eq!(
+            fs::read_to_string(change_dir.join("proposal.md")).unwrap(),
+            "# Change: add-2fa\n"
+        );
+        assert_eq!(
+            fs::read_to_string(change_dir.join("tasks.md")).unwrap(),
+            "# Tasks add-2fa\n\n- [ ] 1.1 Task\n"
+        );
+        assert!(!change_dir.join("design.md").exists());
+        assert!(!change_dir.join("specs").exists());
+    }
+
+    #[test]
+    fn test_init_change_with_design_and_capabilities() {
+        let (temp_dir, config) = create_project();
+        let root = temp_dir.path();
+        let options = ChangeInitOptions {
+            capabilities: vec!["auth".to_string(), "notifications".to_string()],
+            design: true,
+        };
+
+        let scaffold = init_change(root, &config, "add-2fa", &options).unwrap();
+
+        assert_eq!(
+            scaffold.files,
+            vec![
+                "proposal.md",
+                "tasks.md",
+                "design.md",
+                "specs/auth/spec.md",
+                "specs/notifications/spec.md"
+            ]
+        );
+        let change_dir = root.join("specs/_changes/add-2fa");
+        assert!(change_dir.join("design.md").exists());
+        assert!(change_dir.join("specs/auth/spec.md").exists());
+        assert!(change_dir.join("specs/notifications/spec.md").exists());
+    }
+
+    #[test]
+    fn test_init_change_rejects_existing_change() {
+        let (temp_dir, config) = create_project();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("specs/_changes/add-2fa")).unwrap();
+
+        let result = init_change(root, &config, "add-2fa", &ChangeInitOptions::default());
+
+        assert!(result.unwrap_err().to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_init_change_rejects_archived_change() {
+        let (temp_dir, config) = create_project();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("specs/_archive/2025-01-15-add-2fa")).unwrap();
+
+        let result = init_change(root, &config, "add-2fa", &ChangeInitOptions::default());
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("archive"));
+        assert!(err.contains("2025-01-15-add-2fa"));
+    }
+
+    #[test]
+    fn test_init_change_allows_id_that_only_suffixes_archived_id() {
+        let (temp_dir, config) = create_project();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("specs/_archive/2025-01-15-add-2fa")).unwrap();
+
+        let result = init_change(root, &config, "2fa", &ChangeInitOptions::default());
+
+        assert!(result.is_ok(), "Expected Ok, got {:?}", result);
+    }
+
+    #[test]
+    fn test_init_change_missing_template() {
+        let (temp_dir, config) = create_project();
+        let root = temp_dir.path();
+        fs::remove_file(root.join(CHANGE_TEMPLATES_DIR).join("tasks.md")).unwrap();
+
+        let result = init_change(root, &config, "add-2fa", &ChangeInitOptions::default());
+
+        assert!(result.unwrap_err().to_string().contains("spox init"));
+        assert!(!root.join("specs/_changes/add-2fa").exists());
+    }
+
+    #[test]
+    fn test_init_change_rejects_invalid_capability() {
+        let (temp_dir, config) = create_project();
+        let options = ChangeInitOptions {
+            capabilities: vec!["../auth".to_string()],
+            design: false,
+        };
+
+        let result = init_change(temp_dir.path(), &config, "add-2fa", &options);
+
+        assert!(result.unwrap_err().to_string().contains("capability"));
+    }
+
+    // ==================== authoring tests ====================
+
+    const PROPOSAL: &str = "# Change: [Brief description of change]
+
+## Why
+[1-2 sentences on problem/opportunity]
+
+## What Changes
+- [Bullet list of changes]
+
+## Impact
+- Affected specs: [list capabilities]
+";
+
+    fn create_change_dir() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("proposal.md"), PROPOSAL).unwrap();
+        temp_dir
+    }
+
+    fn requirement(name: &str, description: &str) -> ParsedRequirement {
+        ParsedRequirement {
+            name: name.to_string(),
+            description: description.to_string(),
+            scenarios: vec![crate::core::spec::ParsedScenario {
+                name: "Code required".to_string(),
+                when_clause: "valid credentials are provided".to_string(),
+                then_clauses: vec![
+                    "the system asks for a code".to_string(),
+                    "login completes after a valid code".to_string(),
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_set_proposal_title_and_sections() {
+        let temp_dir = create_change_dir();
+        let dir = temp_dir.path();
+
+        set_proposal_title(dir, "Add two-factor login").unwrap();
+        set_proposal_section(dir, "Why", "Passwords alone get phished.").unwrap();
+        set_proposal_section(dir, "what changes", "- Add a second factor\n- Send codes\n").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.join("proposal.md")).unwrap(),
+            "# Change: Add two-factor login
+
+## Why
+Passwords alone get phished.
+
+## What Changes
+- Add a second factor
+- Send codes
+
+## Impact
+- Affected specs: [list capabilities]
+"
+        );
+    }
+
+    #[test]
+    fn test_set_proposal_section_appends_missing_section() {
+        let temp_dir = create_change_dir();
+        let dir = temp_dir.path();
+
+        set_proposal_section(dir, "## Risks", "Users may lose their phones.").unwrap();
+        set_proposal_section(dir, "Impact", "").unwrap();
+
+        let proposal = fs::read_to_string(dir.join("proposal.md")).unwrap();
+        assert!(
+            proposal.ends_with("## Impact\n\n## Risks\nUsers may lose their phones.\n"),
+            "{}",
+            proposal
+        );
+    }
+
+    #[test]
+    fn test_set_proposal_section_rejects_headings_in_content() {
+        let temp_dir = create_change_dir();
+
+        let result = set_proposal_section(temp_dir.path(), "Why", "Text\n\n## Impact\nMore");
+
+        assert!(result.unwrap_err().to_string().contains("headings"));
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("proposal.md")).unwrap(),
+            PROPOSAL
+        );
+    }
+
+    #[test]
+    fn test_add_delta_requirement_creates_and_replaces() {
+        let temp_dir = create_change_dir();
+        let dir = temp_dir.path();
+
+        let path = add_delta_requirement(
+            dir,
+            "auth",
+            DeltaOp::Added,
+            &requirement("Two Factor", "The system SHALL ask for a code."),
+        )
+        .unwrap();
+        add_delta_requirement(
+            dir,
+            "auth",
+            DeltaOp::Removed,
+            &ParsedRequirement {
+                name: "Password Hints".to_string(),
+                description: "**Reason**: Hints leak passwords.".to_string(),
+                scenarios: Vec::new(),
+            },
+        )
+        .unwrap();
+        add_delta_requirement(
+            dir,
+            "auth",
+            DeltaOp::Added,
+            &requirement("Two Factor", "The system SHALL ask for a one-time code."),
+        )
+        .unwrap();
+
+        assert_eq!(path, dir.join("specs/auth/spec.md"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "## ADDED Requirements
+
+### Requirement: Two Factor
+The system SHALL ask for a one-time code.
+
+#### Scenario: Code required
+- **WHEN** valid credentials are provided
+- **THEN** the system asks for a code
+- **AND** login completes after a valid code
+
+## REMOVED Requirements
+
+### Requirement: Password Hints
+**Reason**: Hints leak passwords.
+"
+        );
+    }
+
+    #[test]
+    fn test_add_delta_requirement_rejects_invalid_input() {
+        let temp_dir = create_change_dir();
+        let dir = temp_dir.path();
+        let mut unnamed_scenario = requirement("Two Factor", "Text.");
+        unnamed_scenario.scenarios[0].name = String::new();
+
+        let capability = add_delta_requirement(
+            dir,
+            "../auth",
+            DeltaOp::Added,
+            &requirement("Two Factor", "Text."),
+        );
+        let name = add_delta_requirement(dir, "auth", DeltaOp::Added, &requirement(" ", "Text."));
+        let scenario = add_delta_requirement(dir, "auth", DeltaOp::Added, &unnamed_scenario);
+
+        assert!(capability.unwrap_err().to_string().contains("capability"));
+        assert!(name.unwrap_err().to_string().contains("Requirement name"));
+        assert!(scenario.unwrap_err().to_string().contains("scenario"));
+        assert!(!dir.join("specs").exists());
+    }
+
+    // ==================== validate_name tests ====================
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("change ID", "add-two-factor-auth").is_ok());
+        assert!(validate_name("change ID", "v2-api").is_ok());
+        assert!(validate_name("change ID", "").is_err());
+        assert!(validate_name("change ID", "Add-2fa").is_err());
+        assert!(validate_name("change ID", "-add").is_err());
+        assert!(validate_name("change ID", "add/2fa").is_err());
+        assert!(validate_name("change ID", "_archive").is_err());
+    }
+
+    #[test]
+    fn test_strip_date_prefix() {
+        assert_eq!(strip_date_prefix("2025-01-15-add-2fa"), Some("add-2fa"));
+        assert_eq!(strip_date_prefix("add-2fa"), None);
+        assert_eq!(strip_date_prefix("2025-1-15-add-2fa"), None);
+    }
+}
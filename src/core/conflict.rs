@@ -0,0 +1,220 @@
+//! Conflict detection between active changes for Spec Oxide Core.
+//!
+//! Two active changes conflict when their deltas touch the same requirement of
+//! the same capability. Archiving them one after the other would silently
+//! overwrite (or fail to apply) the first change, so overlaps are reported
+//! before either change is archived.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use super::archive::parse_rename;
+use super::error::{Error, Result};
+use super::markdown::DeltaOp;
+use crate::show::change::parse_delta_specs;
+
+/// A change's delta operation on a requirement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementTouch {
+    /// The change ID.
+    pub change_id: String,
+    /// The delta operation.
+    pub operation: DeltaOp,
+    /// The requirement name as written in the delta (`Old -> New` for renames).
+    pub name: String,
+}
+
+/// A requirement touched by more than one active change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    /// The capability the requirement belongs to.
+    pub capability: String,
+    /// The requirement name.
+    pub requirement: String,
+    /// The delta operations on the requirement, sorted by change ID.
+    pub touches: Vec<RequirementTouch>,
+}
+
+impl Conflict {
+    /// Whether the given change is part of this conflict.
+    pub fn involves(&self, change_id: &str) -> bool {
+        self.touches.iter().any(|t| t.change_id == change_id)
+    }
+}
+
+/// Find requirements touched by more than one active change.
+///
+/// A RENAMED delta touches both its old and its new name, so a rename collides
+/// with any other change that modifies, removes or adds either name.
+///
+/// # Arguments
+///
+/// * `changes_folder` - Path to the folder holding the active changes
+///
+/// # Returns
+///
+/// The conflicts sorted by capability and requirement name. A missing changes
+/// folder has no conflicts.
+///
+/// # Errors
+///
+/// Returns an error if the changes folder or a delta spec cannot be read.
+pub fn find_conflicts(changes_folder: &Path) -> Result<Vec<Conflict>> {
+    if !changes_folder.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(changes_folder)
+        .map_err(|e| Error::Other(format!("Failed to read changes folder: {}", e)))?;
+    let mut change_ids: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().join("specs").is_dir())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    change_ids.sort();
+
+    let mut touched: BTreeMap<(String, String), Vec<RequirementTouch>> = BTreeMap::new();
+
+    for change_id in &change_ids {
+        let specs_dir = changes_folder.join(change_id).join("specs");
+        let groups = parse_delta_specs(&specs_dir)
+            .map_err(|e| Error::Other(format!("{}: {}", change_id, e)))?;
+
+        for group in groups {
+            for item in group.items {
+                let names = match (item.operation, parse_rename(&item.name)) {
+                    (DeltaOp::Renamed, Some((from, to))) => vec![from.to_string(), to.to_string()],
+                    _ => vec![item.name.trim().to_string()],
+                };
+
+                for name in names {
+                    let touches = touched.entry((group.capability.clone(), name)).or_default();
+                    // A change touching a requirement twice is not a conflict with itself
+                    if touches.last().is_some_and(|t| &t.change_id == change_id) {
+                        continue;
+                    }
+                    touches.push(RequirementTouch {
+                        change_id: change_id.clone(),
+                        operation: item.operation,
+                        name: item.name.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(touched
+        .into_iter()
+        .filter(|(_, touches)| touches.len() > 1)
+        .map(|((capability, requirement), touches)| Conflict {
+            capability,
+            requirement,
+            touches,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_change(root: &Path, change_id: &str, capability: &str, delta: &str) {
+        let dir = root.join(change_id).join("specs").join(capability);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("spec.md"), delta).unwrap();
+    }
+
+    const MODIFY_LOGIN: &str =
+        "## MODIFIED Requirements\n\n### Requirement: User Login\nThe system SHALL log in.\n";
+
+    // ==================== find_conflicts tests ====================
+
+    #[test]
+    fn test_no_conflicts_for_disjoint_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        create_change(temp_dir.path(), "a", "auth", MODIFY_LOGIN);
+        create_change(temp_dir.path(), "b", "billing", MODIFY_LOGIN);
+        create_change(
+            temp_dir.path(),
+            "c",
+            "auth",
+            "## ADDED Requirements\n\n### Requirement: Logout\nThe system SHALL log out.\n",
+        );
+
+        assert!(find_conflicts(temp_dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_modified_twice_conflicts() {
+        let temp_dir = TempDir::new().unwrap();
+        create_change(temp_dir.path(), "b", "auth", MODIFY_LOGIN);
+        create_change(temp_dir.path(), "a", "auth", MODIFY_LOGIN);
+
+        let conflicts = find_conflicts(temp_dir.path()).unwrap();
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].capability, "auth");
+        assert_eq!(conflicts[0].requirement, "User Login");
+        let ids: Vec<&str> = conflicts[0]
+            .touches
+            .iter()
+            .map(|t| t.change_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert!(conflicts[0].involves("a"));
+        assert!(!conflicts[0].involves("c"));
+    }
+
+    #[test]
+    fn test_removed_vs_modified_conflicts() {
+        let temp_dir = TempDir::new().unwrap();
+        create_change(temp_dir.path(), "a", "auth", MODIFY_LOGIN);
+        create_change(
+            temp_dir.path(),
+            "b",
+            "auth",
+            "## REMOVED Requirements\n\n### Requirement: User Login\n**Reason**: Gone\n",
+        );
+
+        let conflicts = find_conflicts(temp_dir.path()).unwrap();
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].touches[0].operation, DeltaOp::Modified);
+        assert_eq!(conflicts[0].touches[1].operation, DeltaOp::Removed);
+    }
+
+    #[test]
+    fn test_renamed_collides_on_old_and_new_name() {
+        let temp_dir = TempDir::new().unwrap();
+        create_change(
+            temp_dir.path(),
+            "a",
+            "auth",
+            "## RENAMED Requirements\n\n### Requirement: User Login -> Sign In\n",
+        );
+        create_change(temp_dir.path(), "b", "auth", MODIFY_LOGIN);
+        create_change(
+            temp_dir.path(),
+            "c",
+            "auth",
+            "## ADDED Requirements\n\n### Requirement: Sign In\nThe system SHALL sign in.\n",
+        );
+
+        let conflicts = find_conflicts(temp_dir.path()).unwrap();
+
+        let requirements: Vec<&str> = conflicts.iter().map(|c| c.requirement.as_str()).collect();
+        assert_eq!(requirements, vec!["Sign In", "User Login"]);
+        assert_eq!(conflicts[0].touches[0].name, "User Login -> Sign In");
+    }
+
+    #[test]
+    fn test_missing_changes_folder_has_no_conflicts() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let conflicts = find_conflicts(&temp_dir.path().join("missing")).unwrap();
+
+        assert!(conflicts.is_empty());
+    }
+}
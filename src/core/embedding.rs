@@ -0,0 +1,598 @@
+//! Text embeddings for the search index.
+//!
+//! The `Embedder` trait abstracts over the embedding backends selected by the
+//! `[search]` section of the config:
+//!
+//! - `FastEmbedder`: neural embeddings from fastembed; the model is downloaded
+//!   on first use, or loaded from `model_path` on machines without internet
+//! - `HashingEmbedder`: hashed term-frequency vectors; no model, no network,
+//!   deterministic, for air-gapped machines and tests
+//!
+//! Loading a model is the slowest part of indexing and searching, so it is
+//! loaded once per process: `LazyEmbedder` loads it on first use and is shared
+//! (the MCP server keeps one for its whole lifetime).
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use fastembed::{
+    EmbeddingModel, InitOptions, InitOptionsUserDefined, Pooling, TextEmbedding, TokenizerFiles,
+    UserDefinedEmbeddingModel,
+};
+use sha2::{Digest, Sha256};
+
+use super::error::{Error, Result};
+use crate::config::{Config, EmbeddingBackend};
+
+/// The fastembed model to use.
+const FASTEMBED_MODEL: EmbeddingModel = EmbeddingModel::AllMiniLML6V2;
+
+/// Locations of the ONNX model in a local model directory, in order of preference.
+///
+/// Hugging Face repositories keep it in an `onnx/` subfolder.
+const LOCAL_MODEL_FILES: &[&str] = &["model.onnx", "onnx/model.onnx"];
+
+/// Number of hex digits of the ONNX file hash in local model names.
+const LOCAL_MODEL_HASH_LENGTH: usize = 12;
+
+/// Number of texts embedded per fastembed model run.
+const BATCH_SIZE: usize = 64;
+
+/// Number of dimensions of hashing embeddings.
+const HASHING_DIMENSIONS: usize = 512;
+
+/// Words too common in specs to carry meaning in hashing embeddings.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "be", "by", "for", "from", "if", "in", "is", "it", "its",
+    "must", "of", "on", "or", "shall", "should", "that", "the", "then", "this", "to", "when",
+    "with",
+];
+
+/// A backend that turns texts into embedding vectors.
+pub trait Embedder: Send + Sync {
+    /// The backend, as recorded in the index.
+    fn backend(&self) -> EmbeddingBackend;
+
+    /// Name of the model, as recorded in the index.
+    ///
+    /// Embeddings are only comparable if backend and model name match.
+    fn model_name(&self) -> String;
+
+    /// Embed texts in batches.
+    ///
+    /// # Returns
+    ///
+    /// One embedding per text, in the same order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if embedding generation fails.
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>>;
+
+    /// Embed a single text, such as a search query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if embedding generation fails.
+    fn embed_one(&self, text: &str) -> Result<Vec<f32>> {
+        self.embed(&[text])?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Other("Failed to get embedding".to_string()))
+    }
+}
+
+/// Name of the model a backend uses, as recorded in the index.
+fn model_name(backend: EmbeddingBackend) -> String {
+    match backend {
+        EmbeddingBackend::Fastembed => format!("{:?}", FASTEMBED_MODEL),
+        EmbeddingBackend::Hashing => format!("hashing-{}", HASHING_DIMENSIONS),
+    }
+}
+
+// =============================================================================
+// fastembed
+// =============================================================================
+
+/// Neural embeddings from a fastembed model.
+pub struct FastEmbedder {
+    model: TextEmbedding,
+    /// Model name recorded in the index.
+    name: String,
+}
+
+impl FastEmbedder {
+    /// Load the embedding model, downloading it on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model cannot be downloaded or initialized.
+    pub fn new() -> Result<Self> {
+        let model = TextEmbedding::try_new(
+            InitOptions::new(FASTEMBED_MODEL).with_show_download_progress(true),
+        )
+        .map_err(|e| Error::Other(format!("Failed to initialize embedding model: {}", e)))?;
+
+        Ok(Self {
+            model,
+            name: model_name(EmbeddingBackend::Fastembed),
+        })
+    }
+
+    /// Load a model from a local directory, without network access.
+    ///
+    /// The directory holds the ONNX model (`model.onnx` or `onnx/model.onnx`)
+    /// and the tokenizer files `tokenizer.json`, `config.json`,
+    /// `special_tokens_map.json` and `tokenizer_config.json`, as in the model's
+    /// Hugging Face repository. Embeddings are mean-pooled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file is missing or the model cannot be initialized.
+    pub fn from_dir(dir: &Path) -> Result<Self> {
+        let onnx_file = read_model_file(&local_onnx_path(dir)?)?;
+        let tokenizer_files = TokenizerFiles {
+            tokenizer_file: read_model_file(&dir.join("tokenizer.json"))?,
+            config_file: read_model_file(&dir.join("config.json"))?,
+            special_tokens_map_file: read_model_file(&dir.join("special_tokens_map.json"))?,
+            tokenizer_config_file: read_model_file(&dir.join("tokenizer_config.json"))?,
+        };
+        let name = local_model_name(dir, &onnx_file);
+
+        let user_model =
+            UserDefinedEmbeddingModel::new(onnx_file, tokenizer_files).with_pooling(Pooling::Mean);
+        let model =
+            TextEmbedding::try_new_from_user_defined(user_model, InitOptionsUserDefined::new())
+                .map_err(|e| {
+                    Error::Other(format!(
+                        "Failed to initialize embedding model from {}: {}",
+                        dir.display(),
+                        e
+                    ))
+                })?;
+
+        Ok(Self { model, name })
+    }
+}
+
+/// Path of the ONNX model in a local model directory.
+///
+/// # Errors
+///
+/// Returns an error if the directory contains no ONNX model.
+fn local_onnx_path(dir: &Path) -> Result<PathBuf> {
+    LOCAL_MODEL_FILES
+        .iter()
+        .map(|file| dir.join(file))
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            Error::Other(format!(
+                "No ONNX model found in {} (expected {})",
+                dir.display(),
+                LOCAL_MODEL_FILES.join(" or ")
+            ))
+        })
+}
+
+/// Read a file of a local model.
+fn read_model_file(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|e| {
+        Error::Other(format!(
+            "Failed to read model file {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Name of a local model, as recorded in the index.
+///
+/// Combines the directory name with a hash of the ONNX file, so replacing the
+/// model in place is detected as a different model.
+fn local_model_name(dir: &Path, onnx_file: &[u8]) -> String {
+    let dir_name = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| dir.display().to_string());
+    let hash: String = Sha256::digest(onnx_file)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!("local:{}@{}", dir_name, &hash[..LOCAL_MODEL_HASH_LENGTH])
+}
+
+impl Embedder for FastEmbedder {
+    fn backend(&self) -> EmbeddingBackend {
+        EmbeddingBackend::Fastembed
+    }
+
+    fn model_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.model
+            .embed(texts.to_vec(), Some(BATCH_SIZE))
+            .map_err(|e| Error::Other(format!("Failed to generate embeddings: {}", e)))
+    }
+}
+
+// =============================================================================
+// Hashing
+// =============================================================================
+
+/// Deterministic embeddings without a model.
+///
+/// Each term (a lowercased word without stop words and common suffixes) is
+/// hashed into one of a fixed number of dimensions with a hash-derived sign,
+/// weighted by `1 + ln(term frequency)`. Vectors are L2-normalized, so cosine
+/// similarity measures shared vocabulary rather than meaning.
+#[derive(Debug, Default)]
+pub struct HashingEmbedder;
+
+impl HashingEmbedder {
+    /// Create a hashing embedder.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Embed a single text.
+    fn embed_text(&self, text: &str) -> Vec<f32> {
+        let mut term_counts: HashMap<String, usize> = HashMap::new();
+        for term in terms(text) {
+            *term_counts.entry(term).or_default() += 1;
+        }
+
+        let mut vector = vec![0.0f32; HASHING_DIMENSIONS];
+        for (term, count) in term_counts {
+            let hash = fnv1a(term.as_bytes());
+            let index = (hash % HASHING_DIMENSIONS as u64) as usize;
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            vector[index] += sign * (1.0 + (count as f32).ln());
+        }
+
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|x| *x /= norm);
+        }
+        vector
+    }
+}
+
+impl Embedder for HashingEmbedder {
+    fn backend(&self) -> EmbeddingBackend {
+        EmbeddingBackend::Hashing
+    }
+
+    fn model_name(&self) -> String {
+        model_name(EmbeddingBackend::Hashing)
+    }
+
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        Ok(texts.iter().map(|text| self.embed_text(text)).collect())
+    }
+}
+
+/// The terms of a text: lowercased words, without stop words, crudely stemmed.
+fn terms(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.to_lowercase())
+        .filter(|word| word.len() > 1 && !STOP_WORDS.contains(&word.as_str()))
+        .map(|word| stem(&word))
+}
+
+/// Strip common English suffixes so that "logs", "logged" and "logging" match.
+fn stem(word: &str) -> String {
+    for suffix in ["ing", "ed", "es", "s"] {
+        if let Some(stem) = word.strip_suffix(suffix) {
+            if stem.chars().count() >= 3 {
+                return stem.to_string();
+            }
+        }
+    }
+    word.to_string()
+}
+
+/// 64-bit FNV-1a hash; stable across platforms and Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+// =============================================================================
+// Lazy loading
+// =============================================================================
+
+/// An embedder for the configured backend that loads it on first use.
+pub struct LazyEmbedder {
+    backend: EmbeddingBackend,
+    /// Local model directory for the fastembed backend.
+    model_path: Option<PathBuf>,
+    /// Model name, computed once (hashing a local model reads the whole file).
+    model_name: OnceLock<String>,
+    embedder: OnceLock<Box<dyn Embedder>>,
+}
+
+impl LazyEmbedder {
+    /// Create an embedder without loading the backend.
+    pub fn new(backend: EmbeddingBackend) -> Self {
+        Self {
+            backend,
+            model_path: None,
+            model_name: OnceLock::new(),
+            embedder: OnceLock::new(),
+        }
+    }
+
+    /// Create an embedder for the `[search]` config without loading the backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The project config
+    /// * `project_root` - Directory that a relative `model_path` is resolved against
+    pub fn from_config(config: &Config, project_root: &Path) -> Self {
+        let mut embedder = Self::new(config.embedding_backend());
+        if embedder.backend == EmbeddingBackend::Fastembed {
+            embedder.model_path = config.model_path().map(|path| project_root.join(path));
+        }
+        embedder
+    }
+
+    /// The loaded embedder, loading the backend if this is the first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be loaded. The next call tries again.
+    fn get(&self) -> Result<&dyn Embedder> {
+        if let Some(embedder) = self.embedder.get() {
+            return Ok(embedder.as_ref());
+        }
+
+        // Two threads may both load the model; the first one stored wins
+        let embedder: Box<dyn Embedder> = match (self.backend, &self.model_path) {
+            (EmbeddingBackend::Fastembed, Some(dir)) => Box::new(FastEmbedder::from_dir(dir)?),
+            (EmbeddingBackend::Fastembed, None) => Box::new(FastEmbedder::new()?),
+            (EmbeddingBackend::Hashing, _) => Box::new(HashingEmbedder::new()),
+        };
+        Ok(self.embedder.get_or_init(|| embedder).as_ref())
+    }
+
+    /// Whether the backend has been loaded.
+    #[cfg(test)]
+    pub fn is_loaded(&self) -> bool {
+        self.embedder.get().is_some()
+    }
+}
+
+impl Embedder for LazyEmbedder {
+    fn backend(&self) -> EmbeddingBackend {
+        self.backend
+    }
+
+    fn model_name(&self) -> String {
+        if let Some(embedder) = self.embedder.get() {
+            return embedder.model_name();
+        }
+
+        self.model_name
+            .get_or_init(|| match &self.model_path {
+                // An unreadable model is reported when it is loaded
+                Some(dir) => local_onnx_path(dir)
+                    .and_then(|path| read_model_file(&path))
+                    .map(|onnx_file| local_model_name(dir, &onnx_file))
+                    .unwrap_or_else(|_| format!("local:{}", dir.display())),
+                None => model_name(self.backend),
+            })
+            .clone()
+    }
+
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.get()?.embed(texts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    // ==================== HashingEmbedder tests ====================
+
+    #[test]
+    fn test_hashing_embedder_is_deterministic_and_normalized() {
+        let embedder = HashingEmbedder::new();
+        let a = embedder.embed_one("Users log in with a password").unwrap();
+        let b = embedder.embed_one("Users log in with a password").unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(a.len(), HASHING_DIMENSIONS);
+        let norm = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!(
+            (norm - 1.0).abs() < 1e-5,
+            "Expected unit vector, norm {}",
+            norm
+        );
+    }
+
+    #[test]
+    fn test_hashing_embedder_ranks_shared_terms_higher() {
+        let embedder = HashingEmbedder::new();
+        let query = embedder.embed_one("password login").unwrap();
+        let auth = embedder
+            .embed_one("The system SHALL let users login with their passwords.")
+            .unwrap();
+        let billing = embedder
+            .embed_one("The system SHALL send invoices every month.")
+            .unwrap();
+
+        assert!(cosine(&query, &auth) > cosine(&query, &billing));
+        assert!(cosine(&query, &auth) > 0.3);
+    }
+
+    #[test]
+    fn test_hashing_embedder_empty_text_is_zero_vector() {
+        let embedding = HashingEmbedder::new().embed_one("the and of").unwrap();
+        assert!(embedding.iter().all(|x| *x == 0.0));
+    }
+
+    #[test]
+    fn test_stem_strips_suffixes() {
+        assert_eq!(stem("logging"), "logg");
+        assert_eq!(stem("logged"), "logg");
+        assert_eq!(stem("passwords"), "password");
+        assert_eq!(stem("is"), "is");
+        assert_eq!(stem("uses"), "use");
+    }
+
+    #[test]
+    fn test_fnv1a_known_value() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    // ==================== LazyEmbedder tests ====================
+
+    #[test]
+    fn test_lazy_embedder_does_not_load_backend_until_used() {
+        let embedder = LazyEmbedder::new(EmbeddingBackend::Fastembed);
+        assert!(!embedder.is_loaded());
+        assert_eq!(embedder.model_name(), "AllMiniLML6V2");
+
+        assert!(embedder.embed(&[]).unwrap().is_empty());
+        assert!(!embedder.is_loaded());
+    }
+
+    #[test]
+    fn test_lazy_embedder_hashing_backend() {
+        let embedder = LazyEmbedder::new(EmbeddingBackend::Hashing);
+        let embedding = embedder.embed_one("login").unwrap();
+
+        assert!(embedder.is_loaded());
+        assert_eq!(embedder.model_name(), "hashing-512");
+        assert_eq!(
+            embedding,
+            HashingEmbedder::new().embed_one("login").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lazy_embedder_from_config_resolves_model_path() {
+        let config: Config = toml::from_str(
+            r#"
+[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+
+[search]
+model_path = "models/minilm"
+"#,
+        )
+        .unwrap();
+
+        let embedder = LazyEmbedder::from_config(&config, Path::new("/project"));
+        assert_eq!(
+            embedder.model_path,
+            Some(PathBuf::from("/project/models/minilm"))
+        );
+        assert_eq!(embedder.model_name(), "local:/project/models/minilm");
+        assert!(!embedder.is_loaded());
+    }
+
+    // ==================== Local model tests ====================
+
+    /// Write a fake local model directory with all required files.
+    fn write_local_model(dir: &Path, onnx_file: &str) {
+        fs::create_dir_all(dir.join("onnx")).unwrap();
+        fs::write(dir.join(onnx_file), b"not really onnx").unwrap();
+        for file in [
+            "tokenizer.json",
+            "config.json",
+            "special_tokens_map.json",
+            "tokenizer_config.json",
+        ] {
+            fs::write(dir.join(file), b"{}").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_local_model_name_records_onnx_hash() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path().join("minilm");
+        write_local_model(&dir, "onnx/model.onnx");
+
+        let local_embedder = || {
+            let mut embedder = LazyEmbedder::new(EmbeddingBackend::Fastembed);
+            embedder.model_path = Some(dir.clone());
+            embedder
+        };
+        let name = local_embedder().model_name();
+        assert!(name.starts_with("local:minilm@"), "Got {}", name);
+        assert_eq!(name.len(), "local:minilm@".len() + LOCAL_MODEL_HASH_LENGTH);
+
+        // A different model in the same directory gets a different name
+        fs::write(dir.join("onnx/model.onnx"), b"another model").unwrap();
+        assert_ne!(local_embedder().model_name(), name);
+    }
+
+    #[test]
+    fn test_from_dir_reports_missing_onnx_model() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+
+        let err = FastEmbedder::from_dir(temp_dir.path())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("No ONNX model found"), "Got {}", err);
+        assert!(err.contains("model.onnx or onnx/model.onnx"), "Got {}", err);
+    }
+
+    #[test]
+    fn test_from_dir_reports_missing_tokenizer_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        write_local_model(temp_dir.path(), "model.onnx");
+        fs::remove_file(temp_dir.path().join("special_tokens_map.json")).unwrap();
+
+        let err = FastEmbedder::from_dir(temp_dir.path())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Failed to read model file"), "Got {}", err);
+        assert!(err.contains("special_tokens_map.json"), "Got {}", err);
+    }
+
+    #[test]
+    #[ignore] // Requires embedding model download
+    fn test_fastembed_batch_matches_single_embeddings() {
+        let embedder = FastEmbedder::new().expect("Failed to load model");
+
+        let texts = ["Users log in with a password.", "Payments are processed."];
+        let batch = embedder.embed(&texts).unwrap();
+
+        assert_eq!(batch.len(), 2);
+        for (text, embedding) in texts.iter().zip(&batch) {
+            let single = embedder.embed_one(text).unwrap();
+            assert_eq!(single.len(), embedding.len());
+            assert!(single
+                .iter()
+                .zip(embedding)
+                .all(|(a, b)| (a - b).abs() < 1e-4));
+        }
+    }
+}
@@ -0,0 +1,652 @@
+//! Spec writer and formatter for Spec Oxide Core.
+//!
+//! This module turns structured specs back into markdown and normalises
+//! existing spec and delta files to the layout of `templates/specs/spec.md`:
+//!
+//! - Canonical heading levels (`##` sections, `### Requirement:`, `#### Scenario:`)
+//! - Scenario clauses as `- **WHEN**` / `- **THEN**` / `- **AND**` bullets
+//! - Body text directly below its heading, one blank line before each heading
+//! - Requirement descriptions above their scenarios
+//! - Sections in template order (Purpose, Requirements, ADDED, MODIFIED, REMOVED, RENAMED)
+//!
+//! Formatting never drops content: free text, code blocks and unknown sections
+//! are kept as written.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::error::{Error, Result};
+use super::markdown::{Document, Heading, SectionKind};
+use super::spec::{ParsedRequirement, ParsedSpec};
+use crate::config::Config;
+use crate::show::change::{DeltaItem, DeltaOp};
+
+// =============================================================================
+// Writer
+// =============================================================================
+
+/// Render a parsed spec as markdown.
+///
+/// The output follows the spec template layout and parses back into an equal
+/// `ParsedSpec`.
+pub fn render_spec(spec: &ParsedSpec) -> String {
+    let mut blocks = vec![
+        format!("# {}", spec.title),
+        format!("## Purpose\n{}", spec.purpose),
+        "## Requirements".to_string(),
+    ];
+    blocks.extend(spec.requirements.iter().map(render_requirement));
+
+    let mut output = blocks.join("\n\n");
+    output.push('\n');
+    output
+}
+
+/// Render a single requirement block (heading, description and scenarios).
+///
+/// The block has no trailing newline so it can be joined with other blocks.
+pub fn render_requirement(req: &ParsedRequirement) -> String {
+    let mut head = format!("### Requirement: {}", req.name);
+    if !req.description.is_empty() {
+        head.push('\n');
+        head.push_str(&req.description);
+    }
+
+    let mut blocks = vec![head];
+    for scenario in &req.scenarios {
+        let mut lines = vec![format!("#### Scenario: {}", scenario.name)];
+        if !scenario.when_clause.is_empty() {
+            lines.push(format!("- **WHEN** {}", scenario.when_clause));
+        }
+        for (i, clause) in scenario.then_clauses.iter().enumerate() {
+            let keyword = if i == 0 { "THEN" } else { "AND" };
+            lines.push(format!("- **{}** {}", keyword, clause));
+        }
+        blocks.push(lines.join("\n"));
+    }
+
+    blocks.join("\n\n")
+}
+
+/// Render delta items as a delta spec.
+///
+/// Items are grouped under their operation header in template order; each
+/// requirement block is written verbatim from the item's content.
+pub fn render_delta(items: &[DeltaItem]) -> String {
+    let mut blocks = Vec::new();
+
+    for op in DeltaOp::ALL {
+        let group: Vec<&DeltaItem> = items.iter().filter(|i| i.operation == op).collect();
+        if group.is_empty() {
+            continue;
+        }
+        blocks.push(format!("## {} Requirements", op.label()));
+        blocks.extend(group.iter().map(|i| i.content.trim_end().to_string()));
+    }
+
+    if blocks.is_empty() {
+        return String::new();
+    }
+
+    let mut output = blocks.join("\n\n");
+    output.push('\n');
+    output
+}
+
+// =============================================================================
+// Formatter
+// =============================================================================
+
+/// Normalise a spec or delta spec to the template layout.
+///
+/// Formatting is idempotent: formatting the output again returns it unchanged.
+pub fn format_markdown(content: &str) -> String {
+    let content = normalize_headings(content);
+    let content = order_requirement_parts(&content);
+    let content = normalize_body(&content);
+    order_sections(&content)
+}
+
+/// A spec or delta spec file that is not formatted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnformattedFile {
+    /// Path of the file, relative to the project root.
+    pub path: PathBuf,
+}
+
+/// Format all specs and the delta specs of all active changes.
+///
+/// Covers `<spec_folder>/<id>/spec.md` (skipping `_`-prefixed folders) and
+/// `<changes_folder>/<change>/specs/<capability>/spec.md`. Archived changes are
+/// left untouched.
+///
+/// # Arguments
+///
+/// * `project_root` - The root directory of the project
+/// * `config` - The loaded configuration with folder paths
+/// * `write` - Rewrite files in place; if false, only report them
+///
+/// # Returns
+///
+/// The files that were (or, without `write`, would be) reformatted, sorted by path.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or written.
+pub fn format_project(
+    project_root: &Path,
+    config: &Config,
+    write: bool,
+) -> Result<Vec<UnformattedFile>> {
+    let mut unformatted = Vec::new();
+
+    for relative in spec_files(project_root, config) {
+        let path = project_root.join(&relative);
+        let content = fs::read_to_string(&path)
+            .map_err(|e| Error::Other(format!("Failed to read {}: {}", path.display(), e)))?;
+
+        let formatted = format_markdown(&content);
+        if formatted == content {
+            continue;
+        }
+
+        if write {
+            fs::write(&path, &formatted)
+                .map_err(|e| Error::Other(format!("Failed to write {}: {}", path.display(), e)))?;
+        }
+        unformatted.push(UnformattedFile { path: relative });
+    }
+
+    Ok(unformatted)
+}
+
+/// Collect spec and delta spec files, relative to the project root.
+fn spec_files(project_root: &Path, config: &Config) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    let spec_folder = Path::new(config.spec_folder());
+    for name in subdirectories(&project_root.join(spec_folder)) {
+        if !name.starts_with('_') {
+            files.push(spec_folder.join(name).join("spec.md"));
+        }
+    }
+
+    let changes_folder = Path::new(config.changes_folder());
+    for change in subdirectories(&project_root.join(changes_folder)) {
+        let specs_dir = changes_folder.join(change).join("specs");
+        for capability in subdirectories(&project_root.join(&specs_dir)) {
+            files.push(specs_dir.join(capability).join("spec.md"));
+        }
+    }
+
+    files.retain(|f| project_root.join(f).is_file());
+    files.sort();
+    files
+}
+
+/// Names of the subdirectories of a directory (empty if it does not exist).
+fn subdirectories(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect()
+}
+
+/// Rewrite every heading to its canonical level and spelling.
+fn normalize_headings(content: &str) -> String {
+    let doc = Document::parse(content);
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+
+    for heading in &doc.headings {
+        lines[heading.span.start.line - 1] = canonical_heading(heading);
+    }
+
+    lines.join("\n")
+}
+
+/// The canonical form of a heading, based on its role.
+fn canonical_heading(heading: &Heading) -> String {
+    let text = heading.text.as_str();
+
+    let line = if let Some(name) = strip_prefix_ignore_case(text, "Requirement:") {
+        format!("### Requirement: {}", name.trim())
+    } else if let Some(name) = strip_prefix_ignore_case(text, "Scenario:") {
+        format!("#### Scenario: {}", name.trim())
+    } else if heading.level == 1 {
+        format!("# {}", text)
+    } else if let Some(section) = SectionKind::from_heading(text).heading_text() {
+        format!("## {}", section)
+    } else {
+        format!("{} {}", "#".repeat(heading.level), text)
+    };
+
+    line.trim_end().to_string()
+}
+
+/// Strip a prefix, ignoring ASCII case.
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = text.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &text[prefix.len()..])
+}
+
+/// Move requirement descriptions written below a scenario above the scenarios.
+///
+/// In a requirement without a description, the text following the last clause
+/// of a scenario is taken for the description.
+fn order_requirement_parts(content: &str) -> String {
+    let doc = Document::parse(content);
+    let mut moves: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    for req in doc.sections.iter().flat_map(|s| &s.requirements) {
+        if !req.description.is_empty() {
+            continue;
+        }
+        // Line indexes after the last clause up to the end of each scenario
+        let description: Vec<usize> = req
+            .scenarios
+            .iter()
+            .filter_map(|scenario| {
+                let last_clause = scenario.clauses.last()?;
+                Some(last_clause.span.start.line..scenario.span.end.line)
+            })
+            .flatten()
+            .collect();
+        if !description.is_empty() {
+            moves.insert(req.heading.span.start.line - 1, description);
+        }
+    }
+
+    if moves.is_empty() {
+        return content.to_string();
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let moved: HashSet<usize> = moves.values().flatten().copied().collect();
+    let mut out: Vec<&str> = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        if moved.contains(&idx) {
+            continue;
+        }
+        out.push(line);
+        if let Some(description) = moves.get(&idx) {
+            out.extend(description.iter().map(|&i| lines[i]));
+            out.push("");
+        }
+    }
+
+    let mut output = out.join("\n");
+    output.push('\n');
+    output
+}
+
+/// Normalise blank lines and scenario clause bullets.
+fn normalize_body(content: &str) -> String {
+    let doc = Document::parse(content);
+    let headings: HashSet<usize> = doc.headings.iter().map(|h| h.span.start.line - 1).collect();
+    let clauses: HashMap<usize, String> = doc
+        .sections
+        .iter()
+        .flat_map(|s| &s.requirements)
+        .flat_map(|r| &r.scenarios)
+        .flat_map(|s| &s.clauses)
+        .map(|c| {
+            let line = format!("- **{}** {}", c.kind.keyword(), c.text);
+            (c.span.start.line - 1, line.trim_end().to_string())
+        })
+        .collect();
+
+    let mut out: Vec<String> = Vec::new();
+    let mut in_fence = false;
+    let mut pending_blank = false;
+    let mut after_heading = false;
+    let mut last_was_clause = false;
+
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        let is_fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+
+        // Code blocks are copied verbatim
+        if in_fence {
+            out.push(line.to_string());
+            in_fence = !is_fence;
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            pending_blank = !out.is_empty() && !after_heading;
+            continue;
+        }
+
+        if headings.contains(&idx) {
+            if !out.is_empty() {
+                out.push(String::new());
+            }
+            out.push(trimmed.to_string());
+            pending_blank = false;
+            after_heading = true;
+            last_was_clause = false;
+            continue;
+        }
+
+        let clause = clauses.get(&idx);
+        if pending_blank && !(last_was_clause && clause.is_some()) {
+            out.push(String::new());
+        }
+        pending_blank = false;
+        after_heading = false;
+        last_was_clause = clause.is_some();
+
+        match clause {
+            Some(clause) => out.push(clause.clone()),
+            None => out.push(line.trim_end().to_string()),
+        }
+        in_fence = is_fence;
+    }
+
+    let mut output = out.join("\n");
+    if !output.is_empty() {
+        output.push('\n');
+    }
+    output
+}
+
+/// Position of a section kind in the template layout.
+fn section_rank(kind: SectionKind) -> Option<usize> {
+    match kind {
+        SectionKind::Purpose => Some(0),
+        SectionKind::Requirements => Some(1),
+        SectionKind::Delta(op) => DeltaOp::ALL.iter().position(|o| *o == op).map(|i| i + 2),
+        SectionKind::Other => None,
+    }
+}
+
+/// Put level-2 sections into template order.
+///
+/// Unknown sections move together with the known section they follow.
+fn order_sections(content: &str) -> String {
+    let doc = Document::parse(content);
+    let Some(first) = doc.sections.first() else {
+        return content.to_string();
+    };
+    let first_line = first.heading.span.start.line;
+
+    // A second top-level heading means the layout is not a single spec; leave it alone
+    if doc
+        .headings
+        .iter()
+        .any(|h| h.level == 1 && h.span.start.line > first_line)
+    {
+        return content.to_string();
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let starts: Vec<usize> = doc
+        .sections
+        .iter()
+        .map(|s| s.heading.span.start.line - 1)
+        .collect();
+
+    let mut rank = 0;
+    let mut chunks: Vec<(usize, String)> = Vec::new();
+    for (i, section) in doc.sections.iter().enumerate() {
+        rank = section_rank(section.kind).unwrap_or(rank);
+        let end = starts.get(i + 1).copied().unwrap_or(lines.len());
+        let chunk = lines[starts[i]..end].join("\n").trim_end().to_string();
+        chunks.push((rank, chunk));
+    }
+
+    if chunks.windows(2).all(|w| w[0].0 <= w[1].0) {
+        return content.to_string();
+    }
+    chunks.sort_by_key(|(rank, _)| *rank);
+
+    let prelude = lines[..starts[0]].join("\n").trim_end().to_string();
+    let mut blocks: Vec<String> = Vec::new();
+    if !prelude.is_empty() {
+        blocks.push(prelude);
+    }
+    blocks.extend(chunks.into_iter().map(|(_, chunk)| chunk));
+
+    let mut output = blocks.join("\n\n");
+    output.push('\n');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::spec::ParsedScenario;
+    use tempfile::TempDir;
+
+    const FORMATTED_SPEC: &str = r#"# Auth Specification
+
+## Purpose
+Define authentication for the system.
+
+## Requirements
+
+### Requirement: User Login
+The system SHALL allow users to login.
+
+#### Scenario: Successful login
+- **WHEN** user provides valid credentials
+- **THEN** user is authenticated
+- **AND** a session token is returned
+"#;
+
+    fn parse(content: &str) -> ParsedSpec {
+        crate::core::spec::parse_spec_content(content, "auth").unwrap()
+    }
+
+    // ==================== render_spec tests ====================
+
+    #[test]
+    fn test_render_spec_round_trips() {
+        let spec = ParsedSpec {
+            id: "auth".to_string(),
+            title: "Auth Specification".to_string(),
+            purpose: "Define authentication for the system.".to_string(),
+            requirements: vec![ParsedRequirement {
+                name: "User Login".to_string(),
+                description: "The system SHALL allow users to login.".to_string(),
+                scenarios: vec![ParsedScenario {
+                    name: "Successful login".to_string(),
+                    when_clause: "user provides valid credentials".to_string(),
+                    then_clauses: vec![
+                        "user is authenticated".to_string(),
+                        "a session token is returned".to_string(),
+                    ],
+                }],
+            }],
+        };
+
+        let rendered = render_spec(&spec);
+
+        assert_eq!(rendered, FORMATTED_SPEC);
+        assert_eq!(parse(&rendered), spec);
+    }
+
+    #[test]
+    fn test_render_spec_of_parsed_spec_round_trips() {
+        let spec = parse(FORMATTED_SPEC);
+
+        assert_eq!(parse(&render_spec(&spec)), spec);
+    }
+
+    #[test]
+    fn test_render_delta_groups_by_operation() {
+        let item = |operation, name: &str| DeltaItem {
+            operation,
+            name: name.to_string(),
+            text: String::new(),
+            scenarios: vec![],
+            content: format!("### Requirement: {}\nThe system SHALL {}.\n", name, name),
+        };
+        let items = vec![item(DeltaOp::Removed, "Old"), item(DeltaOp::Added, "New")];
+
+        let rendered = render_delta(&items);
+
+        assert_eq!(
+            rendered,
+            "## ADDED Requirements\n\n### Requirement: New\nThe system SHALL New.\n\n## REMOVED Requirements\n\n### Requirement: Old\nThe system SHALL Old.\n"
+        );
+    }
+
+    // ==================== format_markdown tests ====================
+
+    #[test]
+    fn test_format_keeps_formatted_spec_unchanged() {
+        assert_eq!(format_markdown(FORMATTED_SPEC), FORMATTED_SPEC);
+    }
+
+    #[test]
+    fn test_format_normalizes_layout() {
+        let messy = "# Auth Specification\n\n\n## purpose\n\nDefine authentication for the system.\n\n## Requirements\n## Requirement:User Login\n\nThe system SHALL allow users to login.\n\n### Scenario: Successful login\n\n* WHEN user provides valid credentials\n\n1. **then** user is authenticated\n- **And**: a session token is returned\n\n\n";
+
+        assert_eq!(format_markdown(messy), FORMATTED_SPEC);
+    }
+
+    #[test]
+    fn test_format_orders_sections() {
+        let content = "# T\n\n## Requirements\n\n### Requirement: A\nThe system SHALL a.\n\n## Notes\nKeep me.\n\n## Purpose\nWhy.\n";
+
+        assert_eq!(
+            format_markdown(content),
+            "# T\n\n## Purpose\nWhy.\n\n## Requirements\n\n### Requirement: A\nThe system SHALL a.\n\n## Notes\nKeep me.\n"
+        );
+    }
+
+    #[test]
+    fn test_format_orders_delta_sections() {
+        let content = "## REMOVED Requirements\n\n### Requirement: Old\n**Reason**: Gone\n\n## ADDED Requirements\n\n### Requirement: New\nThe system SHALL new.\n";
+
+        let formatted = format_markdown(content);
+
+        assert!(formatted.starts_with("## ADDED Requirements"));
+        assert!(formatted.contains("**Reason**: Gone"));
+    }
+
+    #[test]
+    fn test_format_moves_description_above_scenarios() {
+        let content = "## Requirements\n\n### Requirement: A\n\n#### Scenario: S\n- **WHEN** x\n- **THEN** y\n\nThe system SHALL a.\n\n### Requirement: B\nThe system SHALL b.\n";
+
+        let formatted = format_markdown(content);
+
+        assert_eq!(
+            formatted,
+            "## Requirements\n\n### Requirement: A\nThe system SHALL a.\n\n#### Scenario: S\n- **WHEN** x\n- **THEN** y\n\n### Requirement: B\nThe system SHALL b.\n"
+        );
+        let doc = Document::parse(&formatted);
+        assert_eq!(
+            doc.spec_requirements()[0].description,
+            "The system SHALL a."
+        );
+        assert_eq!(format_markdown(&formatted), formatted);
+    }
+
+    #[test]
+    fn test_format_keeps_scenario_notes_of_described_requirement() {
+        let content = "## Requirements\n\n### Requirement: A\nThe system SHALL a.\n\n#### Scenario: S\n- **WHEN** x\n- **THEN** y\n\nNote on the scenario.\n";
+
+        assert_eq!(format_markdown(content), content);
+    }
+
+    #[test]
+    fn test_format_preserves_code_blocks() {
+        let content =
+            "# T\n\n## Purpose\nWhy.\n\n```text\n## Not a heading\n\n\n- WHEN not a clause\n```\n";
+
+        assert_eq!(format_markdown(content), content);
+    }
+
+    #[test]
+    fn test_format_is_idempotent() {
+        let messy = "## removed requirements\n### Requirement: B\n\n\n## added requirements\n\n\n### Requirement: A\nText\n#### Scenario: S\n- WHEN x\n\n- THEN y\n";
+
+        let once = format_markdown(messy);
+
+        assert_eq!(format_markdown(&once), once);
+    }
+
+    #[test]
+    fn test_format_preserves_parsed_content() {
+        let messy = "# Auth Specification\n## Purpose\nDefine authentication for the system.\n## Requirements\n### Requirement: User Login\nThe system SHALL allow users to login.\n#### Scenario: Successful login\n- WHEN user provides valid credentials\n- THEN user is authenticated\n- AND a session token is returned";
+
+        assert_eq!(parse(&format_markdown(messy)), parse(messy));
+    }
+
+    // ==================== format_project tests ====================
+
+    fn create_project() -> (TempDir, Config) {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        fs::create_dir_all(root.join("specs/auth")).unwrap();
+        fs::write(root.join("specs/auth/spec.md"), FORMATTED_SPEC).unwrap();
+        fs::create_dir_all(root.join("specs/_changes/add-2fa/specs/auth")).unwrap();
+        fs::write(
+            root.join("specs/_changes/add-2fa/specs/auth/spec.md"),
+            "## ADDED Requirements\n\n\n### Requirement: Two-Factor\nThe system SHALL ask for a code.\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("specs/_archive/2025-01-01-old/specs/auth")).unwrap();
+        fs::write(
+            root.join("specs/_archive/2025-01-01-old/specs/auth/spec.md"),
+            "## ADDED Requirements\n\n\n",
+        )
+        .unwrap();
+
+        let config: Config = toml::from_str(
+            r#"
+[paths]
+spec_folder = "specs/"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#,
+        )
+        .unwrap();
+
+        (temp_dir, config)
+    }
+
+    #[test]
+    fn test_format_project_check_reports_without_writing() {
+        let (temp_dir, config) = create_project();
+        let root = temp_dir.path();
+        let delta = root.join("specs/_changes/add-2fa/specs/auth/spec.md");
+        let before = fs::read_to_string(&delta).unwrap();
+
+        let unformatted = format_project(root, &config, false).unwrap();
+
+        assert_eq!(unformatted.len(), 1);
+        assert_eq!(
+            unformatted[0].path,
+            Path::new("specs/_changes/add-2fa/specs/auth/spec.md")
+        );
+        assert_eq!(fs::read_to_string(&delta).unwrap(), before);
+    }
+
+    #[test]
+    fn test_format_project_writes_files() {
+        let (temp_dir, config) = create_project();
+        let root = temp_dir.path();
+
+        format_project(root, &config, true).unwrap();
+
+        assert!(format_project(root, &config, false).unwrap().is_empty());
+        let archived =
+            fs::read_to_string(root.join("specs/_archive/2025-01-01-old/specs/auth/spec.md"))
+                .unwrap();
+        assert_eq!(archived, "## ADDED Requirements\n\n\n");
+    }
+}
@@ -0,0 +1,424 @@
+//! Approximate nearest neighbour search for the search index.
+//!
+//! Semantic search compares the query embedding with every indexed text. That
+//! is fast for a project's specs, but grows linearly with the number of texts,
+//! so large indexes also carry a Hierarchical Navigable Small World (HNSW)
+//! graph: every text is a node linked to its most similar texts, on a stack of
+//! ever sparser layers. A search walks greedily from the top layer down and
+//! only scores the nodes it visits.
+//!
+//! The graph stores node ids only. Nodes are the positions of the embeddings
+//! it was built from, so the same embeddings in the same order must be passed
+//! to `search`.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of links per node above layer 0.
+const M: usize = 16;
+
+/// Maximum number of links per node on layer 0.
+const M0: usize = 2 * M;
+
+/// Number of candidates kept while inserting a node.
+const EF_CONSTRUCTION: usize = 100;
+
+/// Minimum number of candidates kept while searching.
+const EF_SEARCH: usize = 64;
+
+/// An HNSW graph over a list of embeddings, compared by cosine similarity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hnsw {
+    /// The links of every node, per layer from 0 up to the node's level.
+    links: Vec<Vec<Vec<u32>>>,
+    /// The node on the top layer where every search starts.
+    entry_point: Option<u32>,
+}
+
+/// A node and its similarity to the vector being searched for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    similarity: f32,
+    node: u32,
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.similarity
+            .total_cmp(&other.similarity)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hnsw {
+    /// Build the graph over `vectors`, inserting them in order.
+    ///
+    /// Node levels are derived from the node ids, so the same vectors always
+    /// produce the same graph.
+    pub fn build(vectors: &[&[f32]]) -> Self {
+        // Compare unit vectors by their dot product instead of recomputing
+        // norms for every comparison
+        let normalized: Vec<Vec<f32>> = vectors.iter().map(|vector| normalize(vector)).collect();
+        let normalized: Vec<&[f32]> = normalized.iter().map(Vec::as_slice).collect();
+
+        let mut graph = Self {
+            links: Vec::with_capacity(vectors.len()),
+            entry_point: None,
+        };
+        for node in 0..vectors.len() {
+            graph.insert(&normalized, node as u32);
+        }
+        graph
+    }
+
+    /// Number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    /// Find the (approximately) `k` most similar vectors to `query`.
+    ///
+    /// # Arguments
+    ///
+    /// * `vectors` - The vectors the graph was built from, in the same order
+    /// * `query` - The vector to search for
+    /// * `k` - The number of nodes to return
+    ///
+    /// # Returns
+    ///
+    /// Node ids with their cosine similarity to `query`, most similar first.
+    pub fn search(&self, vectors: &[&[f32]], query: &[f32], k: usize) -> Vec<(usize, f32)> {
+        let Some(entry_point) = self.entry_point else {
+            return Vec::new();
+        };
+
+        let similarity = cosine_similarity;
+        let mut entry = Candidate {
+            similarity: similarity(query, vectors[entry_point as usize]),
+            node: entry_point,
+        };
+        for layer in (1..self.level(entry_point) + 1).rev() {
+            entry = self.search_layer(vectors, similarity, query, entry, 1, layer)[0];
+        }
+
+        let mut found = self.search_layer(vectors, similarity, query, entry, k.max(EF_SEARCH), 0);
+        found.truncate(k);
+        found
+            .into_iter()
+            .map(|candidate| (candidate.node as usize, candidate.similarity))
+            .collect()
+    }
+
+    /// Link a new node into the graph.
+    ///
+    /// `vectors` must be normalized.
+    fn insert(&mut self, vectors: &[&[f32]], node: u32) {
+        let level = random_level(node);
+        self.links.push(vec![Vec::new(); level + 1]);
+
+        let Some(entry_point) = self.entry_point else {
+            self.entry_point = Some(node);
+            return;
+        };
+
+        let query = vectors[node as usize];
+        let top = self.level(entry_point);
+        let mut entry = Candidate {
+            similarity: dot(query, vectors[entry_point as usize]),
+            node: entry_point,
+        };
+
+        // Descend greedily to the node's own top layer
+        for layer in (level + 1..top + 1).rev() {
+            entry = self.search_layer(vectors, dot, query, entry, 1, layer)[0];
+        }
+
+        // Link to the most similar nodes on each of the node's layers
+        for layer in (0..level.min(top) + 1).rev() {
+            let candidates = self.search_layer(vectors, dot, query, entry, EF_CONSTRUCTION, layer);
+            let max_links = max_links(layer);
+            let neighbours: Vec<u32> = candidates
+                .iter()
+                .take(max_links)
+                .map(|candidate| candidate.node)
+                .collect();
+
+            for &neighbour in &neighbours {
+                let links = &mut self.links[neighbour as usize][layer];
+                links.push(node);
+                if links.len() > max_links {
+                    self.prune(vectors, neighbour, layer);
+                }
+            }
+            self.links[node as usize][layer] = neighbours;
+            entry = candidates[0];
+        }
+
+        if level > top {
+            self.entry_point = Some(node);
+        }
+    }
+
+    /// Keep only the most similar links of a node that has too many.
+    ///
+    /// `vectors` must be normalized.
+    fn prune(&mut self, vectors: &[&[f32]], node: u32, layer: usize) {
+        let vector = vectors[node as usize];
+        let links = &mut self.links[node as usize][layer];
+        let mut scored: Vec<Candidate> = links
+            .iter()
+            .map(|&link| Candidate {
+                similarity: dot(vector, vectors[link as usize]),
+                node: link,
+            })
+            .collect();
+        scored.sort_by(|a, b| b.cmp(a));
+        *links = scored
+            .into_iter()
+            .take(max_links(layer))
+            .map(|candidate| candidate.node)
+            .collect();
+    }
+
+    /// Best-first search on one layer, starting from `entry`.
+    ///
+    /// # Returns
+    ///
+    /// Up to `ef` nodes, most similar first.
+    fn search_layer(
+        &self,
+        vectors: &[&[f32]],
+        similarity: fn(&[f32], &[f32]) -> f32,
+        query: &[f32],
+        entry: Candidate,
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Candidate> {
+        let mut visited = HashSet::from([entry.node]);
+        // Nodes to expand, most similar first
+        let mut candidates = BinaryHeap::from([entry]);
+        // Best nodes found so far, least similar first
+        let mut found = BinaryHeap::from([Reverse(entry)]);
+
+        while let Some(candidate) = candidates.pop() {
+            let worst = found.peek().map_or(f32::MIN, |Reverse(c)| c.similarity);
+            if candidate.similarity < worst && found.len() >= ef {
+                break;
+            }
+
+            for &link in &self.links[candidate.node as usize][layer] {
+                if !visited.insert(link) {
+                    continue;
+                }
+                let next = Candidate {
+                    similarity: similarity(query, vectors[link as usize]),
+                    node: link,
+                };
+                let worst = found.peek().map_or(f32::MIN, |Reverse(c)| c.similarity);
+                if found.len() < ef || next.similarity > worst {
+                    candidates.push(next);
+                    found.push(Reverse(next));
+                    if found.len() > ef {
+                        found.pop();
+                    }
+                }
+            }
+        }
+
+        let mut found: Vec<Candidate> = found.into_iter().map(|Reverse(c)| c).collect();
+        found.sort_by(|a, b| b.cmp(a));
+        found
+    }
+
+    /// The top layer of a node.
+    fn level(&self, node: u32) -> usize {
+        self.links[node as usize].len() - 1
+    }
+}
+
+/// Maximum number of links per node on a layer.
+fn max_links(layer: usize) -> usize {
+    if layer == 0 {
+        M0
+    } else {
+        M
+    }
+}
+
+/// The top layer of a node: level `l` with probability `(1 - 1/M) / M^l`.
+///
+/// Uses a hash of the node id instead of a random number generator, so builds
+/// are reproducible.
+fn random_level(node: u32) -> usize {
+    // SplitMix64 finalizer
+    let mut x = u64::from(node).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^= x >> 31;
+
+    // Uniform in (0, 1]
+    let uniform = ((x >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+    (-uniform.ln() / (M as f64).ln()) as usize
+}
+
+/// Dot product of two vectors: their cosine similarity if both are normalized.
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Euclidean norm of a vector.
+fn norm(a: &[f32]) -> f32 {
+    dot(a, a).sqrt()
+}
+
+/// A vector scaled to unit length; a zero vector stays zero.
+fn normalize(a: &[f32]) -> Vec<f32> {
+    let norm = norm(a);
+    if norm == 0.0 {
+        return a.to_vec();
+    }
+    a.iter().map(|x| x / norm).collect()
+}
+
+/// Cosine similarity of two vectors, 0.0 if either is zero.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let (norm_a, norm_b) = (norm(a), norm(b));
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot(a, b) / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pseudo-random vectors with a deterministic generator.
+    fn random_vectors(count: usize, dimensions: usize, seed: u64) -> Vec<Vec<f32>> {
+        let mut state = seed;
+        let mut next = || {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 40) as f32 / (1u64 << 24) as f32 - 0.5
+        };
+        (0..count)
+            .map(|_| (0..dimensions).map(|_| next()).collect())
+            .collect()
+    }
+
+    /// The `k` most similar vectors by exhaustive comparison.
+    fn exact_search(vectors: &[&[f32]], query: &[f32], k: usize) -> Vec<usize> {
+        let mut scored: Vec<(usize, f32)> = vectors
+            .iter()
+            .enumerate()
+            .map(|(i, vector)| (i, cosine_similarity(query, vector)))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored.into_iter().take(k).map(|(i, _)| i).collect()
+    }
+
+    #[test]
+    fn test_empty_graph_finds_nothing() {
+        let graph = Hnsw::build(&[]);
+        assert_eq!(graph.len(), 0);
+        assert!(graph.search(&[], &[1.0, 0.0], 5).is_empty());
+    }
+
+    #[test]
+    fn test_finds_exact_match() {
+        let vectors = random_vectors(500, 16, 7);
+        let refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
+        let graph = Hnsw::build(&refs);
+
+        for node in [0, 123, 499] {
+            let found = graph.search(&refs, &vectors[node], 1);
+            assert_eq!(found[0].0, node);
+            assert!((found[0].1 - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_results_are_sorted_and_limited() {
+        let vectors = random_vectors(300, 8, 11);
+        let refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
+        let graph = Hnsw::build(&refs);
+
+        let found = graph.search(&refs, &random_vectors(1, 8, 99)[0], 10);
+
+        assert_eq!(found.len(), 10);
+        assert!(found.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    }
+
+    #[test]
+    fn test_recall_against_exact_search() {
+        let vectors = random_vectors(2000, 16, 42);
+        let refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
+        let graph = Hnsw::build(&refs);
+        let queries = random_vectors(50, 16, 1234);
+        let k = 10;
+
+        let mut hits = 0;
+        for query in &queries {
+            let exact: HashSet<usize> = exact_search(&refs, query, k).into_iter().collect();
+            hits += graph
+                .search(&refs, query, k)
+                .iter()
+                .filter(|(node, _)| exact.contains(node))
+                .count();
+        }
+
+        let recall = hits as f32 / (queries.len() * k) as f32;
+        assert!(recall >= 0.95, "Recall@{} too low: {}", k, recall);
+    }
+
+    #[test]
+    fn test_build_is_deterministic() {
+        let vectors = random_vectors(200, 8, 3);
+        let refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
+
+        let a = Hnsw::build(&refs);
+        let b = Hnsw::build(&refs);
+
+        assert_eq!(a.links, b.links);
+        assert_eq!(a.entry_point, b.entry_point);
+    }
+
+    #[test]
+    fn test_node_links_are_bounded() {
+        let vectors = random_vectors(1000, 8, 5);
+        let refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
+        let graph = Hnsw::build(&refs);
+
+        for node_links in &graph.links {
+            for (layer, links) in node_links.iter().enumerate() {
+                assert!(links.len() <= max_links(layer));
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_level_distribution() {
+        let levels: Vec<usize> = (0..10_000).map(random_level).collect();
+        let layer_zero_only = levels.iter().filter(|&&level| level == 0).count();
+
+        // About 1 - 1/M of the nodes live on layer 0 only
+        assert!(
+            (9_000..9_700).contains(&layer_zero_only),
+            "{}",
+            layer_zero_only
+        );
+        assert!(levels.iter().all(|&level| level < 8));
+    }
+}
@@ -1,28 +1,54 @@
 //! Search index for Spec Oxide.
 //!
-//! This module provides semantic search capabilities over specs using embeddings.
-//! It uses fastembed for local embedding generation and cosine similarity for search.
+//! This module provides search over specs, scenarios, active changes and
+//! archived changes using embeddings and keywords. It uses the embedder from
+//! `core::embedding` and cosine similarity for semantic search, BM25 from
+//! `core::bm25` for keyword search, and reciprocal rank fusion to combine both.
+//!
+//! The index file starts with a versioned header and records a fingerprint of
+//! the indexed texts, so an index from another spox version or one that no
+//! longer matches the specs and changes is detected instead of silently used.
+//!
+//! Indexes with at least `ANN_THRESHOLD` texts also get an HNSW graph from
+//! `core::hnsw`, saved next to the index file, so semantic search does not have
+//! to compare the query with every text.
 
+use std::collections::HashMap;
+use std::fmt;
 use std::fs;
-use std::io::{BufReader, BufWriter};
-use std::path::Path;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-use fastembed::{EmbeddingModel, InitOptions, TextEmbedding};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
+use super::bm25::{self, Bm25, TermCounts};
+use super::embedding::Embedder;
 use super::error::{Error, Result};
-use super::spec::ParsedSpec;
+use super::hnsw::Hnsw;
+use super::spec::{ParsedScenario, ParsedSpec};
+use crate::config::{Config, EmbeddingBackend};
+use crate::show::change::{parse_change, DeltaGroup};
 
-/// The embedding model to use.
-const EMBEDDING_MODEL: EmbeddingModel = EmbeddingModel::AllMiniLML6V2;
-
-/// The search index containing all spec embeddings.
+/// The search index containing all spec and change embeddings.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SpecIndex {
+    /// The embedding backend that generated the embeddings.
+    pub backend: EmbeddingBackend,
     /// The name of the model used to generate embeddings.
     pub model_name: String,
+    /// Fingerprint of the specs and changes the index was built from.
+    pub fingerprint: String,
     /// All indexed specs.
     pub specs: Vec<IndexedSpec>,
+    /// All indexed active and archived changes.
+    pub changes: Vec<IndexedChange>,
+    /// Approximate nearest neighbour graph over the embeddings of `texts`,
+    /// for indexes with at least `ANN_THRESHOLD` texts. Saved in its own file.
+    #[serde(skip)]
+    pub ann: Option<Hnsw>,
 }
 
 /// An indexed spec with its embeddings.
@@ -32,8 +58,12 @@ pub struct IndexedSpec {
     pub id: String,
     /// The spec title.
     pub title: String,
+    /// SHA-256 of the purpose text the embedding was generated from.
+    pub purpose_hash: String,
     /// The embedding for the spec purpose.
     pub purpose_embedding: Vec<f32>,
+    /// Keyword term counts of the spec title and purpose.
+    pub purpose_terms: TermCounts,
     /// All indexed requirements.
     pub requirements: Vec<IndexedRequirement>,
 }
@@ -45,30 +75,274 @@ pub struct IndexedRequirement {
     pub name: String,
     /// The requirement description.
     pub description: String,
+    /// SHA-256 of the description the embedding was generated from.
+    pub description_hash: String,
+    /// The embedding for the description.
+    pub description_embedding: Vec<f32>,
+    /// Keyword term counts of the requirement name and description.
+    pub description_terms: TermCounts,
+    /// All indexed scenarios.
+    pub scenarios: Vec<IndexedScenario>,
+}
+
+/// An indexed scenario with its embedding.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexedScenario {
+    /// The scenario name.
+    pub name: String,
+    /// The WHEN and THEN clauses as one text.
+    pub text: String,
+    /// SHA-256 of the text the embedding was generated from.
+    pub text_hash: String,
+    /// The embedding for the text.
+    pub text_embedding: Vec<f32>,
+    /// Keyword term counts of the scenario name and text.
+    pub text_terms: TermCounts,
+}
+
+/// An indexed change proposal with its embedding.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexedChange {
+    /// The change ID (folder name, dated for archived changes).
+    pub id: String,
+    /// Whether the change is in the archive folder.
+    pub archived: bool,
+    /// The proposal and design text.
+    pub text: String,
+    /// SHA-256 of the text the embedding was generated from.
+    pub text_hash: String,
+    /// The embedding for the text.
+    pub text_embedding: Vec<f32>,
+    /// Keyword term counts of the change ID and text.
+    pub text_terms: TermCounts,
+    /// The delta requirements of an active change.
+    pub deltas: Vec<IndexedDelta>,
+}
+
+/// An indexed delta requirement of an active change with its embedding.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexedDelta {
+    /// The capability the delta targets.
+    pub capability: String,
+    /// The requirement name as written in the delta.
+    pub name: String,
+    /// The requirement description.
+    pub description: String,
+    /// SHA-256 of the description the embedding was generated from.
+    pub description_hash: String,
     /// The embedding for the description.
     pub description_embedding: Vec<f32>,
+    /// Keyword term counts of the requirement name and description.
+    pub description_terms: TermCounts,
+}
+
+/// A change read from the changes or archive folder, ready to be indexed.
+#[derive(Debug, Clone)]
+pub struct ChangeSource {
+    /// The change ID (folder name).
+    pub id: String,
+    /// Whether the change is in the archive folder.
+    pub archived: bool,
+    /// The Why and What Changes sections of the proposal, followed by design.md.
+    pub text: String,
+    /// The delta requirements by capability (empty for archived changes, whose
+    /// deltas are already part of the specs).
+    pub deltas: Vec<DeltaGroup>,
+}
+
+/// What kind of text a search result matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResultKind {
+    /// A spec purpose.
+    Spec,
+    /// A requirement of a spec.
+    Requirement,
+    /// A scenario of a spec requirement.
+    Scenario,
+    /// An active change proposal or one of its delta requirements.
+    Change,
+    /// An archived change proposal.
+    Archive,
+}
+
+impl ResultKind {
+    /// All kinds, in index order.
+    pub const ALL: [ResultKind; 5] = [
+        ResultKind::Spec,
+        ResultKind::Requirement,
+        ResultKind::Scenario,
+        ResultKind::Change,
+        ResultKind::Archive,
+    ];
+
+    /// The kind name as accepted by `search_specs`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ResultKind::Spec => "spec",
+            ResultKind::Requirement => "requirement",
+            ResultKind::Scenario => "scenario",
+            ResultKind::Change => "change",
+            ResultKind::Archive => "archive",
+        }
+    }
+}
+
+impl fmt::Display for ResultKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ResultKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        ResultKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| {
+                format!(
+                    "Unknown result kind '{}': expected spec, requirement, scenario, change or archive",
+                    s
+                )
+            })
+    }
 }
 
 /// A search result with relevance score.
 #[derive(Debug, Clone)]
 pub struct SearchResult {
-    /// The spec ID.
+    /// What kind of text matched.
+    pub kind: ResultKind,
+    /// The spec ID; for changes, the capability of the matched delta (empty if
+    /// the proposal matched).
     pub spec_id: String,
-    /// The requirement name, if matched on a requirement (None if matched on spec purpose).
+    /// The requirement name, if matched on a requirement, scenario or delta
+    /// (None if matched on spec purpose or proposal).
     pub requirement: Option<String>,
-    /// The similarity score (0.0 to 1.0).
+    /// The scenario name, if matched on a scenario.
+    pub scenario: Option<String>,
+    /// The change ID, if matched on an active or archived change.
+    pub change_id: Option<String>,
+    /// The relevance score (0.0 to 1.0).
     pub score: f32,
     /// A snippet of the matched text.
     pub snippet: String,
 }
 
-/// Build a search index from parsed specs.
+/// Restrictions on what `search` returns.
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilter {
+    /// Only return results of these kinds (all kinds if empty).
+    pub kinds: Vec<ResultKind>,
+    /// Only return results of these specs or delta capabilities (all if empty).
+    pub spec_ids: Vec<String>,
+    /// Never return results of these specs or delta capabilities.
+    pub exclude_spec_ids: Vec<String>,
+    /// Only return results scoring at least this much.
+    pub min_score: Option<f32>,
+}
+
+impl SearchFilter {
+    /// Whether the filter only removes results by score, so it cannot remove
+    /// results that outrank the ones it keeps.
+    fn is_by_score_only(&self) -> bool {
+        self.kinds.is_empty() && self.spec_ids.is_empty() && self.exclude_spec_ids.is_empty()
+    }
+
+    /// Whether a result of the given kind, spec and score passes the filter.
+    fn allows(&self, kind: ResultKind, spec_id: &str, score: f32) -> bool {
+        (self.kinds.is_empty() || self.kinds.contains(&kind))
+            && (self.spec_ids.is_empty() || self.spec_ids.iter().any(|id| id == spec_id))
+            && !self.exclude_spec_ids.iter().any(|id| id == spec_id)
+            && self.min_score.is_none_or(|min_score| score >= min_score)
+    }
+}
+
+/// One page of ranked search results.
+#[derive(Debug, Clone)]
+pub struct SearchPage {
+    /// The results on the page, sorted by relevance score (highest first).
+    pub results: Vec<SearchResult>,
+    /// Number of results passing the filter across all pages.
+    ///
+    /// Approximate when the ANN graph of a large index answers the search:
+    /// only the nearest candidates are scored, so this is a lower bound.
+    pub total: usize,
+}
+
+/// How `search` ranks the index against a query.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Cosine similarity of embeddings: matches by meaning.
+    Semantic,
+    /// BM25 over terms: matches exact words and identifiers, no model needed.
+    Keyword,
+    /// Reciprocal rank fusion of the semantic and keyword rankings.
+    #[default]
+    Hybrid,
+}
+
+impl SearchMode {
+    /// The mode name as accepted by `search_specs` and `spox search`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SearchMode::Semantic => "semantic",
+            SearchMode::Keyword => "keyword",
+            SearchMode::Hybrid => "hybrid",
+        }
+    }
+}
+
+impl fmt::Display for SearchMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SearchMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "semantic" => Ok(SearchMode::Semantic),
+            "keyword" => Ok(SearchMode::Keyword),
+            "hybrid" => Ok(SearchMode::Hybrid),
+            other => Err(format!(
+                "Unknown search mode '{}': expected semantic, keyword or hybrid",
+                other
+            )),
+        }
+    }
+}
+
+/// What an index update changed, counted per indexed text (spec purpose,
+/// requirement, scenario, proposal or delta).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexChanges {
+    /// Number of specs in the index.
+    pub specs: usize,
+    /// Number of active and archived changes in the index.
+    pub changes: usize,
+    /// Texts that were not indexed before.
+    pub added: usize,
+    /// Texts that changed.
+    pub updated: usize,
+    /// Texts that no longer exist.
+    pub removed: usize,
+}
+
+/// Build a search index from parsed specs and changes.
 ///
-/// This function generates embeddings for all spec purposes and requirement descriptions.
+/// This function generates embeddings and keyword term counts for all spec
+/// purposes, requirement descriptions and scenarios, and for the proposals
+/// and delta requirements of the changes.
 ///
 /// # Arguments
 ///
 /// * `specs` - A slice of parsed specs to index
+/// * `changes` - The active and archived changes to index
+/// * `embedder` - The embedder; all texts are embedded in one batch
 ///
 /// # Returns
 ///
@@ -77,59 +351,451 @@ pub struct SearchResult {
 /// # Errors
 ///
 /// Returns an error if embedding generation fails.
-pub fn build_index(specs: &[ParsedSpec]) -> Result<SpecIndex> {
-    // Initialize the embedding model
-    let model =
-        TextEmbedding::try_new(InitOptions::new(EMBEDDING_MODEL).with_show_download_progress(true))
-            .map_err(|e| Error::Other(format!("Failed to initialize embedding model: {}", e)))?;
+pub fn build_index(
+    specs: &[ParsedSpec],
+    changes: &[ChangeSource],
+    embedder: &dyn Embedder,
+) -> Result<SpecIndex> {
+    update_index(None, specs, changes, embedder).map(|(index, _)| index)
+}
+
+/// Build a search index, reusing the embeddings of an earlier index.
+///
+/// Only texts whose content hash is not in `previous` are embedded, in one
+/// batch, so the embedding model is not even loaded when nothing changed. An
+/// index built with a different backend or model is ignored.
+///
+/// # Arguments
+///
+/// * `previous` - The existing index, if any
+/// * `specs` - A slice of parsed specs to index
+/// * `changes` - The active and archived changes to index
+/// * `embedder` - The embedder for new and changed texts
+///
+/// # Returns
+///
+/// The new `SpecIndex` and what changed compared to `previous`.
+///
+/// # Errors
+///
+/// Returns an error if embedding generation fails.
+pub fn update_index(
+    previous: Option<&SpecIndex>,
+    specs: &[ParsedSpec],
+    changes: &[ChangeSource],
+    embedder: &dyn Embedder,
+) -> Result<(SpecIndex, IndexChanges)> {
+    let previous = previous.filter(|index| is_compatible(index, embedder));
+
+    // Embeddings of the previous index by content hash, and the hash of every
+    // previously indexed text by key
+    let mut embeddings: HashMap<String, Vec<f32>> = HashMap::new();
+    let mut previous_hashes: HashMap<String, &str> = HashMap::new();
+    for (key, hash, embedding) in previous.iter().flat_map(|index| index.texts()) {
+        embeddings.insert(hash.to_string(), embedding.to_vec());
+        previous_hashes.insert(key, hash);
+    }
+
+    let mut counts = IndexChanges {
+        specs: specs.len(),
+        changes: changes.len(),
+        ..Default::default()
+    };
+
+    // Hash every text and count what changed
+    let texts = source_texts(specs, changes);
+    let hashes: Vec<String> = texts
+        .iter()
+        .map(|(key, text)| {
+            let hash = content_hash(text);
+            counts.count(previous_hashes.remove(key), &hash);
+            hash
+        })
+        .collect();
+
+    // Whatever was not matched above has been deleted
+    counts.removed = previous_hashes.len();
+
+    // Embed the texts without a known embedding in one batch
+    let mut missing: Vec<(&str, &str)> = Vec::new();
+    for ((_, text), hash) in texts.iter().zip(&hashes) {
+        if !embeddings.contains_key(hash) && !missing.iter().any(|(_, h)| h == hash) {
+            missing.push((text, hash));
+        }
+    }
+    if !missing.is_empty() {
+        let batch: Vec<&str> = missing.iter().map(|(text, _)| *text).collect();
+        let new_embeddings = embedder.embed(&batch)?;
+        if new_embeddings.len() != batch.len() {
+            return Err(Error::Other(format!(
+                "Embedder returned {} embeddings for {} texts",
+                new_embeddings.len(),
+                batch.len()
+            )));
+        }
+        for ((_, hash), embedding) in missing.iter().zip(new_embeddings) {
+            embeddings.insert(hash.to_string(), embedding);
+        }
+    }
 
-    let model_name = format!("{:?}", EMBEDDING_MODEL);
+    // Assemble the index in the order of `source_texts`
+    let mut hashes = hashes.into_iter();
+    let mut next = || -> Result<(String, Vec<f32>)> {
+        let hash = hashes.next().ok_or_else(|| {
+            Error::Other("Index texts ran out while assembling the index".to_string())
+        })?;
+        let embedding = embeddings
+            .get(&hash)
+            .cloned()
+            .ok_or_else(|| Error::Other(format!("No embedding for text hash {}", hash)))?;
+        Ok((hash, embedding))
+    };
+    let indexed_specs = specs
+        .iter()
+        .map(|spec| {
+            let (purpose_hash, purpose_embedding) = next()?;
+            let requirements = spec
+                .requirements
+                .iter()
+                .map(|req| {
+                    let (description_hash, description_embedding) = next()?;
+                    let scenarios = req
+                        .scenarios
+                        .iter()
+                        .map(|scenario| {
+                            let text = scenario_text(scenario);
+                            let (text_hash, text_embedding) = next()?;
+                            Ok(IndexedScenario {
+                                name: scenario.name.clone(),
+                                text_terms: bm25::term_counts(&format!(
+                                    "{}\n{}",
+                                    scenario.name, text
+                                )),
+                                text,
+                                text_hash,
+                                text_embedding,
+                            })
+                        })
+                        .collect::<Result<_>>()?;
+                    Ok(IndexedRequirement {
+                        name: req.name.clone(),
+                        description: req.description.clone(),
+                        description_hash,
+                        description_embedding,
+                        description_terms: bm25::term_counts(&format!(
+                            "{}\n{}",
+                            req.name, req.description
+                        )),
+                        scenarios,
+                    })
+                })
+                .collect::<Result<_>>()?;
+
+            Ok(IndexedSpec {
+                id: spec.id.clone(),
+                title: spec.title.clone(),
+                purpose_hash,
+                purpose_embedding,
+                purpose_terms: bm25::term_counts(&format!("{}\n{}", spec.title, spec.purpose)),
+                requirements,
+            })
+        })
+        .collect::<Result<_>>()?;
+    let indexed_changes = changes
+        .iter()
+        .map(|change| {
+            let (text_hash, text_embedding) = next()?;
+            let deltas = change
+                .deltas
+                .iter()
+                .flat_map(|group| group.items.iter().map(move |item| (group, item)))
+                .map(|(group, item)| {
+                    let (description_hash, description_embedding) = next()?;
+                    Ok(IndexedDelta {
+                        capability: group.capability.clone(),
+                        name: item.name.clone(),
+                        description: item.text.clone(),
+                        description_hash,
+                        description_embedding,
+                        description_terms: bm25::term_counts(&format!(
+                            "{}\n{}",
+                            item.name, item.text
+                        )),
+                    })
+                })
+                .collect::<Result<_>>()?;
+
+            Ok(IndexedChange {
+                id: change.id.clone(),
+                archived: change.archived,
+                text: change.text.clone(),
+                text_hash,
+                text_embedding,
+                text_terms: bm25::term_counts(&format!("{}\n{}", change.id, change.text)),
+                deltas,
+            })
+        })
+        .collect::<Result<_>>()?;
+    if hashes.next().is_some() {
+        return Err(Error::Other(
+            "Index texts were left over while assembling the index".to_string(),
+        ));
+    }
 
-    let mut indexed_specs = Vec::with_capacity(specs.len());
+    let mut index = SpecIndex {
+        backend: embedder.backend(),
+        model_name: embedder.model_name(),
+        fingerprint: fingerprint(specs, changes),
+        specs: indexed_specs,
+        changes: indexed_changes,
+        ann: None,
+    };
+    if texts.len() >= ANN_THRESHOLD {
+        index.ann = Some(Hnsw::build(&index.embeddings()));
+    }
+    Ok((index, counts))
+}
 
-    for spec in specs {
-        // Generate embedding for the purpose
-        let purpose_texts = vec![spec.purpose.as_str()];
-        let purpose_embeddings = model
-            .embed(purpose_texts, None)
-            .map_err(|e| Error::Other(format!("Failed to generate purpose embedding: {}", e)))?;
+impl IndexChanges {
+    /// Count an entry as added or updated, given its previous hash.
+    fn count(&mut self, previous_hash: Option<&str>, hash: &str) {
+        match previous_hash {
+            None => self.added += 1,
+            Some(previous_hash) if previous_hash != hash => self.updated += 1,
+            Some(_) => {}
+        }
+    }
+}
 
-        let purpose_embedding = purpose_embeddings.into_iter().next().unwrap_or_default();
+impl SpecIndex {
+    /// The embedding of every indexed text, in the order of `texts` and
+    /// `entries`: the node ids of the ANN graph.
+    fn embeddings(&self) -> Vec<&[f32]> {
+        self.texts()
+            .into_iter()
+            .map(|(_, _, embedding)| embedding)
+            .collect()
+    }
 
-        // Generate embeddings for requirements
-        let mut indexed_requirements = Vec::with_capacity(spec.requirements.len());
+    /// The key, content hash and embedding of every indexed text.
+    fn texts(&self) -> Vec<(String, &str, &[f32])> {
+        let mut texts: Vec<(String, &str, &[f32])> = Vec::new();
+        for spec in &self.specs {
+            texts.push((
+                text_key(&["spec", &spec.id]),
+                &spec.purpose_hash,
+                &spec.purpose_embedding,
+            ));
+            for req in &spec.requirements {
+                texts.push((
+                    text_key(&["spec", &spec.id, &req.name]),
+                    &req.description_hash,
+                    &req.description_embedding,
+                ));
+                for scenario in &req.scenarios {
+                    texts.push((
+                        text_key(&["spec", &spec.id, &req.name, &scenario.name]),
+                        &scenario.text_hash,
+                        &scenario.text_embedding,
+                    ));
+                }
+            }
+        }
+        for change in &self.changes {
+            let kind = change_kind(change.archived).as_str();
+            texts.push((
+                text_key(&[kind, &change.id]),
+                &change.text_hash,
+                &change.text_embedding,
+            ));
+            for delta in &change.deltas {
+                texts.push((
+                    text_key(&[kind, &change.id, &delta.capability, &delta.name]),
+                    &delta.description_hash,
+                    &delta.description_embedding,
+                ));
+            }
+        }
+        texts
+    }
+}
 
+/// The key and text to embed of every text of the specs and changes, in the
+/// order `update_index` assembles the index and with the keys of
+/// `SpecIndex::texts`.
+fn source_texts(specs: &[ParsedSpec], changes: &[ChangeSource]) -> Vec<(String, String)> {
+    let mut texts = Vec::new();
+    for spec in specs {
+        texts.push((text_key(&["spec", &spec.id]), spec.purpose.clone()));
         for req in &spec.requirements {
-            let req_texts = vec![req.description.as_str()];
-            let req_embeddings = model.embed(req_texts, None).map_err(|e| {
-                Error::Other(format!("Failed to generate requirement embedding: {}", e))
-            })?;
+            texts.push((
+                text_key(&["spec", &spec.id, &req.name]),
+                req.description.clone(),
+            ));
+            for scenario in &req.scenarios {
+                texts.push((
+                    text_key(&["spec", &spec.id, &req.name, &scenario.name]),
+                    scenario_text(scenario),
+                ));
+            }
+        }
+    }
+    for change in changes {
+        let kind = change_kind(change.archived).as_str();
+        texts.push((text_key(&[kind, &change.id]), change.text.clone()));
+        for group in &change.deltas {
+            for item in &group.items {
+                texts.push((
+                    text_key(&[kind, &change.id, &group.capability, &item.name]),
+                    item.text.clone(),
+                ));
+            }
+        }
+    }
+    texts
+}
 
-            let description_embedding = req_embeddings.into_iter().next().unwrap_or_default();
+/// SHA-256 over every spec title and every text to index with its key.
+///
+/// Two sets of specs and changes with the same fingerprint produce the same
+/// index, so comparing fingerprints tells whether an index is out of date
+/// without embedding anything.
+fn fingerprint(specs: &[ParsedSpec], changes: &[ChangeSource]) -> String {
+    let mut hasher = Sha256::new();
+    for spec in specs {
+        hasher.update(spec.title.as_bytes());
+        hasher.update([0x1e]);
+    }
+    for (key, text) in source_texts(specs, changes) {
+        hasher.update(key.as_bytes());
+        hasher.update([0x1f]);
+        hasher.update(text.as_bytes());
+        hasher.update([0x1e]);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
 
-            indexed_requirements.push(IndexedRequirement {
-                name: req.name.clone(),
-                description: req.description.clone(),
-                description_embedding,
-            });
+/// Identify an indexed text across index updates by its path of names.
+fn text_key(parts: &[&str]) -> String {
+    parts.join("\u{1f}")
+}
+
+/// The result kind of an active or archived change.
+fn change_kind(archived: bool) -> ResultKind {
+    if archived {
+        ResultKind::Archive
+    } else {
+        ResultKind::Change
+    }
+}
+
+/// The clauses of a scenario as one text.
+fn scenario_text(scenario: &ParsedScenario) -> String {
+    let mut text = format!("WHEN {}", scenario.when_clause);
+    for (i, clause) in scenario.then_clauses.iter().enumerate() {
+        text.push_str(if i == 0 { " THEN " } else { " AND " });
+        text.push_str(clause);
+    }
+    text
+}
+
+/// Read the changes of a changes or archive folder.
+///
+/// Folders without a `proposal.md` and folders starting with `.` or `_` are
+/// skipped. The text of a change is the Why and What Changes sections of its
+/// proposal followed by its `design.md`, if any.
+///
+/// # Arguments
+///
+/// * `folder` - The changes or archive folder
+/// * `archived` - Whether `folder` is the archive folder; archived changes are
+///   read without their deltas
+///
+/// # Returns
+///
+/// The changes sorted by ID. A missing folder has no changes.
+///
+/// # Errors
+///
+/// Returns an error if the folder or a change cannot be read.
+pub fn parse_changes(folder: &Path, archived: bool) -> Result<Vec<ChangeSource>> {
+    if !folder.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(folder)
+        .map_err(|e| Error::Other(format!("Failed to read changes folder: {}", e)))?;
+    let mut change_dirs: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|path| {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            !name.starts_with('.') && !name.starts_with('_') && path.join("proposal.md").exists()
+        })
+        .collect();
+    change_dirs.sort();
+
+    let mut changes = Vec::new();
+    for change_dir in change_dirs {
+        let info = parse_change(&change_dir).map_err(Error::Other)?;
+
+        let mut text = format!("{}\n\n{}", info.why, info.what_changes);
+        let design_path = change_dir.join("design.md");
+        if design_path.exists() {
+            let design = fs::read_to_string(&design_path)
+                .map_err(|e| Error::Other(format!("Failed to read design.md: {}", e)))?;
+            text.push_str("\n\n");
+            text.push_str(&design);
         }
 
-        indexed_specs.push(IndexedSpec {
-            id: spec.id.clone(),
-            title: spec.title.clone(),
-            purpose_embedding,
-            requirements: indexed_requirements,
+        changes.push(ChangeSource {
+            id: info.name,
+            archived,
+            text: text.trim().to_string(),
+            deltas: if archived { Vec::new() } else { info.deltas },
         });
     }
 
-    Ok(SpecIndex {
-        model_name,
-        specs: indexed_specs,
-    })
+    Ok(changes)
+}
+
+/// SHA-256 of a text as a hex string.
+fn content_hash(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
 }
 
+/// Magic bytes at the start of every index file.
+const INDEX_MAGIC: &[u8; 8] = b"SPOXIDX\0";
+
+/// Magic bytes at the start of every ANN graph file.
+const ANN_MAGIC: &[u8; 8] = b"SPOXANN\0";
+
+/// Minimum number of indexed texts for which an ANN graph is built.
+///
+/// Below it, comparing the query with every text is fast enough and exact.
+pub const ANN_THRESHOLD: usize = 2000;
+
+/// Version of the index and ANN graph file formats, written after the magic
+/// bytes.
+///
+/// Bump it whenever the serialized layout of `SpecIndex` changes, so older
+/// and newer index files are rejected with a "rebuild required" error instead
+/// of failing to deserialize.
+pub const INDEX_FORMAT_VERSION: u32 = 1;
+
 /// Save the search index to a file.
 ///
+/// The file starts with `INDEX_MAGIC` and `INDEX_FORMAT_VERSION`, followed by
+/// the bincode-serialized index. The ANN graph, if any, is saved next to it
+/// with the extension `hnsw`; an outdated graph file is removed.
+///
 /// # Arguments
 ///
 /// * `index` - The index to save
@@ -145,14 +811,63 @@ pub fn save_index(index: &SpecIndex, path: &Path) -> Result<()> {
             .map_err(|e| Error::Other(format!("Failed to create index directory: {}", e)))?;
     }
 
+    write_versioned(path, INDEX_MAGIC, index)?;
+
+    let ann_path = ann_path(path);
+    match &index.ann {
+        Some(graph) => write_versioned(
+            &ann_path,
+            ANN_MAGIC,
+            &(&index.fingerprint, &index.model_name, graph),
+        ),
+        None if ann_path.exists() => fs::remove_file(&ann_path)
+            .map_err(|e| Error::Other(format!("Failed to remove ANN graph file: {}", e))),
+        None => Ok(()),
+    }
+}
+
+/// The ANN graph file of an index file.
+fn ann_path(index_path: &Path) -> PathBuf {
+    index_path.with_extension("hnsw")
+}
+
+/// Write a value to a file after the magic bytes and `INDEX_FORMAT_VERSION`.
+fn write_versioned<T: Serialize>(path: &Path, magic: &[u8; 8], value: &T) -> Result<()> {
     let file = fs::File::create(path)
         .map_err(|e| Error::Other(format!("Failed to create index file: {}", e)))?;
 
-    let writer = BufWriter::new(file);
-    bincode::serialize_into(writer, index)
-        .map_err(|e| Error::Other(format!("Failed to serialize index: {}", e)))?;
+    let mut writer = BufWriter::new(file);
+    writer
+        .write_all(magic)
+        .and_then(|_| writer.write_all(&INDEX_FORMAT_VERSION.to_le_bytes()))
+        .map_err(|e| Error::Other(format!("Failed to write index file: {}", e)))?;
+    bincode::serialize_into(writer, value)
+        .map_err(|e| Error::Other(format!("Failed to serialize index: {}", e)))
+}
+
+/// Read a value written by `write_versioned` with the same magic bytes.
+fn read_versioned<T: DeserializeOwned>(path: &Path, magic: &[u8; 8]) -> Result<T> {
+    let file = fs::File::open(path)
+        .map_err(|e| Error::Other(format!("Failed to open index file: {}", e)))?;
+
+    let mut reader = BufReader::new(file);
+    let mut header = [0u8; 12];
+    if reader.read_exact(&mut header).is_err() || &header[..8] != magic {
+        return Err(Error::Other(
+            "Search index has an unknown format. Run 'spox index' to rebuild it.".to_string(),
+        ));
+    }
+    let version = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
+    if version != INDEX_FORMAT_VERSION {
+        return Err(Error::Other(format!(
+            "Search index format version {} is not supported (expected {}). \
+             Run 'spox index' to rebuild it.",
+            version, INDEX_FORMAT_VERSION
+        )));
+    }
 
-    Ok(())
+    bincode::deserialize_from(reader)
+        .map_err(|e| Error::Other(format!("Failed to deserialize index: {}", e)))
 }
 
 /// Default index file path relative to project root.
@@ -161,82 +876,222 @@ const INDEX_PATH: &str = ".spox/search_index.bin";
 /// Config file path relative to project root.
 const CONFIG_PATH: &str = ".spox/config.toml";
 
-/// Rebuild the search index from scratch.
+/// The folders the search index is built from.
+#[derive(Debug, Clone)]
+pub struct IndexFolders {
+    /// The spec folder.
+    pub specs: PathBuf,
+    /// The folder with the active changes.
+    pub changes: PathBuf,
+    /// The folder with the archived changes.
+    pub archive: PathBuf,
+}
+
+impl IndexFolders {
+    /// The folders configured in `config`, relative to the project root.
+    pub fn from_config(project_root: &Path, config: &Config) -> Self {
+        Self {
+            specs: project_root.join(config.spec_folder()),
+            changes: project_root.join(config.changes_folder()),
+            archive: project_root.join(config.archive_folder()),
+        }
+    }
+
+    /// Parse all specs and all active and archived changes.
+    fn parse(&self) -> Result<(Vec<ParsedSpec>, Vec<ChangeSource>)> {
+        let specs = super::spec::parse_all_specs(&self.specs)?;
+        let mut changes = parse_changes(&self.changes, false)?;
+        changes.extend(parse_changes(&self.archive, true)?);
+        Ok((specs, changes))
+    }
+}
+
+/// Rebuild the search index.
 ///
-/// This function parses all specs from the configured spec folder, builds a new
-/// index (replacing any existing one), and saves it to `.spox/search_index.bin`.
+/// This function parses all specs and the active and archived changes from
+/// the configured folders, updates the existing index (re-embedding only
+/// changed texts and dropping deleted ones), and saves it to
+/// `.spox/search_index.bin`. An existing index that cannot be loaded is
+/// rebuilt from scratch.
 ///
 /// # Arguments
 ///
 /// * `project_root` - The root directory of the project
+/// * `embedder` - The embedder for new and changed texts
 ///
 /// # Returns
 ///
-/// The number of specs indexed.
+/// The number of specs and changes indexed and the added, updated and removed
+/// entries.
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Configuration cannot be loaded
-/// - Specs cannot be parsed
+/// - Specs or changes cannot be parsed
 /// - Index cannot be built or saved
-pub fn rebuild_index(project_root: &Path) -> Result<usize> {
+pub fn rebuild_index(project_root: &Path, embedder: &dyn Embedder) -> Result<IndexChanges> {
     // Load configuration
     let config_path = project_root.join(CONFIG_PATH);
-    let config = crate::config::Config::load(&config_path)
+    let config = Config::load(&config_path)
         .map_err(|e| Error::Other(format!("Failed to load config: {}", e)))?;
 
-    // Get spec folder from config (relative to project root)
-    let spec_folder = project_root.join(config.spec_folder());
-
-    // Parse all specs
-    let specs = super::spec::parse_all_specs(&spec_folder)?;
+    // Parse all specs and changes (folders relative to project root)
+    let (specs, changes) = IndexFolders::from_config(project_root, &config).parse()?;
 
-    // Build the index
-    let index = build_index(&specs)?;
-    let spec_count = index.specs.len();
+    // Update the index, starting over if the old one is unreadable
+    let index_path = project_root.join(INDEX_PATH);
+    let previous = load_index(&index_path).ok();
+    let (index, counts) = update_index(previous.as_ref(), &specs, &changes, embedder)?;
 
     // Save the index
-    let index_path = project_root.join(INDEX_PATH);
     save_index(&index, &index_path)?;
 
-    Ok(spec_count)
+    Ok(counts)
+}
+
+/// How `ensure_index` obtained the index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexFreshness {
+    /// The index on disk matched the specs and changes.
+    Current,
+    /// No index existed; it has been built.
+    Built,
+    /// The index was out of date or in an old format; it has been updated.
+    Updated,
+    /// The index is out of date and could not be updated, for the given
+    /// reason; the old index is returned.
+    Stale(String),
 }
 
-/// Ensure the search index exists, building it if necessary.
+/// Ensure the search index exists and is up to date, building or updating it
+/// if necessary.
 ///
-/// This function checks if an index file exists at `.spox/search_index.bin` relative
-/// to the project root. If the index exists, it is loaded and returned. If not,
-/// all specs are parsed and a new index is built, saved, and returned.
+/// This function parses all specs and changes and compares their fingerprint
+/// with the one recorded in the index at `.spox/search_index.bin` relative to
+/// the project root. A missing index is built; an index that is out of date or
+/// cannot be loaded is updated, re-embedding only changed texts. Either way
+/// the new index is saved.
 ///
 /// # Arguments
 ///
 /// * `project_root` - The root directory of the project
-/// * `specs_folder` - The folder containing spec files
+/// * `folders` - The folders containing specs and changes
+/// * `embedder` - The embedder used when the index has to be built
 ///
 /// # Returns
 ///
-/// The `SpecIndex` (either loaded or newly built).
+/// The `SpecIndex` and how it was obtained. If an out-of-date index cannot be
+/// updated (e.g. the embedding model is unavailable), the old index is
+/// returned as `IndexFreshness::Stale` instead of failing the search.
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The existing index cannot be loaded
-/// - Specs cannot be parsed (when building)
-/// - The new index cannot be built or saved
-pub fn ensure_index(project_root: &Path, specs_folder: &Path) -> Result<SpecIndex> {
+/// - Specs or changes cannot be parsed
+/// - A missing or unreadable index cannot be built
+/// - The new index cannot be saved
+pub fn ensure_index(
+    project_root: &Path,
+    folders: &IndexFolders,
+    embedder: &dyn Embedder,
+) -> Result<(SpecIndex, IndexFreshness)> {
     let index_path = project_root.join(INDEX_PATH);
+    let (specs, changes) = folders.parse()?;
 
-    if index_path.exists() {
-        // Index exists, load and return it
-        load_index(&index_path)
-    } else {
-        // Index doesn't exist, build it
-        let specs = super::spec::parse_all_specs(specs_folder)?;
-        let index = build_index(&specs)?;
+    if !index_path.exists() {
+        let index = build_index(&specs, &changes, embedder)?;
         save_index(&index, &index_path)?;
-        Ok(index)
+        return Ok((index, IndexFreshness::Built));
+    }
+
+    let previous = match load_index(&index_path) {
+        Ok(index) if index.fingerprint == fingerprint(&specs, &changes) => {
+            return Ok((index, IndexFreshness::Current));
+        }
+        Ok(index) => Some(index),
+        Err(_) => None,
+    };
+    match update_index(previous.as_ref(), &specs, &changes, embedder) {
+        Ok((index, _)) => {
+            save_index(&index, &index_path)?;
+            Ok((index, IndexFreshness::Updated))
+        }
+        Err(e) => match previous {
+            Some(index) => Ok((index, IndexFreshness::Stale(e.to_string()))),
+            None => Err(e),
+        },
+    }
+}
+
+/// Whether the search index on disk can be used as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexStatus {
+    /// The index matches the specs and changes.
+    Current,
+    /// No index exists.
+    Missing,
+    /// The index cannot be loaded, e.g. because of an old format version.
+    Unreadable(String),
+    /// The index was built with another embedding backend or model.
+    Incompatible {
+        /// The backend and model recorded in the index.
+        indexed: String,
+        /// The configured backend and model.
+        configured: String,
+    },
+    /// Specs or changes changed since the index was built.
+    Stale,
+}
+
+impl IndexStatus {
+    /// Whether the index can be searched without a rebuild.
+    pub fn is_current(&self) -> bool {
+        *self == IndexStatus::Current
+    }
+}
+
+/// Check whether the search index is up to date, without changing it.
+///
+/// # Arguments
+///
+/// * `project_root` - The root directory of the project
+/// * `folders` - The folders containing specs and changes
+/// * `embedder` - The configured embedder; only its backend and model name
+///   are used, the model is not loaded
+///
+/// # Errors
+///
+/// Returns an error if specs or changes cannot be parsed.
+pub fn check_index(
+    project_root: &Path,
+    folders: &IndexFolders,
+    embedder: &dyn Embedder,
+) -> Result<IndexStatus> {
+    let index_path = project_root.join(INDEX_PATH);
+    if !index_path.exists() {
+        return Ok(IndexStatus::Missing);
+    }
+    let index = match load_index(&index_path) {
+        Ok(index) => index,
+        Err(e) => return Ok(IndexStatus::Unreadable(e.to_string())),
+    };
+    if !is_compatible(&index, embedder) {
+        return Ok(IndexStatus::Incompatible {
+            indexed: format!("{} ({})", index.backend.as_str(), index.model_name),
+            configured: format!(
+                "{} ({})",
+                embedder.backend().as_str(),
+                embedder.model_name()
+            ),
+        });
+    }
+
+    let (specs, changes) = folders.parse()?;
+    if index.fingerprint != fingerprint(&specs, &changes) {
+        return Ok(IndexStatus::Stale);
     }
+    Ok(IndexStatus::Current)
 }
 
 /// Load the search index from a file.
@@ -251,91 +1106,317 @@ pub fn ensure_index(project_root: &Path, specs_folder: &Path) -> Result<SpecInde
 ///
 /// # Errors
 ///
-/// Returns an error if the file cannot be read or deserialized.
+/// Returns an error if the file cannot be read or deserialized, or if it was
+/// written with another index format version. A missing, unreadable or
+/// outdated ANN graph file is ignored: search then compares with every text.
 pub fn load_index(path: &Path) -> Result<SpecIndex> {
-    let file = fs::File::open(path)
-        .map_err(|e| Error::Other(format!("Failed to open index file: {}", e)))?;
-
-    let reader = BufReader::new(file);
-    let index: SpecIndex = bincode::deserialize_from(reader)
-        .map_err(|e| Error::Other(format!("Failed to deserialize index: {}", e)))?;
+    let mut index: SpecIndex = read_versioned(path, INDEX_MAGIC)?;
+
+    let ann_path = ann_path(path);
+    if ann_path.exists() {
+        let ann: Option<(String, String, Hnsw)> = read_versioned(&ann_path, ANN_MAGIC).ok();
+        index.ann = ann
+            .filter(|(fingerprint, model_name, graph)| {
+                *fingerprint == index.fingerprint
+                    && *model_name == index.model_name
+                    && graph.len() == index.texts().len()
+            })
+            .map(|(_, _, graph)| graph);
+    }
 
     Ok(index)
 }
 
-/// Search the index for relevant specs and requirements.
+/// Search the index for relevant specs, requirements, scenarios and changes.
+///
+/// Returns one page of the ranked results: the first `offset` results passing
+/// the filter are skipped and at most `top_k` of the rest are returned, with
+/// the number of results passing the filter in total.
+///
+/// A semantic or hybrid search of an index with an ANN graph, filtered by
+/// score at most, only scores a few times `offset + top_k` nearest entries,
+/// and compares with every entry if those do not fill the page. Its total
+/// then counts the scored entries only.
 ///
 /// # Arguments
 ///
 /// * `index` - The search index to query
 /// * `query` - The search query text
-/// * `top_k` - The maximum number of results to return
-///
-/// # Returns
-///
-/// A vector of search results, sorted by relevance score (highest first).
+/// * `offset` - The number of top results to skip
+/// * `top_k` - The maximum number of results on the page
+/// * `mode` - Rank by embeddings, keywords, or both
+/// * `filter` - Restrictions on the results, applied before paging
+/// * `embedder` - The embedder for the query; reuse it across searches
+///   (unused in keyword mode)
 ///
 /// # Errors
 ///
-/// Returns an error if the search fails.
-pub fn search(index: &SpecIndex, query: &str, top_k: usize) -> Result<Vec<SearchResult>> {
-    // Initialize the embedding model for query
-    let model = TextEmbedding::try_new(
-        InitOptions::new(EMBEDDING_MODEL).with_show_download_progress(false),
-    )
-    .map_err(|e| Error::Other(format!("Failed to initialize embedding model: {}", e)))?;
-
-    // Generate embedding for the query
-    let query_texts = vec![query];
-    let query_embeddings = model
-        .embed(query_texts, None)
-        .map_err(|e| Error::Other(format!("Failed to generate query embedding: {}", e)))?;
-
-    let query_embedding = query_embeddings
+/// Returns an error if the index was built with another backend or model and
+/// the mode uses embeddings, or if the search fails.
+pub fn search(
+    index: &SpecIndex,
+    query: &str,
+    offset: usize,
+    top_k: usize,
+    mode: SearchMode,
+    filter: &SearchFilter,
+    embedder: &dyn Embedder,
+) -> Result<SearchPage> {
+    let entries = entries(index);
+
+    // A search filtered by score only needs the nearest entries; one filtered
+    // by spec or kind compares with every entry, so the filter cannot exhaust
+    // the candidates
+    let wanted = offset.saturating_add(top_k);
+    let candidates = filter
+        .is_by_score_only()
+        .then(|| wanted.saturating_mul(ANN_CANDIDATE_FACTOR));
+
+    let mut ranked = rank(&entries, index, query, mode, filter, candidates, embedder)?;
+
+    // The nearest candidates may not fill a deep page, or a page filtered by
+    // score; rank every entry then
+    let ann_search = index.ann.is_some() && mode != SearchMode::Keyword;
+    if ann_search && candidates.is_some() && ranked.len() < wanted {
+        ranked = rank(&entries, index, query, mode, filter, None, embedder)?;
+    }
+
+    // Take the requested page
+    let total = ranked.len();
+    let results = ranked
         .into_iter()
-        .next()
-        .ok_or_else(|| Error::Other("Failed to get query embedding".to_string()))?;
+        .skip(offset)
+        .take(top_k)
+        .map(|(i, score)| {
+            let entry = &entries[i];
+            SearchResult {
+                kind: entry.kind,
+                spec_id: entry.spec_id.to_string(),
+                requirement: entry.requirement.map(str::to_string),
+                scenario: entry.scenario.map(str::to_string),
+                change_id: entry.change_id.map(str::to_string),
+                score,
+                snippet: entry.snippet.clone(),
+            }
+        })
+        .collect();
 
-    // Collect all results
-    let mut results = Vec::new();
+    Ok(SearchPage { results, total })
+}
 
-    for spec in &index.specs {
-        // Score against spec purpose
-        let purpose_score = cosine_similarity(&query_embedding, &spec.purpose_embedding);
-        if purpose_score > 0.0 {
-            results.push(SearchResult {
-                spec_id: spec.id.clone(),
-                requirement: None,
-                score: purpose_score,
-                snippet: format!("[{}] {}", spec.title, truncate_text(&spec.title, 100)),
-            });
-        }
+/// Score every entry and return the `(entry, score)` pairs passing the
+/// filter, highest score first.
+///
+/// With `candidates`, semantic scores come from that many nearest entries of
+/// the ANN graph, if the index has one; other entries score 0.0.
+fn rank(
+    entries: &[Entry],
+    index: &SpecIndex,
+    query: &str,
+    mode: SearchMode,
+    filter: &SearchFilter,
+    candidates: Option<usize>,
+    embedder: &dyn Embedder,
+) -> Result<Vec<(usize, f32)>> {
+    // Scores are computed over the whole index, so keyword statistics and
+    // ranks do not depend on the filter
+    let scores = match mode {
+        SearchMode::Semantic => semantic_scores(entries, index, query, candidates, embedder)?,
+        SearchMode::Keyword => keyword_scores(entries, query),
+        SearchMode::Hybrid => reciprocal_rank_fusion(&[
+            semantic_scores(entries, index, query, candidates, embedder)?,
+            keyword_scores(entries, query),
+        ]),
+    };
+
+    let mut ranked: Vec<(usize, f32)> = scores
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, score)| {
+            let entry = &entries[i];
+            score > 0.0 && filter.allows(entry.kind, entry.spec_id, score)
+        })
+        .collect();
+
+    // Sort by score (highest first)
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    Ok(ranked)
+}
+
+/// Rank constant of reciprocal rank fusion; damps the weight of the top ranks.
+const RRF_K: f32 = 60.0;
+
+/// Number of ANN candidates per requested result. Hybrid search fuses the
+/// semantic candidates with the keyword ranking, so it needs more than
+/// `top_k` of them.
+const ANN_CANDIDATE_FACTOR: usize = 4;
+
+/// A searchable text of the index.
+struct Entry<'a> {
+    kind: ResultKind,
+    spec_id: &'a str,
+    requirement: Option<&'a str>,
+    scenario: Option<&'a str>,
+    change_id: Option<&'a str>,
+    snippet: String,
+    embedding: &'a [f32],
+    terms: &'a TermCounts,
+}
 
-        // Score against each requirement
+/// All searchable texts of the index, in index order.
+fn entries(index: &SpecIndex) -> Vec<Entry<'_>> {
+    let mut entries = Vec::new();
+    for spec in &index.specs {
+        entries.push(Entry {
+            kind: ResultKind::Spec,
+            spec_id: &spec.id,
+            requirement: None,
+            scenario: None,
+            change_id: None,
+            snippet: format!("[{}] {}", spec.title, truncate_text(&spec.title, 100)),
+            embedding: &spec.purpose_embedding,
+            terms: &spec.purpose_terms,
+        });
         for req in &spec.requirements {
-            let req_score = cosine_similarity(&query_embedding, &req.description_embedding);
-            if req_score > 0.0 {
-                results.push(SearchResult {
-                    spec_id: spec.id.clone(),
-                    requirement: Some(req.name.clone()),
-                    score: req_score,
-                    snippet: truncate_text(&req.description, 100),
+            entries.push(Entry {
+                kind: ResultKind::Requirement,
+                spec_id: &spec.id,
+                requirement: Some(&req.name),
+                scenario: None,
+                change_id: None,
+                snippet: truncate_text(&req.description, 100),
+                embedding: &req.description_embedding,
+                terms: &req.description_terms,
+            });
+            for scenario in &req.scenarios {
+                entries.push(Entry {
+                    kind: ResultKind::Scenario,
+                    spec_id: &spec.id,
+                    requirement: Some(&req.name),
+                    scenario: Some(&scenario.name),
+                    change_id: None,
+                    snippet: truncate_text(&scenario.text, 100),
+                    embedding: &scenario.text_embedding,
+                    terms: &scenario.text_terms,
                 });
             }
         }
     }
+    for change in &index.changes {
+        let kind = change_kind(change.archived);
+        entries.push(Entry {
+            kind,
+            spec_id: "",
+            requirement: None,
+            scenario: None,
+            change_id: Some(&change.id),
+            snippet: truncate_text(
+                &change.text.split_whitespace().collect::<Vec<_>>().join(" "),
+                100,
+            ),
+            embedding: &change.text_embedding,
+            terms: &change.text_terms,
+        });
+        for delta in &change.deltas {
+            entries.push(Entry {
+                kind,
+                spec_id: &delta.capability,
+                requirement: Some(&delta.name),
+                scenario: None,
+                change_id: Some(&change.id),
+                snippet: truncate_text(&delta.description, 100),
+                embedding: &delta.description_embedding,
+                terms: &delta.description_terms,
+            });
+        }
+    }
+    entries
+}
 
-    // Sort by score (highest first)
-    results.sort_by(|a, b| {
-        b.score
-            .partial_cmp(&a.score)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
+/// Cosine similarity of the query embedding to every entry.
+///
+/// With an ANN graph and `candidates`, only the (approximately) `candidates`
+/// most similar entries are scored; all others score 0.0.
+fn semantic_scores(
+    entries: &[Entry],
+    index: &SpecIndex,
+    query: &str,
+    candidates: Option<usize>,
+    embedder: &dyn Embedder,
+) -> Result<Vec<f32>> {
+    // Query and index embeddings are only comparable from the same model
+    if !is_compatible(index, embedder) {
+        return Err(Error::Other(format!(
+            "Search index was built with {} ({}) but {} ({}) is configured: rebuild required. Run 'spox index' to rebuild it.",
+            index.backend.as_str(),
+            index.model_name,
+            embedder.backend().as_str(),
+            embedder.model_name()
+        )));
+    }
+
+    let query_embedding = embedder.embed_one(query)?;
+    if let (Some(graph), Some(candidates)) = (&index.ann, candidates) {
+        let embeddings: Vec<&[f32]> = entries.iter().map(|entry| entry.embedding).collect();
+        let mut scores = vec![0.0; entries.len()];
+        for (node, similarity) in graph.search(&embeddings, &query_embedding, candidates) {
+            scores[node] = similarity;
+        }
+        return Ok(scores);
+    }
+
+    Ok(entries
+        .iter()
+        .map(|entry| cosine_similarity(&query_embedding, entry.embedding))
+        .collect())
+}
+
+/// BM25 score of every entry, scaled so that the best match scores 1.0.
+fn keyword_scores(entries: &[Entry], query: &str) -> Vec<f32> {
+    let documents: Vec<&TermCounts> = entries.iter().map(|entry| entry.terms).collect();
+    let bm25 = Bm25::new(&documents);
+    let query_terms = bm25::terms(query);
+
+    let scores: Vec<f32> = documents
+        .iter()
+        .map(|terms| bm25.score(&query_terms, terms))
+        .collect();
+    let best = scores.iter().copied().fold(0.0, f32::max);
+    if best == 0.0 {
+        return scores;
+    }
+    scores.into_iter().map(|score| score / best).collect()
+}
+
+/// Combine rankings by summing `1 / (RRF_K + rank)` over the rankings an
+/// entry appears in, scaled so that first place in every ranking scores 1.0.
+///
+/// Each ranking holds one score per entry; entries scoring 0.0 or less are
+/// not ranked.
+fn reciprocal_rank_fusion(rankings: &[Vec<f32>]) -> Vec<f32> {
+    let len = rankings.first().map_or(0, Vec::len);
+    let mut fused = vec![0.0; len];
+
+    for scores in rankings {
+        let mut ranked: Vec<usize> = (0..scores.len()).filter(|&i| scores[i] > 0.0).collect();
+        ranked.sort_by(|&a, &b| {
+            scores[b]
+                .partial_cmp(&scores[a])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        for (rank, i) in ranked.into_iter().enumerate() {
+            fused[i] += 1.0 / (RRF_K + rank as f32 + 1.0);
+        }
+    }
 
-    // Take top_k results
-    results.truncate(top_k);
+    let best = rankings.len() as f32 / (RRF_K + 1.0);
+    fused.into_iter().map(|score| score / best).collect()
+}
 
-    Ok(results)
+/// Whether an index was built by the same backend and model as `embedder`.
+fn is_compatible(index: &SpecIndex, embedder: &dyn Embedder) -> bool {
+    index.backend == embedder.backend() && index.model_name == embedder.model_name()
 }
 
 /// Calculate cosine similarity between two vectors.
@@ -353,149 +1434,961 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
         return 0.0;
     }
 
-    let dot_product: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
-    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
-    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let dot_product: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+
+    dot_product / (norm_a * norm_b)
+}
+
+/// Truncate text to a maximum length, adding ellipsis if needed.
+fn truncate_text(text: &str, max_len: usize) -> String {
+    if text.len() <= max_len {
+        text.to_string()
+    } else {
+        let mut end = max_len.saturating_sub(3);
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}...", &text[..end])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::embedding::{HashingEmbedder, LazyEmbedder};
+
+    /// The fastembed backend, loaded only when something needs embedding.
+    fn fastembed() -> LazyEmbedder {
+        LazyEmbedder::new(EmbeddingBackend::Fastembed)
+    }
+
+    // ==================== Cosine similarity tests ====================
+
+    #[test]
+    fn test_cosine_similarity_identical_vectors() {
+        let a = vec![1.0, 2.0, 3.0];
+        let b = vec![1.0, 2.0, 3.0];
+        let sim = cosine_similarity(&a, &b);
+        assert!((sim - 1.0).abs() < 0.0001, "Expected 1.0, got {}", sim);
+    }
+
+    #[test]
+    fn test_cosine_similarity_orthogonal_vectors() {
+        let a = vec![1.0, 0.0, 0.0];
+        let b = vec![0.0, 1.0, 0.0];
+        let sim = cosine_similarity(&a, &b);
+        assert!((sim - 0.0).abs() < 0.0001, "Expected 0.0, got {}", sim);
+    }
+
+    #[test]
+    fn test_cosine_similarity_opposite_vectors() {
+        let a = vec![1.0, 2.0, 3.0];
+        let b = vec![-1.0, -2.0, -3.0];
+        let sim = cosine_similarity(&a, &b);
+        assert!((sim - (-1.0)).abs() < 0.0001, "Expected -1.0, got {}", sim);
+    }
+
+    #[test]
+    fn test_cosine_similarity_different_lengths() {
+        let a = vec![1.0, 2.0, 3.0];
+        let b = vec![1.0, 2.0];
+        let sim = cosine_similarity(&a, &b);
+        assert_eq!(sim, 0.0, "Expected 0.0 for different length vectors");
+    }
+
+    #[test]
+    fn test_cosine_similarity_empty_vectors() {
+        let a: Vec<f32> = vec![];
+        let b: Vec<f32> = vec![];
+        let sim = cosine_similarity(&a, &b);
+        assert_eq!(sim, 0.0, "Expected 0.0 for empty vectors");
+    }
+
+    #[test]
+    fn test_cosine_similarity_zero_vector() {
+        let a = vec![0.0, 0.0, 0.0];
+        let b = vec![1.0, 2.0, 3.0];
+        let sim = cosine_similarity(&a, &b);
+        assert_eq!(sim, 0.0, "Expected 0.0 when one vector is zero");
+    }
+
+    // ==================== Truncate text tests ====================
+
+    #[test]
+    fn test_truncate_text_short() {
+        let result = truncate_text("hello", 10);
+        assert_eq!(result, "hello");
+    }
+
+    #[test]
+    fn test_truncate_text_long() {
+        let result = truncate_text("hello world this is a long text", 15);
+        assert_eq!(result, "hello world ...");
+    }
+
+    #[test]
+    fn test_truncate_text_multibyte() {
+        let result = truncate_text("Archive — merge deltas", 12);
+        assert_eq!(result, "Archive ...");
+    }
+
+    #[test]
+    fn test_truncate_text_exact() {
+        let result = truncate_text("hello", 5);
+        assert_eq!(result, "hello");
+    }
+
+    // ==================== Index save/load tests ====================
+
+    #[test]
+    fn test_save_and_load_index() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let index_path = temp_dir.path().join("test_index.bin");
+
+        // Create a simple index
+        let index = SpecIndex {
+            backend: EmbeddingBackend::Fastembed,
+            model_name: "test-model".to_string(),
+            fingerprint: String::new(),
+            specs: vec![IndexedSpec {
+                id: "test-spec".to_string(),
+                title: "Test Spec".to_string(),
+                purpose_hash: content_hash("Test purpose."),
+                purpose_embedding: vec![0.1, 0.2, 0.3],
+                purpose_terms: TermCounts::new(),
+                requirements: vec![IndexedRequirement {
+                    name: "Test Requirement".to_string(),
+                    description: "This is a test requirement.".to_string(),
+                    description_hash: content_hash("This is a test requirement."),
+                    description_embedding: vec![0.4, 0.5, 0.6],
+                    description_terms: TermCounts::new(),
+                    scenarios: vec![],
+                }],
+            }],
+            changes: vec![],
+            ann: None,
+        };
+
+        // Save the index
+        let save_result = save_index(&index, &index_path);
+        assert!(
+            save_result.is_ok(),
+            "Failed to save index: {:?}",
+            save_result
+        );
+
+        // Load the index
+        let load_result = load_index(&index_path);
+        assert!(
+            load_result.is_ok(),
+            "Failed to load index: {:?}",
+            load_result
+        );
+
+        let loaded_index = load_result.unwrap();
+        assert_eq!(loaded_index.model_name, "test-model");
+        assert_eq!(loaded_index.specs.len(), 1);
+        assert_eq!(loaded_index.specs[0].id, "test-spec");
+        assert_eq!(loaded_index.specs[0].requirements.len(), 1);
+    }
+
+    #[test]
+    fn test_load_index_not_found() {
+        let result = load_index(Path::new("/nonexistent/path/index.bin"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_load_index_rejects_other_format_versions() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let index_path = temp_dir.path().join("index.bin");
+
+        let mut bytes = INDEX_MAGIC.to_vec();
+        bytes.extend((INDEX_FORMAT_VERSION + 1).to_le_bytes());
+        std::fs::write(&index_path, bytes).unwrap();
+        let err = load_index(&index_path).unwrap_err().to_string();
+        assert!(err.contains("format version"), "Unexpected error: {}", err);
+        assert!(err.contains("spox index"), "Unexpected error: {}", err);
+
+        // An index written before the header existed
+        std::fs::write(&index_path, [1, 0, 0, 0, 0, 0, 0, 0, 9]).unwrap();
+        let err = load_index(&index_path).unwrap_err().to_string();
+        assert!(err.contains("unknown format"), "Unexpected error: {}", err);
+    }
+
+    // ==================== update_index tests ====================
+
+    /// The default folders of a project.
+    fn folders(project_root: &Path) -> IndexFolders {
+        IndexFolders {
+            specs: project_root.join("specs"),
+            changes: project_root.join("specs/_changes"),
+            archive: project_root.join("specs/_archive"),
+        }
+    }
+
+    fn parsed_spec(id: &str, purpose: &str, requirements: &[(&str, &str)]) -> ParsedSpec {
+        ParsedSpec {
+            id: id.to_string(),
+            title: id.to_string(),
+            purpose: purpose.to_string(),
+            requirements: requirements
+                .iter()
+                .map(
+                    |(name, description)| super::super::spec::ParsedRequirement {
+                        name: name.to_string(),
+                        description: description.to_string(),
+                        scenarios: vec![],
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    /// An index of `specs` with fake embeddings, as if built by the current model.
+    fn indexed(specs: &[ParsedSpec]) -> SpecIndex {
+        SpecIndex {
+            backend: EmbeddingBackend::Fastembed,
+            model_name: fastembed().model_name(),
+            fingerprint: fingerprint(specs, &[]),
+            specs: specs
+                .iter()
+                .map(|spec| IndexedSpec {
+                    id: spec.id.clone(),
+                    title: spec.title.clone(),
+                    purpose_hash: content_hash(&spec.purpose),
+                    purpose_embedding: vec![1.0, 0.0],
+                    purpose_terms: bm25::term_counts(&spec.purpose),
+                    requirements: spec
+                        .requirements
+                        .iter()
+                        .map(|req| IndexedRequirement {
+                            name: req.name.clone(),
+                            description: req.description.clone(),
+                            description_hash: content_hash(&req.description),
+                            description_embedding: vec![0.0, 1.0],
+                            description_terms: bm25::term_counts(&req.description),
+                            scenarios: vec![],
+                        })
+                        .collect(),
+                })
+                .collect(),
+            changes: vec![],
+            ann: None,
+        }
+    }
+
+    #[test]
+    fn test_content_hash_is_sha256_hex() {
+        assert_eq!(
+            content_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_update_index_unchanged_reuses_embeddings() {
+        let specs = vec![parsed_spec(
+            "auth",
+            "Handle auth.",
+            &[("Login", "Users log in.")],
+        )];
+        let previous = indexed(&specs);
+
+        let embedder = fastembed();
+        let (index, changes) = update_index(Some(&previous), &specs, &[], &embedder).unwrap();
+
+        assert!(
+            !embedder.is_loaded(),
+            "Nothing changed, the model must not be loaded"
+        );
+        assert_eq!(
+            changes,
+            IndexChanges {
+                specs: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(index.specs[0].purpose_embedding, vec![1.0, 0.0]);
+        assert_eq!(
+            index.specs[0].requirements[0].description_embedding,
+            vec![0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_update_index_drops_deleted_entries() {
+        let before = vec![
+            parsed_spec(
+                "auth",
+                "Handle auth.",
+                &[("Login", "Users log in."), ("Logout", "Users log out.")],
+            ),
+            parsed_spec("payments", "Handle payments.", &[("Checkout", "Pay.")]),
+        ];
+        let after = vec![parsed_spec(
+            "auth",
+            "Handle auth.",
+            &[("Login", "Users log in.")],
+        )];
+
+        let (index, changes) =
+            update_index(Some(&indexed(&before)), &after, &[], &fastembed()).unwrap();
+
+        assert_eq!(changes.specs, 1);
+        assert_eq!(changes.removed, 3);
+        assert_eq!((changes.added, changes.updated), (0, 0));
+        assert_eq!(index.specs.len(), 1);
+        assert_eq!(index.specs[0].requirements.len(), 1);
+    }
+
+    #[test]
+    fn test_update_index_renamed_requirement_reuses_embedding() {
+        let before = vec![parsed_spec(
+            "auth",
+            "Handle auth.",
+            &[("Login", "Users log in.")],
+        )];
+        let after = vec![parsed_spec(
+            "auth",
+            "Handle auth.",
+            &[("Sign In", "Users log in.")],
+        )];
+
+        let (index, changes) =
+            update_index(Some(&indexed(&before)), &after, &[], &fastembed()).unwrap();
+
+        assert_eq!((changes.added, changes.updated, changes.removed), (1, 0, 1));
+        assert_eq!(index.specs[0].requirements[0].name, "Sign In");
+        assert_eq!(
+            index.specs[0].requirements[0].description_embedding,
+            vec![0.0, 1.0]
+        );
+    }
+
+    #[test]
+    #[ignore] // Requires embedding model download
+    fn test_update_index_reembeds_changed_text() {
+        let before = vec![parsed_spec(
+            "auth",
+            "Handle auth.",
+            &[("Login", "Users log in.")],
+        )];
+        let after = vec![parsed_spec(
+            "auth",
+            "Handle auth.",
+            &[
+                ("Login", "Users log in with a passkey."),
+                ("Logout", "Users log out."),
+            ],
+        )];
+
+        let (index, changes) =
+            update_index(Some(&indexed(&before)), &after, &[], &fastembed()).unwrap();
+
+        assert_eq!((changes.added, changes.updated, changes.removed), (1, 1, 0));
+        assert_eq!(index.specs[0].purpose_embedding, vec![1.0, 0.0]);
+        assert_ne!(
+            index.specs[0].requirements[0].description_embedding,
+            vec![0.0, 1.0]
+        );
+    }
+
+    // ==================== Offline (hashing backend) tests ====================
+
+    #[test]
+    fn test_build_and_search_with_hashing_embedder() {
+        let specs = vec![
+            parsed_spec(
+                "auth",
+                "Handle user authentication.",
+                &[("Login", "Users log in with email and password.")],
+            ),
+            parsed_spec(
+                "payments",
+                "Handle payment processing.",
+                &[("Checkout", "Process credit card payments at checkout.")],
+            ),
+        ];
+        let embedder = HashingEmbedder::new();
+
+        let index = build_index(&specs, &[], &embedder).unwrap();
+        assert_eq!(index.backend, EmbeddingBackend::Hashing);
+        assert_eq!(index.model_name, "hashing-512");
+
+        let results = search(
+            &index,
+            "password login",
+            0,
+            5,
+            SearchMode::Semantic,
+            &SearchFilter::default(),
+            &embedder,
+        )
+        .unwrap()
+        .results;
+        assert_eq!(results[0].spec_id, "auth");
+        assert_eq!(results[0].requirement.as_deref(), Some("Login"));
+    }
+
+    #[test]
+    fn test_search_rejects_index_from_other_backend() {
+        let specs = vec![parsed_spec("auth", "Handle auth.", &[])];
+        let index = indexed(&specs);
+
+        let result = search(
+            &index,
+            "login",
+            0,
+            5,
+            SearchMode::Semantic,
+            &SearchFilter::default(),
+            &HashingEmbedder::new(),
+        );
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("fastembed"), "Unexpected error: {}", err);
+        assert!(
+            err.contains("rebuild required"),
+            "Unexpected error: {}",
+            err
+        );
+        assert!(err.contains("spox index"), "Unexpected error: {}", err);
+    }
+
+    #[test]
+    fn test_search_rejects_index_from_other_model() {
+        let specs = vec![parsed_spec("auth", "Handle auth.", &[])];
+        let mut index = build_index(&specs, &[], &HashingEmbedder::new()).unwrap();
+        index.model_name = "hashing-256".to_string();
+
+        let err = search(
+            &index,
+            "login",
+            0,
+            5,
+            SearchMode::Semantic,
+            &SearchFilter::default(),
+            &HashingEmbedder::new(),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("hashing-256"), "Unexpected error: {}", err);
+        assert!(
+            err.contains("rebuild required"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    // ==================== Keyword and hybrid search tests ====================
+
+    fn config_specs() -> Vec<ParsedSpec> {
+        vec![
+            parsed_spec(
+                "config",
+                "Handle project configuration.",
+                &[
+                    (
+                        "Archive Folder",
+                        "The archive_folder setting SHALL name the folder for archived changes.",
+                    ),
+                    (
+                        "Spec Folder",
+                        "The spec folder setting SHALL name the folder for specs.",
+                    ),
+                ],
+            ),
+            parsed_spec(
+                "mcp",
+                "Serve spec tools to AI assistants.",
+                &[("Server", "Running spox mcp serve SHALL start the server.")],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_search_mode_round_trips_names() {
+        for mode in [
+            SearchMode::Semantic,
+            SearchMode::Keyword,
+            SearchMode::Hybrid,
+        ] {
+            assert_eq!(mode.as_str().parse::<SearchMode>(), Ok(mode));
+        }
+        assert_eq!(SearchMode::default(), SearchMode::Hybrid);
+        assert!("fuzzy".parse::<SearchMode>().unwrap_err().contains("fuzzy"));
+    }
+
+    #[test]
+    fn test_keyword_search_matches_exact_identifier() {
+        let embedder = HashingEmbedder::new();
+        let index = build_index(&config_specs(), &[], &embedder).unwrap();
+
+        let results = search(
+            &index,
+            "archive_folder",
+            0,
+            5,
+            SearchMode::Keyword,
+            &SearchFilter::default(),
+            &embedder,
+        )
+        .unwrap()
+        .results;
+
+        // "Spec Folder" only shares the word "folder"
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].requirement.as_deref(), Some("Archive Folder"));
+        assert_eq!(results[0].score, 1.0);
+        assert!(
+            results[1].score < 0.5,
+            "Unexpected score {}",
+            results[1].score
+        );
+    }
+
+    #[test]
+    fn test_keyword_search_does_not_need_compatible_embedder() {
+        let index = indexed(&config_specs());
+
+        let results = search(
+            &index,
+            "spox mcp serve",
+            0,
+            5,
+            SearchMode::Keyword,
+            &SearchFilter::default(),
+            &HashingEmbedder::new(),
+        )
+        .unwrap()
+        .results;
+
+        assert_eq!(results[0].spec_id, "mcp");
+        assert_eq!(results[0].requirement.as_deref(), Some("Server"));
+    }
+
+    #[test]
+    fn test_hybrid_search_ranks_entry_found_by_both_first() {
+        let embedder = HashingEmbedder::new();
+        let index = build_index(&config_specs(), &[], &embedder).unwrap();
+
+        let results = search(
+            &index,
+            "archive_folder",
+            0,
+            5,
+            SearchMode::Hybrid,
+            &SearchFilter::default(),
+            &embedder,
+        )
+        .unwrap()
+        .results;
+
+        assert_eq!(results[0].requirement.as_deref(), Some("Archive Folder"));
+        assert!(results[0].score <= 1.0);
+        assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    // ==================== Scenario and change tests ====================
+
+    fn write_change(folder: &Path, id: &str, why: &str, delta: Option<&str>) {
+        let change_dir = folder.join(id);
+        std::fs::create_dir_all(&change_dir).unwrap();
+        std::fs::write(
+            change_dir.join("proposal.md"),
+            format!(
+                "# Change: {}\n\n## Why\n\n{}\n\n## What Changes\n\n- Update specs\n",
+                id, why
+            ),
+        )
+        .unwrap();
+        if let Some(delta) = delta {
+            let delta_dir = change_dir.join("specs/auth");
+            std::fs::create_dir_all(&delta_dir).unwrap();
+            std::fs::write(delta_dir.join("spec.md"), delta).unwrap();
+        }
+    }
+
+    const TWO_FACTOR_DELTA: &str = r#"## ADDED Requirements
+
+### Requirement: Two Factor
+
+The system SHALL require a one-time passcode after the password.
+
+#### Scenario: Passcode accepted
+
+- **WHEN** user enters a valid passcode
+- **THEN** user is logged in
+"#;
+
+    #[test]
+    fn test_scenario_text_joins_clauses() {
+        let scenario = super::super::spec::ParsedScenario {
+            name: "Success".to_string(),
+            when_clause: "user logs in".to_string(),
+            then_clauses: vec!["session starts".to_string(), "token returned".to_string()],
+        };
+        assert_eq!(
+            scenario_text(&scenario),
+            "WHEN user logs in THEN session starts AND token returned"
+        );
+    }
+
+    #[test]
+    fn test_parse_changes_reads_deltas_of_active_changes_only() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let changes_dir = temp_dir.path().join("changes");
+        let archive_dir = temp_dir.path().join("archive");
+        write_change(
+            &changes_dir,
+            "add-2fa",
+            "Passwords leak.",
+            Some(TWO_FACTOR_DELTA),
+        );
+        write_change(
+            &archive_dir,
+            "2025-01-01-add-login",
+            "Users need accounts.",
+            Some(TWO_FACTOR_DELTA),
+        );
+        std::fs::create_dir_all(changes_dir.join("draft")).unwrap();
+
+        let active = parse_changes(&changes_dir, false).unwrap();
+        let archived = parse_changes(&archive_dir, true).unwrap();
+
+        assert_eq!(active.len(), 1, "Folders without proposal.md are skipped");
+        assert_eq!(active[0].id, "add-2fa");
+        assert!(active[0].text.contains("Passwords leak."));
+        assert_eq!(active[0].deltas[0].items[0].name, "Two Factor");
+        assert_eq!(archived[0].id, "2025-01-01-add-login");
+        assert!(archived[0].archived);
+        assert!(archived[0].deltas.is_empty());
+        assert!(parse_changes(&temp_dir.path().join("missing"), false)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_search_finds_scenarios_and_changes_by_kind() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let changes_dir = temp_dir.path().join("changes");
+        write_change(
+            &changes_dir,
+            "add-2fa",
+            "Passwords leak.",
+            Some(TWO_FACTOR_DELTA),
+        );
+        let changes = parse_changes(&changes_dir, false).unwrap();
+        let specs = vec![super::super::spec::parse_spec_content(
+            r#"# Auth Specification
+
+## Purpose
 
-    if norm_a == 0.0 || norm_b == 0.0 {
-        return 0.0;
-    }
+Handle authentication.
 
-    dot_product / (norm_a * norm_b)
-}
+## Requirements
 
-/// Truncate text to a maximum length, adding ellipsis if needed.
-fn truncate_text(text: &str, max_len: usize) -> String {
-    if text.len() <= max_len {
-        text.to_string()
-    } else {
-        format!("{}...", &text[..max_len.saturating_sub(3)])
-    }
-}
+### Requirement: Login
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+The system SHALL log users in with a password.
 
-    // ==================== Cosine similarity tests ====================
+#### Scenario: Locked account
 
-    #[test]
-    fn test_cosine_similarity_identical_vectors() {
-        let a = vec![1.0, 2.0, 3.0];
-        let b = vec![1.0, 2.0, 3.0];
-        let sim = cosine_similarity(&a, &b);
-        assert!((sim - 1.0).abs() < 0.0001, "Expected 1.0, got {}", sim);
+- **WHEN** user enters a wrong passcode three times
+- **THEN** the account is locked
+"#,
+            "auth",
+        )
+        .unwrap()];
+        let embedder = HashingEmbedder::new();
+        let index = build_index(&specs, &changes, &embedder).unwrap();
+
+        let results = search(
+            &index,
+            "passcode",
+            0,
+            10,
+            SearchMode::Keyword,
+            &SearchFilter::default(),
+            &embedder,
+        )
+        .unwrap()
+        .results;
+        let kinds: Vec<ResultKind> = results.iter().map(|r| r.kind).collect();
+        assert!(kinds.contains(&ResultKind::Scenario), "{:?}", kinds);
+        assert!(kinds.contains(&ResultKind::Change), "{:?}", kinds);
+
+        let scenario = results
+            .iter()
+            .find(|r| r.kind == ResultKind::Scenario)
+            .unwrap();
+        assert_eq!(scenario.spec_id, "auth");
+        assert_eq!(scenario.requirement.as_deref(), Some("Login"));
+        assert_eq!(scenario.scenario.as_deref(), Some("Locked account"));
+
+        let filter = SearchFilter {
+            kinds: vec![ResultKind::Change],
+            ..Default::default()
+        };
+        let results = search(
+            &index,
+            "passcode",
+            0,
+            10,
+            SearchMode::Keyword,
+            &filter,
+            &embedder,
+        )
+        .unwrap()
+        .results;
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|r| r.kind == ResultKind::Change));
+        assert!(results
+            .iter()
+            .all(|r| r.change_id.as_deref() == Some("add-2fa")));
+        assert!(results
+            .iter()
+            .any(|r| r.spec_id == "auth" && r.requirement.as_deref() == Some("Two Factor")));
     }
 
     #[test]
-    fn test_cosine_similarity_orthogonal_vectors() {
-        let a = vec![1.0, 0.0, 0.0];
-        let b = vec![0.0, 1.0, 0.0];
-        let sim = cosine_similarity(&a, &b);
-        assert!((sim - 0.0).abs() < 0.0001, "Expected 0.0, got {}", sim);
-    }
+    fn test_search_filters_by_spec_id() {
+        let specs = vec![
+            parsed_spec("auth", "Handle sessions.", &[("Login", "Start a session.")]),
+            parsed_spec("cli", "Run commands.", &[("Logout", "End a session.")]),
+        ];
+        let embedder = HashingEmbedder::new();
+        let index = build_index(&specs, &[], &embedder).unwrap();
+        let filter = SearchFilter {
+            spec_ids: vec!["cli".to_string()],
+            ..Default::default()
+        };
 
-    #[test]
-    fn test_cosine_similarity_opposite_vectors() {
-        let a = vec![1.0, 2.0, 3.0];
-        let b = vec![-1.0, -2.0, -3.0];
-        let sim = cosine_similarity(&a, &b);
-        assert!((sim - (-1.0)).abs() < 0.0001, "Expected -1.0, got {}", sim);
+        let results = search(
+            &index,
+            "session",
+            0,
+            10,
+            SearchMode::Keyword,
+            &filter,
+            &embedder,
+        )
+        .unwrap()
+        .results;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].spec_id, "cli");
+        assert_eq!(results[0].requirement.as_deref(), Some("Logout"));
     }
 
     #[test]
-    fn test_cosine_similarity_different_lengths() {
-        let a = vec![1.0, 2.0, 3.0];
-        let b = vec![1.0, 2.0];
-        let sim = cosine_similarity(&a, &b);
-        assert_eq!(sim, 0.0, "Expected 0.0 for different length vectors");
+    fn test_search_excludes_spec_ids_and_low_scores() {
+        let specs = vec![
+            parsed_spec("auth", "Handle sessions.", &[("Login", "Start a session.")]),
+            parsed_spec("cli", "Run commands.", &[("Logout", "End a session.")]),
+        ];
+        let embedder = HashingEmbedder::new();
+        let index = build_index(&specs, &[], &embedder).unwrap();
+
+        let filter = SearchFilter {
+            exclude_spec_ids: vec!["cli".to_string()],
+            ..Default::default()
+        };
+        let results = search(
+            &index,
+            "session",
+            0,
+            10,
+            SearchMode::Keyword,
+            &filter,
+            &embedder,
+        )
+        .unwrap()
+        .results;
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|r| r.spec_id == "auth"));
+
+        let filter = SearchFilter {
+            min_score: Some(1.1),
+            ..Default::default()
+        };
+        let results = search(
+            &index,
+            "session",
+            0,
+            10,
+            SearchMode::Keyword,
+            &filter,
+            &embedder,
+        )
+        .unwrap()
+        .results;
+        assert!(results.is_empty());
     }
 
     #[test]
-    fn test_cosine_similarity_empty_vectors() {
-        let a: Vec<f32> = vec![];
-        let b: Vec<f32> = vec![];
-        let sim = cosine_similarity(&a, &b);
-        assert_eq!(sim, 0.0, "Expected 0.0 for empty vectors");
+    fn test_search_skips_offset_and_reports_total() {
+        let specs = vec![
+            parsed_spec("auth", "Handle sessions.", &[("Login", "Start a session.")]),
+            parsed_spec("cli", "Run commands.", &[("Logout", "End a session.")]),
+        ];
+        let embedder = HashingEmbedder::new();
+        let index = build_index(&specs, &[], &embedder).unwrap();
+        let filter = SearchFilter {
+            kinds: vec![ResultKind::Requirement],
+            ..Default::default()
+        };
+
+        let first = search(
+            &index,
+            "session",
+            0,
+            1,
+            SearchMode::Keyword,
+            &filter,
+            &embedder,
+        )
+        .unwrap();
+        let second = search(
+            &index,
+            "session",
+            1,
+            1,
+            SearchMode::Keyword,
+            &filter,
+            &embedder,
+        )
+        .unwrap();
+        let past_end = search(
+            &index,
+            "session",
+            2,
+            1,
+            SearchMode::Keyword,
+            &filter,
+            &embedder,
+        )
+        .unwrap();
+
+        assert_eq!(first.total, 2);
+        assert_eq!(second.total, 2);
+        assert_eq!(first.results.len(), 1);
+        assert_eq!(second.results.len(), 1);
+        assert_ne!(first.results[0].spec_id, second.results[0].spec_id);
+        assert!(past_end.results.is_empty());
+        assert_eq!(past_end.total, 2);
     }
 
     #[test]
-    fn test_cosine_similarity_zero_vector() {
-        let a = vec![0.0, 0.0, 0.0];
-        let b = vec![1.0, 2.0, 3.0];
-        let sim = cosine_similarity(&a, &b);
-        assert_eq!(sim, 0.0, "Expected 0.0 when one vector is zero");
-    }
+    fn test_update_index_counts_scenarios_and_changes() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let changes_dir = temp_dir.path().join("changes");
+        write_change(
+            &changes_dir,
+            "add-2fa",
+            "Passwords leak.",
+            Some(TWO_FACTOR_DELTA),
+        );
+        let changes = parse_changes(&changes_dir, false).unwrap();
+        let mut specs = vec![parsed_spec("auth", "Handle auth.", &[("Login", "Log in.")])];
+        specs[0].requirements[0]
+            .scenarios
+            .push(super::super::spec::ParsedScenario {
+                name: "Success".to_string(),
+                when_clause: "valid password".to_string(),
+                then_clauses: vec!["logged in".to_string()],
+            });
+        let embedder = HashingEmbedder::new();
 
-    // ==================== Truncate text tests ====================
+        // Purpose, requirement, scenario, proposal and delta
+        let (index, counts) = update_index(None, &specs, &changes, &embedder).unwrap();
+        assert_eq!((counts.specs, counts.changes, counts.added), (1, 1, 5));
 
-    #[test]
-    fn test_truncate_text_short() {
-        let result = truncate_text("hello", 10);
-        assert_eq!(result, "hello");
+        // Archiving the change drops its proposal and delta
+        let (_, counts) = update_index(Some(&index), &specs, &[], &embedder).unwrap();
+        assert_eq!((counts.added, counts.updated, counts.removed), (0, 0, 2));
     }
 
     #[test]
-    fn test_truncate_text_long() {
-        let result = truncate_text("hello world this is a long text", 15);
-        assert_eq!(result, "hello world ...");
+    fn test_result_kind_round_trips_names() {
+        for kind in ResultKind::ALL {
+            assert_eq!(kind.as_str().parse::<ResultKind>(), Ok(kind));
+        }
+        assert!("section".parse::<ResultKind>().is_err());
     }
 
     #[test]
-    fn test_truncate_text_exact() {
-        let result = truncate_text("hello", 5);
-        assert_eq!(result, "hello");
-    }
+    fn test_reciprocal_rank_fusion() {
+        let fused = reciprocal_rank_fusion(&[vec![0.9, 0.5, 0.0], vec![0.2, 0.0, 0.0]]);
 
-    // ==================== Index save/load tests ====================
+        // First in both rankings scores 1.0, unranked entries 0.0
+        assert!(
+            (fused[0] - 1.0).abs() < 0.0001,
+            "Expected 1.0, got {}",
+            fused[0]
+        );
+        assert!(fused[1] > 0.0 && fused[1] < fused[0]);
+        assert_eq!(fused[2], 0.0);
+    }
 
     #[test]
-    fn test_save_and_load_index() {
-        let temp_dir = tempfile::TempDir::new().unwrap();
-        let index_path = temp_dir.path().join("test_index.bin");
+    fn test_update_index_ignores_index_from_other_backend() {
+        let specs = vec![parsed_spec(
+            "auth",
+            "Handle auth.",
+            &[("Login", "Users log in.")],
+        )];
+
+        let (index, changes) =
+            update_index(Some(&indexed(&specs)), &specs, &[], &HashingEmbedder::new()).unwrap();
+
+        assert_eq!(index.backend, EmbeddingBackend::Hashing);
+        assert_eq!((changes.added, changes.updated, changes.removed), (2, 0, 0));
+        assert_ne!(index.specs[0].purpose_embedding, vec![1.0, 0.0]);
+    }
 
-        // Create a simple index
-        let index = SpecIndex {
-            model_name: "test-model".to_string(),
-            specs: vec![IndexedSpec {
-                id: "test-spec".to_string(),
-                title: "Test Spec".to_string(),
-                purpose_embedding: vec![0.1, 0.2, 0.3],
-                requirements: vec![IndexedRequirement {
-                    name: "Test Requirement".to_string(),
-                    description: "This is a test requirement.".to_string(),
-                    description_embedding: vec![0.4, 0.5, 0.6],
-                }],
-            }],
-        };
+    /// The hashing embedder, dropping the last embedding of every batch.
+    struct ShortEmbedder;
 
-        // Save the index
-        let save_result = save_index(&index, &index_path);
-        assert!(
-            save_result.is_ok(),
-            "Failed to save index: {:?}",
-            save_result
-        );
+    impl Embedder for ShortEmbedder {
+        fn backend(&self) -> EmbeddingBackend {
+            EmbeddingBackend::Hashing
+        }
 
-        // Load the index
-        let load_result = load_index(&index_path);
-        assert!(
-            load_result.is_ok(),
-            "Failed to load index: {:?}",
-            load_result
-        );
+        fn model_name(&self) -> String {
+            HashingEmbedder::new().model_name()
+        }
 
-        let loaded_index = load_result.unwrap();
-        assert_eq!(loaded_index.model_name, "test-model");
-        assert_eq!(loaded_index.specs.len(), 1);
-        assert_eq!(loaded_index.specs[0].id, "test-spec");
-        assert_eq!(loaded_index.specs[0].requirements.len(), 1);
+        fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+            let mut embeddings = HashingEmbedder::new().embed(texts)?;
+            embeddings.pop();
+            Ok(embeddings)
+        }
     }
 
     #[test]
-    fn test_load_index_not_found() {
-        let result = load_index(Path::new("/nonexistent/path/index.bin"));
-        assert!(result.is_err());
+    fn test_update_index_rejects_missing_embeddings() {
+        let specs = vec![parsed_spec(
+            "auth",
+            "Handle auth.",
+            &[("Login", "Users log in.")],
+        )];
+
+        let err = update_index(None, &specs, &[], &ShortEmbedder).unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("Embedder returned 1 embeddings for 2 texts"),
+            "Unexpected error: {}",
+            err
+        );
     }
 
     // ==================== Integration tests (require model download) ====================
@@ -520,7 +2413,7 @@ mod tests {
             }],
         }];
 
-        let result = build_index(&specs);
+        let result = build_index(&specs, &[], &fastembed());
         assert!(result.is_ok(), "Failed to build index: {:?}", result);
 
         let index = result.unwrap();
@@ -561,10 +2454,20 @@ mod tests {
             },
         ];
 
-        let index = build_index(&specs).expect("Failed to build index");
+        let index = build_index(&specs, &[], &fastembed()).expect("Failed to build index");
 
         // Search for authentication-related content
-        let results = search(&index, "user login authentication", 5).expect("Failed to search");
+        let results = search(
+            &index,
+            "user login authentication",
+            0,
+            5,
+            SearchMode::Semantic,
+            &SearchFilter::default(),
+            &fastembed(),
+        )
+        .expect("Failed to search")
+        .results;
 
         assert!(!results.is_empty(), "Expected search results");
 
@@ -585,11 +2488,21 @@ mod tests {
             requirements: vec![],
         }];
 
-        let index = build_index(&specs).expect("Failed to build index");
+        let index = build_index(&specs, &[], &fastembed()).expect("Failed to build index");
 
         // Search for something completely unrelated - with semantic search,
         // we may still get some results with low scores
-        let results = search(&index, "xyzzy quantum chromodynamics", 5).expect("Failed to search");
+        let results = search(
+            &index,
+            "xyzzy quantum chromodynamics",
+            0,
+            5,
+            SearchMode::Semantic,
+            &SearchFilter::default(),
+            &fastembed(),
+        )
+        .expect("Failed to search")
+        .results;
 
         // With semantic search, we might get results but with low scores
         // Just verify the search doesn't crash
@@ -641,7 +2554,7 @@ The system SHALL do something.
         assert!(!index_path.exists(), "Index should not exist yet");
 
         // Call ensure_index
-        let result = ensure_index(project_root, &specs_dir);
+        let result = ensure_index(project_root, &folders(project_root), &fastembed());
 
         // Should succeed
         assert!(result.is_ok(), "ensure_index should succeed: {:?}", result);
@@ -650,7 +2563,8 @@ The system SHALL do something.
         assert!(index_path.exists(), "Index file should be created");
 
         // Returned index should have the spec
-        let index = result.unwrap();
+        let (index, freshness) = result.unwrap();
+        assert_eq!(freshness, IndexFreshness::Built);
         assert_eq!(index.specs.len(), 1);
         assert_eq!(index.specs[0].id, "test-spec");
     }
@@ -672,30 +2586,385 @@ The system SHALL do something.
 
         // Create an existing index file with known data
         let existing_index = SpecIndex {
+            backend: EmbeddingBackend::Fastembed,
             model_name: "existing-model".to_string(),
+            // The fingerprint of the empty spec folder
+            fingerprint: fingerprint(&[], &[]),
             specs: vec![IndexedSpec {
                 id: "existing-spec".to_string(),
                 title: "Existing Spec".to_string(),
+                purpose_hash: content_hash("Existing purpose."),
                 purpose_embedding: vec![0.1, 0.2, 0.3],
+                purpose_terms: TermCounts::new(),
                 requirements: vec![],
             }],
+            changes: vec![],
+            ann: None,
         };
         let index_path = spox_dir.join("search_index.bin");
         save_index(&existing_index, &index_path).unwrap();
 
         // Call ensure_index
-        let result = ensure_index(project_root, &specs_dir);
+        let result = ensure_index(project_root, &folders(project_root), &fastembed());
 
         // Should succeed and return existing index
         assert!(result.is_ok(), "ensure_index should succeed: {:?}", result);
 
-        let index = result.unwrap();
+        let (index, freshness) = result.unwrap();
         // Should have loaded the existing index, not rebuilt
+        assert_eq!(freshness, IndexFreshness::Current);
         assert_eq!(index.model_name, "existing-model");
         assert_eq!(index.specs.len(), 1);
         assert_eq!(index.specs[0].id, "existing-spec");
     }
 
+    /// The hashing embedder as recorded in the index, failing to embed as if
+    /// its model could not be loaded.
+    struct UnavailableEmbedder;
+
+    impl Embedder for UnavailableEmbedder {
+        fn backend(&self) -> EmbeddingBackend {
+            EmbeddingBackend::Hashing
+        }
+
+        fn model_name(&self) -> String {
+            HashingEmbedder::new().model_name()
+        }
+
+        fn embed(&self, _texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+            Err(Error::Other("Model not available".to_string()))
+        }
+    }
+
+    /// A project with one spec, indexed with the hashing embedder.
+    fn indexed_project() -> tempfile::TempDir {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        write_spec(temp_dir.path(), "The system SHALL log users in.");
+        ensure_index(
+            temp_dir.path(),
+            &folders(temp_dir.path()),
+            &HashingEmbedder::new(),
+        )
+        .unwrap();
+        temp_dir
+    }
+
+    fn write_spec(project_root: &Path, requirement: &str) {
+        let spec_dir = project_root.join("specs/auth");
+        std::fs::create_dir_all(&spec_dir).unwrap();
+        std::fs::write(
+            spec_dir.join("spec.md"),
+            format!(
+                "# Auth Specification\n\n## Purpose\n\nHandle auth.\n\n## Requirements\n\n\
+                 ### Requirement: Login\n\n{}\n\n#### Scenario: Success\n\n\
+                 - **WHEN** valid password\n- **THEN** logged in\n",
+                requirement
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_ensure_index_updates_stale_index() {
+        let temp_dir = indexed_project();
+        let project_root = temp_dir.path();
+        let embedder = HashingEmbedder::new();
+
+        let (_, freshness) = ensure_index(project_root, &folders(project_root), &embedder).unwrap();
+        assert_eq!(freshness, IndexFreshness::Current);
+
+        write_spec(
+            project_root,
+            "The system SHALL log users in with a passkey.",
+        );
+        let (index, freshness) =
+            ensure_index(project_root, &folders(project_root), &embedder).unwrap();
+        assert_eq!(freshness, IndexFreshness::Updated);
+        assert!(index.specs[0].requirements[0]
+            .description
+            .contains("passkey"));
+
+        // The update was saved
+        let (_, freshness) = ensure_index(project_root, &folders(project_root), &embedder).unwrap();
+        assert_eq!(freshness, IndexFreshness::Current);
+    }
+
+    #[test]
+    fn test_ensure_index_rebuilds_unreadable_index() {
+        let temp_dir = indexed_project();
+        let project_root = temp_dir.path();
+        std::fs::write(project_root.join(INDEX_PATH), b"old index").unwrap();
+
+        let (index, freshness) = ensure_index(
+            project_root,
+            &folders(project_root),
+            &HashingEmbedder::new(),
+        )
+        .unwrap();
+
+        assert_eq!(freshness, IndexFreshness::Updated);
+        assert_eq!(index.specs.len(), 1);
+        assert!(load_index(&project_root.join(INDEX_PATH)).is_ok());
+    }
+
+    #[test]
+    fn test_ensure_index_returns_stale_index_if_update_fails() {
+        let temp_dir = indexed_project();
+        let project_root = temp_dir.path();
+        write_spec(
+            project_root,
+            "The system SHALL log users in with a passkey.",
+        );
+
+        let (index, freshness) =
+            ensure_index(project_root, &folders(project_root), &UnavailableEmbedder).unwrap();
+
+        assert!(
+            matches!(freshness, IndexFreshness::Stale(_)),
+            "{:?}",
+            freshness
+        );
+        assert!(!index.specs[0].requirements[0]
+            .description
+            .contains("passkey"));
+    }
+
+    #[test]
+    fn test_check_index_reports_status() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project_root = temp_dir.path();
+        let embedder = HashingEmbedder::new();
+        write_spec(project_root, "The system SHALL log users in.");
+        let check = || check_index(project_root, &folders(project_root), &embedder).unwrap();
+
+        assert_eq!(check(), IndexStatus::Missing);
+
+        ensure_index(project_root, &folders(project_root), &embedder).unwrap();
+        assert_eq!(check(), IndexStatus::Current);
+        assert!(check().is_current());
+
+        write_spec(
+            project_root,
+            "The system SHALL log users in with a passkey.",
+        );
+        assert_eq!(check(), IndexStatus::Stale);
+
+        let status = check_index(
+            project_root,
+            &folders(project_root),
+            &LazyEmbedder::new(EmbeddingBackend::Fastembed),
+        )
+        .unwrap();
+        assert!(
+            matches!(status, IndexStatus::Incompatible { .. }),
+            "{:?}",
+            status
+        );
+
+        std::fs::write(project_root.join(INDEX_PATH), b"old index").unwrap();
+        assert!(matches!(check(), IndexStatus::Unreadable(_)));
+    }
+
+    // ==================== ANN graph tests ====================
+
+    /// Embeds the words of a text into 16 hashed dimensions: a cheap stand-in
+    /// for a model when building graphs over thousands of texts.
+    struct WordEmbedder;
+
+    impl Embedder for WordEmbedder {
+        fn backend(&self) -> EmbeddingBackend {
+            EmbeddingBackend::Hashing
+        }
+
+        fn model_name(&self) -> String {
+            "words-16".to_string()
+        }
+
+        fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+            Ok(texts
+                .iter()
+                .map(|text| {
+                    let mut embedding = vec![0.0; 16];
+                    for word in text.split_whitespace() {
+                        let hash = Sha256::digest(word.as_bytes());
+                        embedding[usize::from(hash[0]) % 16] += 1.0;
+                        embedding[usize::from(hash[1]) % 16] -= 1.0;
+                    }
+                    embedding
+                })
+                .collect())
+        }
+    }
+
+    /// Specs with `ANN_THRESHOLD` requirements made of pseudo-random words.
+    fn large_corpus() -> Vec<ParsedSpec> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut word = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            format!("w{}", state % 5000)
+        };
+        (0..ANN_THRESHOLD / 20)
+            .map(|i| {
+                let mut spec = parsed_spec(&format!("spec-{}", i), &word(), &[]);
+                spec.requirements = (0..20)
+                    .map(|j| super::super::spec::ParsedRequirement {
+                        name: format!("Requirement {}", j),
+                        description: (0..8).map(|_| word()).collect::<Vec<_>>().join(" "),
+                        scenarios: vec![],
+                    })
+                    .collect();
+                spec
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_small_index_has_no_ann_graph() {
+        let specs = vec![parsed_spec("auth", "Handle auth.", &[("Login", "Log in.")])];
+        let index = build_index(&specs, &[], &HashingEmbedder::new()).unwrap();
+        assert!(index.ann.is_none());
+    }
+
+    #[test]
+    fn test_ann_search_recall_against_exact_scan() {
+        let specs = large_corpus();
+        let embedder = WordEmbedder;
+        let mut index = build_index(&specs, &[], &embedder).unwrap();
+        assert!(index.ann.is_some(), "Large indexes get an ANN graph");
+
+        let k = 10;
+        let mut hits = 0;
+        let mut queries = 0;
+        for spec in specs.iter().step_by(5) {
+            let query = &spec.requirements[7].description;
+            let search = |index: &SpecIndex| {
+                search(
+                    index,
+                    query,
+                    0,
+                    k,
+                    SearchMode::Semantic,
+                    &SearchFilter::default(),
+                    &embedder,
+                )
+                .unwrap()
+                .results
+            };
+            let approximate = search(&index);
+            let graph = index.ann.take();
+            let exact = search(&index);
+            index.ann = graph;
+
+            // The requirement itself is always found first
+            assert_eq!(approximate[0].spec_id, spec.id);
+            assert_eq!(approximate[0].requirement.as_deref(), Some("Requirement 7"));
+            hits += approximate
+                .iter()
+                .filter(|a| {
+                    exact
+                        .iter()
+                        .any(|e| e.spec_id == a.spec_id && e.requirement == a.requirement)
+                })
+                .count();
+            queries += 1;
+        }
+
+        let recall = hits as f32 / (queries * k) as f32;
+        assert!(recall >= 0.9, "Recall@{} too low: {}", k, recall);
+    }
+
+    #[test]
+    fn test_ann_search_fills_deep_pages() {
+        let specs = large_corpus();
+        let embedder = WordEmbedder;
+        let mut index = build_index(&specs, &[], &embedder).unwrap();
+        assert!(index.ann.is_some(), "Large indexes get an ANN graph");
+
+        let query = &specs[21].requirements[7].description;
+        let page = |index: &SpecIndex, offset: usize, filter: &SearchFilter| {
+            search(
+                index,
+                query,
+                offset,
+                10,
+                SearchMode::Semantic,
+                filter,
+                &embedder,
+            )
+            .unwrap()
+        };
+
+        // A deep page of an unfiltered search is full
+        let deep = page(&index, 500, &SearchFilter::default());
+        assert_eq!(deep.results.len(), 10);
+        assert!(deep.total >= 510);
+
+        let strong = SearchFilter {
+            min_score: Some(0.5),
+            ..Default::default()
+        };
+        index.ann = None;
+        let total = page(&index, 0, &strong).total;
+        assert!(total > 10, "Too few strong matches: {}", total);
+
+        // A graph linked in the wrong order misses strong matches, so the
+        // last page is only found by comparing with every text
+        let mut reversed = index.embeddings();
+        reversed.reverse();
+        index.ann = Some(Hnsw::build(&reversed));
+
+        let last = page(&index, total - 1, &strong);
+        assert_eq!(last.results.len(), 1);
+        assert_eq!(last.total, total);
+
+        let past_end = page(&index, total, &strong);
+        assert!(past_end.results.is_empty());
+        assert_eq!(past_end.total, total);
+    }
+
+    #[test]
+    fn test_ann_graph_is_saved_next_to_index() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let index_path = temp_dir.path().join("search_index.bin");
+        let embedder = WordEmbedder;
+
+        let index = build_index(&large_corpus(), &[], &embedder).unwrap();
+        save_index(&index, &index_path).unwrap();
+        assert!(temp_dir.path().join("search_index.hnsw").exists());
+        assert!(load_index(&index_path).unwrap().ann.is_some());
+
+        // A graph of another index is ignored
+        let small = build_index(
+            &[parsed_spec("auth", "Handle auth.", &[("Login", "Log in.")])],
+            &[],
+            &embedder,
+        )
+        .unwrap();
+        write_versioned(&index_path, INDEX_MAGIC, &small).unwrap();
+        assert!(load_index(&index_path).unwrap().ann.is_none());
+
+        // Saving a small index removes the graph file
+        save_index(&small, &index_path).unwrap();
+        assert!(!temp_dir.path().join("search_index.hnsw").exists());
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_texts_and_titles() {
+        let specs = vec![parsed_spec("auth", "Handle auth.", &[("Login", "Log in.")])];
+        let base = fingerprint(&specs, &[]);
+        assert_eq!(base, fingerprint(&specs, &[]));
+
+        let mut renamed = specs.clone();
+        renamed[0].title = "Authentication".to_string();
+        assert_ne!(base, fingerprint(&renamed, &[]));
+
+        let mut edited = specs.clone();
+        edited[0].requirements[0].description = "Log in fast.".to_string();
+        assert_ne!(base, fingerprint(&edited, &[]));
+    }
+
     #[test]
     #[ignore]
     fn test_build_and_save_load_roundtrip() {
@@ -716,7 +2985,7 @@ The system SHALL do something.
         }];
 
         // Build the index
-        let index = build_index(&specs).expect("Failed to build index");
+        let index = build_index(&specs, &[], &fastembed()).expect("Failed to build index");
 
         // Save it
         save_index(&index, &index_path).expect("Failed to save index");
@@ -810,11 +3079,20 @@ Process payments.
         .unwrap();
 
         // Rebuild index
-        let result = rebuild_index(project_root);
+        let result = rebuild_index(project_root, &fastembed());
         assert!(result.is_ok(), "Expected Ok, got {:?}", result);
 
-        let count = result.unwrap();
-        assert_eq!(count, 2, "Expected 2 specs indexed, got {}", count);
+        let changes = result.unwrap();
+        assert_eq!(
+            changes.specs, 2,
+            "Expected 2 specs indexed, got {}",
+            changes.specs
+        );
+        assert_eq!(changes.added, 4);
+
+        // A second rebuild finds nothing to do
+        let changes = rebuild_index(project_root, &fastembed()).expect("Should rebuild again");
+        assert_eq!((changes.added, changes.updated, changes.removed), (0, 0, 0));
 
         // Verify index file was created
         let index_path = spox_dir.join("search_index.bin");
@@ -855,11 +3133,15 @@ system = ["mcp"]
         fs::create_dir_all(&specs_dir).unwrap();
 
         // Rebuild index
-        let result = rebuild_index(project_root);
+        let result = rebuild_index(project_root, &fastembed());
         assert!(result.is_ok(), "Expected Ok, got {:?}", result);
 
-        let count = result.unwrap();
-        assert_eq!(count, 0, "Expected 0 specs indexed, got {}", count);
+        let changes = result.unwrap();
+        assert_eq!(
+            changes.specs, 0,
+            "Expected 0 specs indexed, got {}",
+            changes.specs
+        );
 
         // Verify index file was created (even if empty)
         let index_path = spox_dir.join("search_index.bin");
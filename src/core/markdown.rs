@@ -0,0 +1,1296 @@
+//! Markdown document model for specs and delta specs.
+//!
+//! Every spec-shaped file (`spec.md` in the spec folder and the delta specs of a
+//! change) is parsed once into a [`Document`]: a typed tree of sections,
+//! requirements, scenarios and clauses. Every node carries a [`Span`] with
+//! 1-indexed line and column positions so that show, validate, index and MCP
+//! all agree on the structure and can point at exact locations.
+//!
+//! Headings inside fenced code blocks are treated as plain text.
+
+/// A position in a document (1-indexed line and column, columns counted in chars).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Position {
+    /// Line number (1-indexed).
+    pub line: usize,
+    /// Column number (1-indexed).
+    pub column: usize,
+}
+
+/// A range in a document. `end` points one column past the last character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    /// First character of the node.
+    pub start: Position,
+    /// One past the last character of the node.
+    pub end: Position,
+}
+
+/// Delta operation type of a delta spec section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeltaOp {
+    Added,
+    Modified,
+    Removed,
+    Renamed,
+}
+
+impl DeltaOp {
+    /// All operations in the order they appear in delta specs.
+    pub const ALL: [DeltaOp; 4] = [
+        DeltaOp::Added,
+        DeltaOp::Modified,
+        DeltaOp::Removed,
+        DeltaOp::Renamed,
+    ];
+
+    /// Get the symbol for this operation.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            DeltaOp::Added => "+",
+            DeltaOp::Modified => "~",
+            DeltaOp::Removed => "-",
+            DeltaOp::Renamed => ">",
+        }
+    }
+
+    /// Get the label for this operation.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeltaOp::Added => "ADDED",
+            DeltaOp::Modified => "MODIFIED",
+            DeltaOp::Removed => "REMOVED",
+            DeltaOp::Renamed => "RENAMED",
+        }
+    }
+}
+
+impl std::str::FromStr for DeltaOp {
+    type Err = String;
+
+    /// Parse an operation from its label, case-insensitively (`added`, `MODIFIED`, ...).
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        DeltaOp::ALL
+            .into_iter()
+            .find(|op| op.label().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                format!(
+                    "Unknown delta operation '{}': expected added, modified, removed or renamed",
+                    s
+                )
+            })
+    }
+}
+
+/// A markdown heading (`#` to `######`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    /// Heading level (number of `#`).
+    pub level: usize,
+    /// Heading text without the `#` markers.
+    pub text: String,
+    /// Location of the heading line.
+    pub span: Span,
+}
+
+/// The role of a level-2 section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionKind {
+    /// `## Purpose`
+    Purpose,
+    /// `## Requirements`
+    Requirements,
+    /// `## ADDED|MODIFIED|REMOVED|RENAMED Requirements`
+    Delta(DeltaOp),
+    /// Any other level-2 section.
+    Other,
+}
+
+/// A level-2 section with the requirements it contains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// The `##` heading.
+    pub heading: Heading,
+    /// The role of the section.
+    pub kind: SectionKind,
+    /// Section text outside of requirement blocks, trimmed.
+    pub text: String,
+    /// Requirement blocks in document order.
+    pub requirements: Vec<Requirement>,
+    /// From the heading to the last non-blank line of the section.
+    pub span: Span,
+}
+
+/// A `### Requirement:` block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Requirement {
+    /// The requirement name.
+    pub name: String,
+    /// The `###` heading.
+    pub heading: Heading,
+    /// Non-blank lines before the first scenario, joined with spaces.
+    pub description: String,
+    /// Scenario blocks in document order.
+    pub scenarios: Vec<Scenario>,
+    /// The raw block as written (heading included, trailing blank lines removed).
+    pub content: String,
+    /// From the heading to the last non-blank line of the block.
+    pub span: Span,
+}
+
+/// A `#### Scenario:` block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scenario {
+    /// The scenario name.
+    pub name: String,
+    /// The `####` heading.
+    pub heading: Heading,
+    /// WHEN/THEN/AND clauses in document order.
+    pub clauses: Vec<Clause>,
+    /// From the heading to the last non-blank line of the block.
+    pub span: Span,
+}
+
+/// Keyword of a scenario clause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClauseKind {
+    When,
+    Then,
+    And,
+}
+
+/// A single `- **WHEN**` / `- **THEN**` / `- **AND**` line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clause {
+    /// The clause keyword.
+    pub kind: ClauseKind,
+    /// The clause text without list marker and keyword.
+    pub text: String,
+    /// Location of the clause line.
+    pub span: Span,
+}
+
+/// A parsed spec or delta spec document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    /// The first level-1 heading.
+    pub title: Option<Heading>,
+    /// Level-2 sections in document order.
+    pub sections: Vec<Section>,
+    /// Every heading outside of code blocks, in document order.
+    pub headings: Vec<Heading>,
+}
+
+impl SectionKind {
+    /// Determine the role of a level-2 section from its heading text.
+    pub fn from_heading(text: &str) -> Self {
+        if text.eq_ignore_ascii_case("Purpose") {
+            return SectionKind::Purpose;
+        }
+        if text.eq_ignore_ascii_case("Requirements") {
+            return SectionKind::Requirements;
+        }
+        for op in DeltaOp::ALL {
+            if text.eq_ignore_ascii_case(&format!("{} Requirements", op.label())) {
+                return SectionKind::Delta(op);
+            }
+        }
+        SectionKind::Other
+    }
+
+    /// The canonical heading text of the section, if it has a fixed name.
+    pub fn heading_text(&self) -> Option<String> {
+        match self {
+            SectionKind::Purpose => Some("Purpose".to_string()),
+            SectionKind::Requirements => Some("Requirements".to_string()),
+            SectionKind::Delta(op) => Some(format!("{} Requirements", op.label())),
+            SectionKind::Other => None,
+        }
+    }
+}
+
+impl ClauseKind {
+    /// The clause keyword as written in specs.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            ClauseKind::When => "WHEN",
+            ClauseKind::Then => "THEN",
+            ClauseKind::And => "AND",
+        }
+    }
+}
+
+impl Scenario {
+    /// Whether the scenario has a WHEN clause.
+    pub fn has_when(&self) -> bool {
+        self.clauses.iter().any(|c| c.kind == ClauseKind::When)
+    }
+
+    /// Whether the scenario has a THEN clause.
+    pub fn has_then(&self) -> bool {
+        self.clauses.iter().any(|c| c.kind == ClauseKind::Then)
+    }
+
+    /// The WHEN clause text, including AND clauses before the first THEN.
+    pub fn when_text(&self) -> String {
+        self.clauses
+            .iter()
+            .take_while(|c| c.kind != ClauseKind::Then)
+            .skip_while(|c| c.kind != ClauseKind::When)
+            .map(|c| c.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" AND ")
+    }
+
+    /// The THEN clause texts, including AND clauses after the first THEN.
+    pub fn then_texts(&self) -> Vec<String> {
+        self.clauses
+            .iter()
+            .skip_while(|c| c.kind != ClauseKind::Then)
+            .filter(|c| c.kind != ClauseKind::When)
+            .map(|c| c.text.clone())
+            .collect()
+    }
+}
+
+impl Section {
+    /// The section text as a single paragraph (non-blank lines joined with spaces).
+    pub fn paragraph(&self) -> String {
+        self.text
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Document {
+    /// Parse markdown content into a document tree.
+    pub fn parse(content: &str) -> Document {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut parser = Parser::new(&lines);
+        let mut in_fence = false;
+
+        for (idx, line) in lines.iter().enumerate() {
+            let trimmed = line.trim();
+
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+                parser.body_line(idx, true);
+                continue;
+            }
+
+            if !in_fence {
+                if let Some(heading) = parse_heading(line, idx) {
+                    parser.heading(heading, idx);
+                    continue;
+                }
+            }
+
+            parser.body_line(idx, in_fence);
+        }
+
+        parser.finish()
+    }
+
+    /// The first section of the given kind.
+    pub fn section(&self, kind: SectionKind) -> Option<&Section> {
+        self.sections.iter().find(|s| s.kind == kind)
+    }
+
+    /// The first level-2 section with the given heading text (case-insensitive).
+    pub fn section_named(&self, name: &str) -> Option<&Section> {
+        self.sections
+            .iter()
+            .find(|s| s.heading.text.eq_ignore_ascii_case(name))
+    }
+
+    /// The requirements of the `## Requirements` section (empty if missing).
+    pub fn spec_requirements(&self) -> &[Requirement] {
+        self.section(SectionKind::Requirements)
+            .map(|s| s.requirements.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// All delta sections with their operation, in document order.
+    pub fn delta_sections(&self) -> impl Iterator<Item = (DeltaOp, &Section)> {
+        self.sections.iter().filter_map(|s| match s.kind {
+            SectionKind::Delta(op) => Some((op, s)),
+            _ => None,
+        })
+    }
+}
+
+// =============================================================================
+// Parser
+// =============================================================================
+
+/// A requirement being built, with its source range.
+struct OpenRequirement {
+    heading: Heading,
+    start: usize,
+    last: usize,
+    description: Vec<String>,
+    scenarios: Vec<Scenario>,
+}
+
+/// A section being built, with its source range.
+struct OpenSection {
+    heading: Heading,
+    last: usize,
+    text: Vec<String>,
+    requirements: Vec<Requirement>,
+}
+
+/// A scenario being built, with its source range.
+struct OpenScenario {
+    heading: Heading,
+    last: usize,
+    clauses: Vec<Clause>,
+}
+
+struct Parser<'a> {
+    lines: &'a [&'a str],
+    doc: Document,
+    section: Option<OpenSection>,
+    requirement: Option<OpenRequirement>,
+    scenario: Option<OpenScenario>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(lines: &'a [&'a str]) -> Self {
+        Self {
+            lines,
+            doc: Document::default(),
+            section: None,
+            requirement: None,
+            scenario: None,
+        }
+    }
+
+    fn heading(&mut self, heading: Heading, idx: usize) {
+        self.doc.headings.push(heading.clone());
+
+        match heading.level {
+            1 => {
+                self.close_section();
+                if self.doc.title.is_none() {
+                    self.doc.title = Some(heading);
+                }
+            }
+            2 => {
+                self.close_section();
+                self.section = Some(OpenSection {
+                    heading,
+                    last: idx,
+                    text: Vec::new(),
+                    requirements: Vec::new(),
+                });
+            }
+            3 => {
+                self.close_requirement();
+                if let Some(section) = self.section.as_mut() {
+                    section.last = idx;
+                    if heading.text.starts_with("Requirement:") {
+                        self.requirement = Some(OpenRequirement {
+                            heading,
+                            start: idx,
+                            last: idx,
+                            description: Vec::new(),
+                            scenarios: Vec::new(),
+                        });
+                    } else {
+                        section.text.push(self.lines[idx].to_string());
+                    }
+                }
+            }
+            4 => {
+                self.close_scenario();
+                if let Some(requirement) = self.requirement.as_mut() {
+                    requirement.last = idx;
+                    if heading.text.starts_with("Scenario:") {
+                        self.scenario = Some(OpenScenario {
+                            heading,
+                            last: idx,
+                            clauses: Vec::new(),
+                        });
+                    }
+                } else {
+                    self.body_line(idx, false);
+                }
+            }
+            _ => self.body_line(idx, false),
+        }
+    }
+
+    fn body_line(&mut self, idx: usize, in_fence: bool) {
+        let line = self.lines[idx];
+        let blank = line.trim().is_empty();
+
+        if let Some(scenario) = self.scenario.as_mut() {
+            if !blank {
+                scenario.last = idx;
+            }
+            if !in_fence {
+                if let Some(clause) = parse_clause(line, idx) {
+                    scenario.clauses.push(clause);
+                }
+            }
+        } else if let Some(requirement) = self.requirement.as_mut() {
+            if !blank {
+                requirement.last = idx;
+                if requirement.scenarios.is_empty() {
+                    requirement.description.push(line.trim().to_string());
+                }
+            }
+        } else if let Some(section) = self.section.as_mut() {
+            if !blank {
+                section.last = idx;
+            }
+            section.text.push(line.to_string());
+        }
+    }
+
+    fn close_scenario(&mut self) {
+        if let Some(scenario) = self.scenario.take() {
+            let span = self.span(&scenario.heading, scenario.last);
+            if let Some(requirement) = self.requirement.as_mut() {
+                requirement.last = requirement.last.max(scenario.last);
+                requirement.scenarios.push(Scenario {
+                    name: heading_name(&scenario.heading, "Scenario:"),
+                    heading: scenario.heading,
+                    clauses: scenario.clauses,
+                    span,
+                });
+            }
+        }
+    }
+
+    fn close_requirement(&mut self) {
+        self.close_scenario();
+        if let Some(requirement) = self.requirement.take() {
+            let span = self.span(&requirement.heading, requirement.last);
+            let content = self.lines[requirement.start..=requirement.last].join("\n");
+            if let Some(section) = self.section.as_mut() {
+                section.last = section.last.max(requirement.last);
+                section.requirements.push(Requirement {
+                    name: heading_name(&requirement.heading, "Requirement:"),
+                    heading: requirement.heading,
+                    description: requirement.description.join(" "),
+                    scenarios: requirement.scenarios,
+                    content,
+                    span,
+                });
+            }
+        }
+    }
+
+    fn close_section(&mut self) {
+        self.close_requirement();
+        if let Some(section) = self.section.take() {
+            let span = self.span(&section.heading, section.last);
+            self.doc.sections.push(Section {
+                kind: SectionKind::from_heading(&section.heading.text),
+                heading: section.heading,
+                text: section.text.join("\n").trim().to_string(),
+                requirements: section.requirements,
+                span,
+            });
+        }
+    }
+
+    fn finish(mut self) -> Document {
+        self.close_section();
+        self.doc
+    }
+
+    /// Span from a heading to the end of the given (0-indexed) line.
+    fn span(&self, heading: &Heading, last: usize) -> Span {
+        Span {
+            start: heading.span.start,
+            end: Position {
+                line: last + 1,
+                column: self.lines[last].trim_end().chars().count() + 1,
+            },
+        }
+    }
+}
+
+/// Parse a heading line (`#` to `######` followed by a space).
+fn parse_heading(line: &str, idx: usize) -> Option<Heading> {
+    let trimmed = line.trim();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+
+    Some(Heading {
+        level,
+        text: rest.trim().to_string(),
+        span: line_span(line, idx),
+    })
+}
+
+/// Parse a scenario clause line, e.g. `- **WHEN** user logs in`.
+///
+/// A bold keyword (`**WHEN**`, `**When**`) counts anywhere in the line; a
+/// plain keyword (`WHEN user logs in`) only at its start, after an optional
+/// list marker. Keywords match case-insensitively, as in the validators this
+/// parser replaced.
+fn parse_clause(line: &str, idx: usize) -> Option<Clause> {
+    let body = strip_list_marker(line.trim());
+
+    for kind in [ClauseKind::When, ClauseKind::Then, ClauseKind::And] {
+        let keyword = kind.keyword();
+        let text = remove_bold_keyword(body, keyword)
+            .or_else(|| strip_plain_keyword(body, keyword).map(|rest| clause_text("", rest)));
+
+        if let Some(text) = text {
+            return Some(Clause {
+                kind,
+                text,
+                span: line_span(line, idx),
+            });
+        }
+    }
+
+    None
+}
+
+/// Remove the first `**KEYWORD**` marker (case-insensitive) and return the
+/// text around it.
+fn remove_bold_keyword(text: &str, keyword: &str) -> Option<String> {
+    let marker_len = keyword.len() + 4;
+    text.match_indices("**").find_map(|(start, _)| {
+        let marker = text.get(start..start + marker_len)?;
+        let word = marker.get(2..2 + keyword.len())?;
+        (word.eq_ignore_ascii_case(keyword) && marker.ends_with("**"))
+            .then(|| clause_text(&text[..start], &text[start + marker_len..]))
+    })
+}
+
+/// Strip a plain `KEYWORD` prefix (case-insensitive) followed by a space or colon.
+fn strip_plain_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    let word = text.get(..keyword.len())?;
+    let rest = &text[keyword.len()..];
+    (word.eq_ignore_ascii_case(keyword) && (rest.starts_with(' ') || rest.starts_with(':')))
+        .then_some(rest)
+}
+
+/// Join the text before and after a clause keyword.
+fn clause_text(before: &str, after: &str) -> String {
+    let before = before.trim();
+    let after = after.trim_start().trim_start_matches(':').trim();
+    match (before.is_empty(), after.is_empty()) {
+        (true, _) => after.to_string(),
+        (false, true) => before.to_string(),
+        (false, false) => format!("{} {}", before, after),
+    }
+}
+
+/// Strip a `- `, `* `, `+ ` or `1. ` list marker.
+fn strip_list_marker(text: &str) -> &str {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(rest) = text.strip_prefix(marker) {
+            return rest.trim_start();
+        }
+    }
+
+    let digits = text.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        if let Some(rest) = text[digits..].strip_prefix(". ") {
+            return rest.trim_start();
+        }
+    }
+
+    text
+}
+
+/// Name of a `Requirement:` or `Scenario:` heading.
+fn heading_name(heading: &Heading, prefix: &str) -> String {
+    heading
+        .text
+        .strip_prefix(prefix)
+        .unwrap_or(&heading.text)
+        .trim()
+        .to_string()
+}
+
+/// Span covering the non-blank part of a single line.
+fn line_span(line: &str, idx: usize) -> Span {
+    let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+    Span {
+        start: Position {
+            line: idx + 1,
+            column: indent + 1,
+        },
+        end: Position {
+            line: idx + 1,
+            column: line.trim_end().chars().count() + 1,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"# Auth Specification
+
+## Purpose
+
+This spec defines authentication
+requirements for the system.
+
+## Requirements
+
+### Requirement: User Login
+
+The system SHALL allow users to login.
+
+#### Scenario: Successful login
+
+- **WHEN** user provides valid credentials
+- **AND** the account is active
+- **THEN** user is authenticated
+- **AND** session token is returned
+
+### Requirement: User Logout
+
+The system SHALL allow users to logout.
+
+#### Scenario: Logout clears session
+
+- WHEN authenticated user requests logout
+- THEN session is invalidated
+
+## Notes
+
+Free text.
+"#;
+
+    const DELTA: &str = r#"## ADDED Requirements
+
+### Requirement: Two-Factor Auth
+
+The system SHALL require a one-time code.
+
+#### Scenario: Code accepted
+
+- **WHEN** user enters a valid code
+- **THEN** user is authenticated
+
+## RENAMED Requirements
+
+### Requirement: User Logout -> Sign Out
+
+## REMOVED Requirements
+
+### Requirement: Legacy Tokens
+
+**Reason**: Replaced
+"#;
+
+    // ==================== Structure tests ====================
+
+    #[test]
+    fn test_delta_op_from_str() {
+        assert_eq!("added".parse::<DeltaOp>(), Ok(DeltaOp::Added));
+        assert_eq!("RENAMED".parse::<DeltaOp>(), Ok(DeltaOp::Renamed));
+        assert!("changed"
+            .parse::<DeltaOp>()
+            .unwrap_err()
+            .contains("changed"));
+    }
+
+    #[test]
+    fn test_parse_title_and_sections() {
+        let doc = Document::parse(SPEC);
+
+        assert_eq!(doc.title.as_ref().unwrap().text, "Auth Specification");
+        let kinds: Vec<SectionKind> = doc.sections.iter().map(|s| s.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                SectionKind::Purpose,
+                SectionKind::Requirements,
+                SectionKind::Other
+            ]
+        );
+    }
+
+    #[test]
+    fn test_purpose_text_and_paragraph() {
+        let doc = Document::parse(SPEC);
+        let purpose = doc.section(SectionKind::Purpose).unwrap();
+
+        assert_eq!(
+            purpose.text,
+            "This spec defines authentication\nrequirements for the system."
+        );
+        assert_eq!(
+            purpose.paragraph(),
+            "This spec defines authentication requirements for the system."
+        );
+    }
+
+    #[test]
+    fn test_requirements_and_descriptions() {
+        let doc = Document::parse(SPEC);
+        let reqs = doc.spec_requirements();
+
+        assert_eq!(reqs.len(), 2);
+        assert_eq!(reqs[0].name, "User Login");
+        assert_eq!(
+            reqs[0].description,
+            "The system SHALL allow users to login."
+        );
+        assert_eq!(reqs[1].name, "User Logout");
+    }
+
+    #[test]
+    fn test_scenario_clauses() {
+        let doc = Document::parse(SPEC);
+        let scenario = &doc.spec_requirements()[0].scenarios[0];
+
+        assert_eq!(scenario.name, "Successful login");
+        assert_eq!(scenario.clauses.len(), 4);
+        assert!(scenario.has_when());
+        assert!(scenario.has_then());
+        assert_eq!(
+            scenario.when_text(),
+            "user provides valid credentials AND the account is active"
+        );
+        assert_eq!(
+            scenario.then_texts(),
+            vec!["user is authenticated", "session token is returned"]
+        );
+    }
+
+    #[test]
+    fn test_plain_keyword_clauses() {
+        let doc = Document::parse(SPEC);
+        let scenario = &doc.spec_requirements()[1].scenarios[0];
+
+        assert!(scenario.has_when());
+        assert!(scenario.has_then());
+        assert_eq!(scenario.when_text(), "authenticated user requests logout");
+    }
+
+    #[test]
+    fn test_delta_sections_include_renamed() {
+        let doc = Document::parse(DELTA);
+        let ops: Vec<(DeltaOp, usize)> = doc
+            .delta_sections()
+            .map(|(op, s)| (op, s.requirements.len()))
+            .collect();
+
+        assert_eq!(
+            ops,
+            vec![
+                (DeltaOp::Added, 1),
+                (DeltaOp::Renamed, 1),
+                (DeltaOp::Removed, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_requirement_content_is_raw_block() {
+        let doc = Document::parse(DELTA);
+        let added = &doc.sections[0].requirements[0];
+
+        assert!(added
+            .content
+            .starts_with("### Requirement: Two-Factor Auth\n"));
+        assert!(added.content.ends_with("- **THEN** user is authenticated"));
+    }
+
+    #[test]
+    fn test_headings_in_code_fences_are_ignored() {
+        let content = "## Requirements\n\n### Requirement: Docs\n\nThe system SHALL document:\n\n```markdown\n## Not a section\n### Requirement: Not a requirement\n```\n";
+        let doc = Document::parse(content);
+
+        assert_eq!(doc.sections.len(), 1);
+        assert_eq!(doc.spec_requirements().len(), 1);
+        assert!(doc.spec_requirements()[0].content.ends_with("```"));
+    }
+
+    #[test]
+    fn test_case_insensitive_section_headers() {
+        let doc = Document::parse("## added requirements\n\n### Requirement: X\n");
+
+        assert_eq!(doc.sections[0].kind, SectionKind::Delta(DeltaOp::Added));
+    }
+
+    #[test]
+    fn test_non_requirement_level3_heading_ends_requirement() {
+        let content = "## Requirements\n\n### Requirement: A\n\nThe system SHALL a.\n\n### Notes\n\nNot part of A.\n";
+        let doc = Document::parse(content);
+        let req = &doc.spec_requirements()[0];
+
+        assert_eq!(req.description, "The system SHALL a.");
+        assert!(doc.sections[0].text.contains("Not part of A."));
+    }
+
+    // ==================== Span tests ====================
+
+    #[test]
+    fn test_heading_spans() {
+        let doc = Document::parse(SPEC);
+        let req = &doc.spec_requirements()[0];
+
+        assert_eq!(
+            req.heading.span.start,
+            Position {
+                line: 10,
+                column: 1
+            }
+        );
+        assert_eq!(
+            req.heading.span.end,
+            Position {
+                line: 10,
+                column: "### Requirement: User Login".len() + 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_block_spans_end_at_last_non_blank_line() {
+        let doc = Document::parse(SPEC);
+        let req = &doc.spec_requirements()[0];
+        let scenario = &req.scenarios[0];
+
+        assert_eq!(scenario.span.start.line, 14);
+        assert_eq!(scenario.span.end.line, 19);
+        assert_eq!(req.span.start.line, 10);
+        assert_eq!(req.span.end.line, 19);
+        let requirements = doc.section(SectionKind::Requirements).unwrap();
+        assert_eq!(requirements.span.end.line, 28);
+    }
+
+    #[test]
+    fn test_clause_spans_include_indentation() {
+        let doc = Document::parse(
+            "## ADDED Requirements\n### Requirement: A\n#### Scenario: S\n  - **WHEN** x\n",
+        );
+        let clause = &doc.sections[0].requirements[0].scenarios[0].clauses[0];
+
+        assert_eq!(clause.span.start, Position { line: 4, column: 3 });
+        assert_eq!(
+            clause.span.end,
+            Position {
+                line: 4,
+                column: 15
+            }
+        );
+    }
+
+    #[test]
+    fn test_headings_list_all_levels() {
+        let doc = Document::parse(SPEC);
+
+        assert_eq!(doc.headings.len(), 8);
+        assert_eq!(doc.headings[0].level, 1);
+        assert!(doc.headings.iter().any(|h| h.text == "Notes"));
+    }
+
+    #[test]
+    fn test_hashtag_is_not_heading() {
+        let doc = Document::parse("#tag\n## Purpose\nText\n");
+
+        assert!(doc.title.is_none());
+        assert_eq!(doc.sections.len(), 1);
+    }
+
+    // ==================== Section tests ====================
+
+    #[test]
+    fn test_section_exists() {
+        let content = r#"# Title
+
+## Purpose
+
+This is the purpose section.
+It has multiple lines.
+
+## Requirements
+
+Some requirements here.
+"#;
+        let doc = Document::parse(content);
+        let purpose = doc.section(SectionKind::Purpose).unwrap();
+
+        assert_eq!(purpose.heading.span.start.line, 3);
+        assert!(purpose.text.contains("This is the purpose section."));
+        assert!(purpose.text.contains("It has multiple lines."));
+        assert!(!purpose.text.contains("Some requirements here."));
+        assert_eq!(purpose.span.end.line, 6);
+    }
+
+    #[test]
+    fn test_section_not_exists() {
+        let doc = Document::parse("# Title\n\n## Purpose\n\nThis is the purpose section.\n");
+
+        assert!(doc.section_named("NonExistent").is_none());
+        assert!(doc.section(SectionKind::Requirements).is_none());
+        assert!(doc.spec_requirements().is_empty());
+    }
+
+    #[test]
+    fn test_section_named_case_insensitive() {
+        let doc = Document::parse("## purpose\nSome content here.\n");
+
+        assert!(doc.section_named("Purpose").is_some());
+        assert_eq!(doc.sections[0].kind, SectionKind::Purpose);
+    }
+
+    #[test]
+    fn test_section_with_requirements() {
+        let content = r#"## Requirements
+
+### Requirement: Login
+User can login.
+
+### Requirement: Logout
+User can logout.
+
+## Other Section
+"#;
+        let doc = Document::parse(content);
+        let requirements = doc.section(SectionKind::Requirements).unwrap();
+
+        assert_eq!(requirements.heading.span.start.line, 1);
+        let names: Vec<&str> = requirements
+            .requirements
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Login", "Logout"]);
+        assert_eq!(requirements.span.end.line, 7);
+        assert!(doc.section_named("Other Section").is_some());
+    }
+
+    #[test]
+    fn test_section_at_end_of_file() {
+        let content = r#"## First Section
+Content 1.
+
+## Last Section
+Content 2.
+No more sections after this.
+"#;
+        let doc = Document::parse(content);
+        let last = doc.section_named("Last Section").unwrap();
+
+        assert_eq!(last.heading.span.start.line, 4);
+        assert!(last.text.contains("Content 2."));
+        assert!(last.text.contains("No more sections after this."));
+        assert_eq!(last.span.end.line, 6);
+    }
+
+    // ==================== Requirement tests ====================
+
+    #[test]
+    fn test_multiple_requirements() {
+        let content = r#"## Requirements
+
+### Requirement: Login
+User can login with credentials.
+
+### Requirement: Logout
+User can logout of the system.
+
+### Requirement: Register
+User can register a new account.
+"#;
+        let doc = Document::parse(content);
+        let reqs = doc.spec_requirements();
+
+        assert_eq!(reqs.len(), 3);
+        assert_eq!(reqs[0].heading.span.start.line, 3);
+        assert_eq!(reqs[0].name, "Login");
+        assert_eq!(reqs[0].description, "User can login with credentials.");
+        assert_eq!(reqs[1].heading.span.start.line, 6);
+        assert_eq!(reqs[1].name, "Logout");
+        assert_eq!(reqs[1].description, "User can logout of the system.");
+        assert_eq!(reqs[2].heading.span.start.line, 9);
+        assert_eq!(reqs[2].name, "Register");
+        assert_eq!(reqs[2].description, "User can register a new account.");
+    }
+
+    #[test]
+    fn test_section_without_requirements() {
+        let doc = Document::parse("## Requirements\n\nJust some text without subsections.\n");
+        let requirements = doc.section(SectionKind::Requirements).unwrap();
+
+        assert!(requirements.requirements.is_empty());
+        assert_eq!(
+            requirements.text.trim(),
+            "Just some text without subsections."
+        );
+    }
+
+    #[test]
+    fn test_requirement_with_scenarios() {
+        let content = r#"## Requirements
+### Requirement: Login
+User can login.
+
+#### Scenario: Valid credentials
+- **WHEN** user provides valid credentials
+- **THEN** user is logged in
+
+#### Scenario: Invalid credentials
+- **WHEN** user provides invalid credentials
+- **THEN** error is shown
+"#;
+        let doc = Document::parse(content);
+        let reqs = doc.spec_requirements();
+
+        assert_eq!(reqs.len(), 1);
+        assert_eq!(reqs[0].heading.span.start.line, 2);
+        assert_eq!(reqs[0].name, "Login");
+        assert_eq!(reqs[0].description, "User can login.");
+        let names: Vec<&str> = reqs[0].scenarios.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Valid credentials", "Invalid credentials"]);
+        assert!(reqs[0]
+            .content
+            .contains("#### Scenario: Invalid credentials"));
+    }
+
+    #[test]
+    fn test_requirement_stops_at_next_section() {
+        let content = r#"## Requirements
+### Requirement: First
+Content 1.
+
+## New Section
+This should not be included.
+
+### Requirement: Second
+Content 2.
+"#;
+        let doc = Document::parse(content);
+        let first = &doc.spec_requirements()[0];
+
+        assert_eq!(doc.spec_requirements().len(), 1);
+        assert!(!first.content.contains("## New Section"));
+        assert!(!first.content.contains("This should not be included"));
+        let new_section = doc.section_named("New Section").unwrap();
+        assert_eq!(new_section.requirements[0].name, "Second");
+    }
+
+    // ==================== Scenario tests ====================
+
+    #[test]
+    fn test_scenarios_basic() {
+        let content = r#"## Requirements
+### Requirement: Login
+
+#### Scenario: Valid login
+- **WHEN** user enters valid credentials
+- **THEN** user is logged in
+
+#### Scenario: Invalid login
+- **WHEN** user enters invalid credentials
+- **THEN** error message is shown
+"#;
+        let doc = Document::parse(content);
+        let scenarios = &doc.spec_requirements()[0].scenarios;
+
+        assert_eq!(scenarios.len(), 2);
+        assert_eq!(scenarios[0].heading.span.start.line, 4);
+        assert_eq!(scenarios[0].name, "Valid login");
+        assert_eq!(scenarios[0].span.end.line, 6);
+        assert_eq!(scenarios[0].when_text(), "user enters valid credentials");
+        assert_eq!(scenarios[0].then_texts(), vec!["user is logged in"]);
+        assert_eq!(scenarios[1].heading.span.start.line, 8);
+        assert_eq!(scenarios[1].name, "Invalid login");
+    }
+
+    #[test]
+    fn test_scenario_with_when_then_and() {
+        let scenario = parse_scenario(
+            "- **WHEN** user fills registration form\n- **THEN** account is created\n- **AND** confirmation email is sent",
+        );
+
+        assert!(scenario.has_when());
+        assert!(scenario.has_then());
+        assert_eq!(
+            scenario.then_texts(),
+            vec!["account is created", "confirmation email is sent"]
+        );
+    }
+
+    #[test]
+    fn test_requirement_without_scenarios() {
+        let doc = Document::parse(
+            "## Requirements\n### Requirement: Something\nJust plain text without scenarios.\n",
+        );
+
+        assert!(doc.spec_requirements()[0].scenarios.is_empty());
+    }
+
+    #[test]
+    fn test_scenario_stops_at_next_requirement() {
+        let doc = Document::parse(
+            "## Requirements\n### Requirement: A\n#### Scenario: First\nContent 1.\n\n### Requirement: Next\nThis should not be included.\n",
+        );
+        let reqs = doc.spec_requirements();
+
+        assert_eq!(reqs[0].scenarios.len(), 1);
+        assert_eq!(reqs[0].scenarios[0].span.end.line, 4);
+        assert_eq!(reqs[1].description, "This should not be included.");
+    }
+
+    // ==================== Clause tests ====================
+
+    /// The only scenario of a requirement whose scenario body is `body`.
+    fn parse_scenario(body: &str) -> Scenario {
+        let content = format!(
+            "## Requirements\n### Requirement: R\n#### Scenario: S\n{}\n",
+            body
+        );
+        Document::parse(&content).spec_requirements()[0].scenarios[0].clone()
+    }
+
+    #[test]
+    fn test_when_clause_bold() {
+        let scenario = parse_scenario("- **WHEN** user clicks button");
+
+        assert!(scenario.has_when());
+        assert_eq!(scenario.when_text(), "user clicks button");
+    }
+
+    #[test]
+    fn test_when_clause_plain() {
+        let scenario = parse_scenario("WHEN user clicks button");
+
+        assert!(scenario.has_when());
+        assert_eq!(scenario.when_text(), "user clicks button");
+    }
+
+    #[test]
+    fn test_when_clause_missing() {
+        assert!(!parse_scenario("User clicks button").has_when());
+    }
+
+    #[test]
+    fn test_when_clause_in_word() {
+        // "when" as part of another word should not match
+        assert!(!parse_scenario("Whenever user clicks").has_when());
+    }
+
+    #[test]
+    fn test_then_clause_bold() {
+        let scenario = parse_scenario("- **THEN** result is shown");
+
+        assert!(scenario.has_then());
+        assert_eq!(scenario.then_texts(), vec!["result is shown"]);
+    }
+
+    #[test]
+    fn test_then_clause_plain() {
+        assert!(parse_scenario("THEN result is shown").has_then());
+    }
+
+    #[test]
+    fn test_then_clause_missing() {
+        assert!(!parse_scenario("Result is shown").has_then());
+    }
+
+    #[test]
+    fn test_then_clause_in_word() {
+        // "then" as part of another word should not match
+        assert!(!parse_scenario("Authentication required").has_then());
+        assert!(!parse_scenario("Thenceforth nothing").has_then());
+    }
+
+    #[test]
+    fn test_bold_keyword_anywhere_in_line() {
+        let scenario = parse_scenario(
+            "1. Given a session **WHEN** it expires\n* Then **THEN**: user is logged out",
+        );
+
+        assert_eq!(scenario.when_text(), "Given a session it expires");
+        assert_eq!(scenario.then_texts(), vec!["Then user is logged out"]);
+    }
+
+    #[test]
+    fn test_keywords_are_case_insensitive() {
+        let scenario = parse_scenario(
+            "- **When** user logs in\n- then: session starts\n- **and** cookie is set",
+        );
+
+        assert_eq!(scenario.when_text(), "user logs in");
+        assert_eq!(
+            scenario.then_texts(),
+            vec!["session starts", "cookie is set"]
+        );
+    }
+
+    #[test]
+    fn test_bold_text_without_keyword_is_not_a_clause() {
+        let scenario = parse_scenario("- **Note** when retried, **WHENCE** is ignored");
+
+        assert!(scenario.clauses.is_empty());
+    }
+
+    // ==================== Full document tests ====================
+
+    #[test]
+    fn test_full_spec_parsing() {
+        let content = r#"# Auth Spec
+
+## Purpose
+
+This spec defines authentication requirements.
+
+## Requirements
+
+### Requirement: User Login
+The system SHALL allow users to login with email and password.
+
+#### Scenario: Successful login
+- **WHEN** user provides valid email and password
+- **THEN** user is authenticated
+- **AND** session token is returned
+
+#### Scenario: Failed login
+- **WHEN** user provides invalid credentials
+- **THEN** authentication error is returned
+
+### Requirement: User Logout
+The system SHALL allow users to logout.
+
+#### Scenario: Logout clears session
+- **WHEN** authenticated user requests logout
+- **THEN** session is invalidated
+"#;
+        let doc = Document::parse(content);
+
+        let purpose = doc.section(SectionKind::Purpose).unwrap();
+        assert_eq!(purpose.heading.span.start.line, 3);
+        assert!(purpose.paragraph().contains("authentication requirements"));
+
+        let reqs = doc.spec_requirements();
+        assert_eq!(reqs.len(), 2);
+        assert_eq!(reqs[0].name, "User Login");
+        assert_eq!(reqs[1].name, "User Logout");
+
+        let login_scenarios = &reqs[0].scenarios;
+        assert_eq!(login_scenarios.len(), 2);
+        assert_eq!(login_scenarios[0].name, "Successful login");
+        assert_eq!(login_scenarios[1].name, "Failed login");
+        assert!(login_scenarios[0].has_when());
+        assert!(login_scenarios[0].has_then());
+    }
+}
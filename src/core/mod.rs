@@ -5,14 +5,27 @@
 //!
 //! - Error types
 //! - Validation logic
+//! - Markdown document model with source spans
 //! - Spec parsing
-//! - Search indexing
+//! - Spec writing and formatting
+//! - Text embeddings, keyword scoring, nearest neighbour graphs and search indexing
+//! - Change scaffolding, approval and archiving
+//! - Conflict detection between active changes
 //!
 //! All functions in this module return structured Rust types (structs, enums, Results)
 //! without any formatting or I/O operations.
 
+pub mod approval;
+pub mod archive;
+pub mod bm25;
+pub mod change;
+pub mod conflict;
+pub mod embedding;
 pub mod error;
+pub mod format;
+pub mod hnsw;
 pub mod index;
+pub mod markdown;
 pub mod spec;
 pub mod validate;
 pub mod version_lock;
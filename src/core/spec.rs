@@ -9,6 +9,7 @@ use std::path::Path;
 use serde::{Deserialize, Serialize};
 
 use super::error::{Error, Result};
+use super::markdown::{Document, SectionKind};
 
 /// A parsed specification with all its requirements.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -154,21 +155,51 @@ pub fn get_spec_by_id(folder: &Path, id: &str) -> Result<ParsedSpec> {
 }
 
 // =============================================================================
-// Internal parsing functions
+// Content parsing
 // =============================================================================
 
 /// Parse spec content string and return structured data.
-fn parse_spec_content(content: &str, id: &str) -> Result<ParsedSpec> {
-    let lines: Vec<&str> = content.lines().collect();
-
-    // Extract spec title from header
-    let title = extract_spec_title(&lines)?;
-
-    // Extract purpose section
-    let purpose = extract_purpose(&lines)?;
-
-    // Extract requirements with scenarios
-    let requirements = extract_requirements_with_scenarios(&lines);
+///
+/// # Arguments
+///
+/// * `content` - The spec markdown
+/// * `id` - The spec ID to assign
+///
+/// # Errors
+///
+/// Returns an error if the title or Purpose section is missing.
+pub fn parse_spec_content(content: &str, id: &str) -> Result<ParsedSpec> {
+    let doc = Document::parse(content);
+
+    let title = doc
+        .title
+        .as_ref()
+        .map(|heading| heading.text.clone())
+        .ok_or_else(|| Error::Other("Missing spec header (expected '# <title>')".to_string()))?;
+
+    let purpose = doc
+        .section(SectionKind::Purpose)
+        .map(|section| section.paragraph())
+        .filter(|purpose| !purpose.is_empty())
+        .ok_or_else(|| Error::Other("Missing Purpose section".to_string()))?;
+
+    let requirements = doc
+        .spec_requirements()
+        .iter()
+        .map(|req| ParsedRequirement {
+            name: req.name.clone(),
+            description: req.description.clone(),
+            scenarios: req
+                .scenarios
+                .iter()
+                .map(|scenario| ParsedScenario {
+                    name: scenario.name.clone(),
+                    when_clause: scenario.when_text(),
+                    then_clauses: scenario.then_texts(),
+                })
+                .collect(),
+        })
+        .collect();
 
     Ok(ParsedSpec {
         id: id.to_string(),
@@ -178,211 +209,6 @@ fn parse_spec_content(content: &str, id: &str) -> Result<ParsedSpec> {
     })
 }
 
-/// Extract the spec title from the first header line.
-fn extract_spec_title(lines: &[&str]) -> Result<String> {
-    for line in lines {
-        let trimmed = line.trim();
-        if trimmed.starts_with("# ") {
-            // Return the full header text (including "Specification" suffix)
-            return Ok(trimmed.strip_prefix("# ").unwrap_or("").trim().to_string());
-        }
-    }
-    Err(Error::Other(
-        "Missing spec header (expected '# <title>')".to_string(),
-    ))
-}
-
-/// Extract the purpose section content.
-fn extract_purpose(lines: &[&str]) -> Result<String> {
-    let mut in_purpose = false;
-    let mut purpose_lines = Vec::new();
-
-    for line in lines {
-        let trimmed = line.trim();
-
-        // Check for Purpose header (case-insensitive)
-        if trimmed.eq_ignore_ascii_case("## Purpose") {
-            in_purpose = true;
-            continue;
-        }
-
-        // Stop at next level-2 header
-        if in_purpose && trimmed.starts_with("## ") {
-            break;
-        }
-
-        if in_purpose && !trimmed.is_empty() {
-            purpose_lines.push(trimmed);
-        }
-    }
-
-    if purpose_lines.is_empty() {
-        return Err(Error::Other("Missing Purpose section".to_string()));
-    }
-
-    Ok(purpose_lines.join(" "))
-}
-
-/// Extract all requirements with their scenarios from the spec content.
-fn extract_requirements_with_scenarios(lines: &[&str]) -> Vec<ParsedRequirement> {
-    let mut requirements = Vec::new();
-    let mut current_req: Option<ParsedRequirement> = None;
-    let mut current_scenario: Option<ParsedScenario> = None;
-    let mut in_requirements = false;
-    let mut in_scenario = false;
-    let mut collecting_then = false;
-
-    for line in lines {
-        let trimmed = line.trim();
-
-        // Check for Requirements section
-        if trimmed.eq_ignore_ascii_case("## Requirements") {
-            in_requirements = true;
-            continue;
-        }
-
-        // Stop at next level-2 header (outside Requirements)
-        if in_requirements
-            && trimmed.starts_with("## ")
-            && !trimmed.eq_ignore_ascii_case("## Requirements")
-        {
-            // Save current scenario if any
-            if let Some(scenario) = current_scenario.take() {
-                if let Some(ref mut req) = current_req {
-                    req.scenarios.push(scenario);
-                }
-            }
-            // Save current requirement if any
-            if let Some(req) = current_req.take() {
-                requirements.push(req);
-            }
-            break;
-        }
-
-        if !in_requirements {
-            continue;
-        }
-
-        // Check for requirement header (### Requirement: <name>)
-        if trimmed.starts_with("### Requirement:") {
-            // Save current scenario if any
-            if let Some(scenario) = current_scenario.take() {
-                if let Some(ref mut req) = current_req {
-                    req.scenarios.push(scenario);
-                }
-            }
-            // Save previous requirement
-            if let Some(req) = current_req.take() {
-                requirements.push(req);
-            }
-
-            let name = trimmed
-                .strip_prefix("### Requirement:")
-                .unwrap_or("")
-                .trim()
-                .to_string();
-
-            current_req = Some(ParsedRequirement {
-                name,
-                description: String::new(),
-                scenarios: Vec::new(),
-            });
-            in_scenario = false;
-            collecting_then = false;
-            continue;
-        }
-
-        // Check for scenario header (#### Scenario: <name>)
-        if trimmed.starts_with("#### Scenario:") {
-            // Save previous scenario
-            if let Some(scenario) = current_scenario.take() {
-                if let Some(ref mut req) = current_req {
-                    req.scenarios.push(scenario);
-                }
-            }
-
-            let scenario_name = trimmed
-                .strip_prefix("#### Scenario:")
-                .unwrap_or("")
-                .trim()
-                .to_string();
-
-            current_scenario = Some(ParsedScenario {
-                name: scenario_name,
-                when_clause: String::new(),
-                then_clauses: Vec::new(),
-            });
-            in_scenario = true;
-            collecting_then = false;
-            continue;
-        }
-
-        // Parse scenario clauses
-        if in_scenario {
-            if let Some(ref mut scenario) = current_scenario {
-                // Check for WHEN clause
-                if trimmed.contains("**WHEN**") || trimmed.contains("**When**") {
-                    let clause = extract_clause_text(trimmed, &["**WHEN**", "**When**"]);
-                    scenario.when_clause = clause;
-                    collecting_then = false;
-                }
-                // Check for THEN clause
-                else if trimmed.contains("**THEN**") || trimmed.contains("**Then**") {
-                    let clause = extract_clause_text(trimmed, &["**THEN**", "**Then**"]);
-                    scenario.then_clauses.push(clause);
-                    collecting_then = true;
-                }
-                // Check for AND clause (continuation of THEN)
-                else if trimmed.contains("**AND**") || trimmed.contains("**And**") {
-                    let clause = extract_clause_text(trimmed, &["**AND**", "**And**"]);
-                    if collecting_then {
-                        scenario.then_clauses.push(clause);
-                    }
-                }
-            }
-        } else if current_req.is_some() && !trimmed.is_empty() {
-            // Accumulate requirement description (before scenarios)
-            if let Some(ref mut req) = current_req {
-                if !req.description.is_empty() {
-                    req.description.push(' ');
-                }
-                req.description.push_str(trimmed);
-            }
-        }
-    }
-
-    // Save final scenario if any
-    if let Some(scenario) = current_scenario.take() {
-        if let Some(ref mut req) = current_req {
-            req.scenarios.push(scenario);
-        }
-    }
-
-    // Save final requirement
-    if let Some(req) = current_req.take() {
-        requirements.push(req);
-    }
-
-    requirements
-}
-
-/// Extract the text content from a clause line, removing the keyword marker.
-fn extract_clause_text(line: &str, keywords: &[&str]) -> String {
-    let mut text = line.to_string();
-
-    // Remove bullet point prefix if present
-    if text.starts_with("- ") {
-        text = text[2..].to_string();
-    }
-
-    // Remove keyword markers
-    for keyword in keywords {
-        text = text.replace(keyword, "");
-    }
-
-    text.trim().to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
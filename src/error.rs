@@ -3,8 +3,6 @@ use std::fmt;
 /// Error type for the Spox CLI.
 #[derive(Debug)]
 pub enum Error {
-    /// Indicates a feature or functionality that has not been implemented yet.
-    NotImplemented(String),
     /// Configuration file was not found at the expected path.
     ConfigNotFound(String),
     /// Failed to parse the configuration file.
@@ -20,7 +18,6 @@ pub enum Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Error::NotImplemented(msg) => write!(f, "not implemented: {}", msg),
             Error::ConfigNotFound(path) => write!(f, "config not found: {}", path),
             Error::ConfigParse(msg) => write!(f, "config parse error: {}", msg),
             Error::ConfigMissingField(field) => write!(f, "config missing field: {}", field),
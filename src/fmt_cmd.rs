@@ -0,0 +1,138 @@
+//! Command handler for `spox fmt`.
+//!
+//! Normalises specs and the delta specs of active changes to the spec template
+//! layout. With `--check`, reports unformatted files and exits non-zero instead
+//! of rewriting them.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::cli::output::{print_json, OutputFormat};
+use crate::config::Config;
+use crate::core::format::{format_project, UnformattedFile};
+use crate::error::{Error, Result};
+
+/// JSON output of the `fmt` command.
+#[derive(Debug, Serialize)]
+struct FmtResponse {
+    /// Whether files were only checked, not written.
+    check: bool,
+    /// The files that were (or would be) reformatted, relative to the project root.
+    files: Vec<String>,
+}
+
+/// Format the result of a format run for display.
+///
+/// # Arguments
+/// * `files` - The files that were (or would be) reformatted
+/// * `check` - Whether this was a `--check` run
+///
+/// # Returns
+/// A formatted string ready for terminal display (no ANSI colors).
+///
+/// # Example Output
+/// ```text
+/// Would reformat specs/auth/spec.md
+///
+/// 1 file would be reformatted
+/// ```
+fn format_fmt_result(files: &[UnformattedFile], check: bool) -> String {
+    if files.is_empty() {
+        return "All spec files are formatted".to_string();
+    }
+
+    let verb = if check {
+        "Would reformat"
+    } else {
+        "Reformatted"
+    };
+    let mut output = String::new();
+    for file in files {
+        output.push_str(&format!("{} {}\n", verb, file.path.display()));
+    }
+
+    let noun = if files.len() == 1 { "file" } else { "files" };
+    let summary = if check {
+        format!("{} {} would be reformatted", files.len(), noun)
+    } else {
+        format!("{} {} reformatted", files.len(), noun)
+    };
+    output.push('\n');
+    output.push_str(&summary);
+    output
+}
+
+/// Run the `fmt` command.
+///
+/// # Arguments
+/// * `check` - Only report unformatted files and exit with code 1 if any are found
+/// * `format` - Output format; JSON prints the reformatted files
+///
+/// # Returns
+/// Returns `Ok(())` on success, or an error if files cannot be read or written.
+pub fn run(check: bool, format: OutputFormat) -> Result<()> {
+    let config_path = Path::new(".spox/config.toml");
+    let config = Config::load(config_path)?;
+
+    let files =
+        format_project(Path::new(""), &config, !check).map_err(|e| Error::Other(e.to_string()))?;
+
+    if format.is_json() {
+        print_json(&FmtResponse {
+            check,
+            files: files
+                .iter()
+                .map(|file| file.path.display().to_string())
+                .collect(),
+        })?;
+    } else {
+        println!("{}", format_fmt_result(&files, check));
+    }
+
+    if check && !files.is_empty() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn file(path: &str) -> UnformattedFile {
+        UnformattedFile {
+            path: PathBuf::from(path),
+        }
+    }
+
+    // ==================== Unit Tests for format_fmt_result ====================
+
+    #[test]
+    fn test_format_fmt_result_nothing_to_do() {
+        assert_eq!(format_fmt_result(&[], true), "All spec files are formatted");
+    }
+
+    #[test]
+    fn test_format_fmt_result_check() {
+        let output = format_fmt_result(&[file("specs/auth/spec.md")], true);
+
+        assert_eq!(
+            output,
+            "Would reformat specs/auth/spec.md\n\n1 file would be reformatted"
+        );
+    }
+
+    #[test]
+    fn test_format_fmt_result_write() {
+        let output = format_fmt_result(
+            &[file("specs/auth/spec.md"), file("specs/mfa/spec.md")],
+            false,
+        );
+
+        assert!(output.starts_with("Reformatted specs/auth/spec.md\n"));
+        assert!(output.ends_with("2 files reformatted"));
+    }
+}
@@ -1,30 +1,155 @@
 //! Index command handler for building the semantic search index.
+//!
+//! With `--check`, reports whether the index is up to date and exits non-zero
+//! if it is not, so CI can catch an index that was not rebuilt.
 
 use std::path::Path;
 
-use crate::core::index::rebuild_index;
+use serde::Serialize;
+
+use crate::cli::output::{print_json, OutputFormat};
+use crate::config::Config;
+use crate::core::embedding::LazyEmbedder;
+use crate::core::index::{check_index, rebuild_index, IndexFolders, IndexStatus};
 use crate::error::{Error, Result};
+use crate::mcp::RebuildIndexResponse;
+
+/// JSON output of the `index --check` command.
+#[derive(Debug, Serialize)]
+struct IndexCheckResponse {
+    /// The index status: "current", "missing", "unreadable", "incompatible" or "stale".
+    status: &'static str,
+    /// Whether the index can be searched without a rebuild.
+    current: bool,
+    /// Why the index is not current, if it is not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+impl From<&IndexStatus> for IndexCheckResponse {
+    fn from(status: &IndexStatus) -> Self {
+        let (name, reason) = match status {
+            IndexStatus::Current => ("current", None),
+            IndexStatus::Missing => ("missing", None),
+            IndexStatus::Unreadable(reason) => ("unreadable", Some(reason.clone())),
+            IndexStatus::Incompatible {
+                indexed,
+                configured,
+            } => (
+                "incompatible",
+                Some(format!(
+                    "built with {} but {} is configured",
+                    indexed, configured
+                )),
+            ),
+            IndexStatus::Stale => ("stale", None),
+        };
+        Self {
+            status: name,
+            current: status.is_current(),
+            reason,
+        }
+    }
+}
+
+/// Format the result of an index check for display.
+///
+/// # Arguments
+/// * `status` - The status of the index
+///
+/// # Returns
+/// A formatted string ready for terminal display (no ANSI colors).
+///
+/// # Example Output
+/// ```text
+/// Search index is stale: specs or changes changed since it was built
+/// Run 'spox index' to rebuild it.
+/// ```
+fn format_index_status(status: &IndexStatus) -> String {
+    let problem = match status {
+        IndexStatus::Current => return "Search index is up to date".to_string(),
+        IndexStatus::Missing => "Search index is missing".to_string(),
+        IndexStatus::Unreadable(reason) => format!("Search index cannot be loaded: {}", reason),
+        IndexStatus::Incompatible {
+            indexed,
+            configured,
+        } => format!(
+            "Search index was built with {} but {} is configured",
+            indexed, configured
+        ),
+        IndexStatus::Stale => {
+            "Search index is stale: specs or changes changed since it was built".to_string()
+        }
+    };
+    format!("{}\nRun 'spox index' to rebuild it.", problem)
+}
 
 /// Run the index command to build the semantic search index.
 ///
+/// # Arguments
+/// * `check` - Only check the index and exit with code 1 if it is not up to date
+/// * `format` - Output format; JSON prints the `rebuild_index` response, or the
+///   index status with `check`
+///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Configuration cannot be loaded
 /// - Specs cannot be parsed
 /// - Index cannot be built or saved
-pub fn run() -> Result<()> {
-    eprintln!("Building search index...");
+pub fn run(check: bool, format: OutputFormat) -> Result<()> {
+    let config = Config::load(Path::new(".spox/config.toml"))?;
+    if check {
+        return run_check(&config, format);
+    }
+    let backend = config.embedding_backend();
+
+    eprintln!("Building search index ({} embeddings)...", backend.as_str());
 
     // Use the core rebuild_index function which handles all the logic
     let project_root = Path::new(".");
-    let specs_indexed = rebuild_index(project_root).map_err(|e| Error::Other(format!("{}", e)))?;
+    let embedder = LazyEmbedder::from_config(&config, project_root);
+    let changes =
+        rebuild_index(project_root, &embedder).map_err(|e| Error::Other(format!("{}", e)))?;
 
-    if specs_indexed == 0 {
+    if changes.specs == 0 {
         eprintln!("Warning: No spec files found");
     }
 
-    eprintln!("Index built successfully with {} specs", specs_indexed);
+    if format.is_json() {
+        return print_json(&RebuildIndexResponse {
+            specs_indexed: changes.specs,
+            changes_indexed: changes.changes,
+            added: changes.added,
+            updated: changes.updated,
+            removed: changes.removed,
+        });
+    }
+    eprintln!(
+        "Index built successfully with {} specs and {} changes ({} added, {} updated, {} removed)",
+        changes.specs, changes.changes, changes.added, changes.updated, changes.removed
+    );
+    Ok(())
+}
+
+/// Check the index without changing it; exit with code 1 unless it is current.
+fn run_check(config: &Config, format: OutputFormat) -> Result<()> {
+    let project_root = Path::new(".");
+    let embedder = LazyEmbedder::from_config(config, project_root);
+    let folders = IndexFolders::from_config(project_root, config);
+    let status = check_index(project_root, &folders, &embedder)
+        .map_err(|e| Error::Other(format!("{}", e)))?;
+
+    if format.is_json() {
+        print_json(&IndexCheckResponse::from(&status))?;
+    } else {
+        println!("{}", format_index_status(&status));
+    }
+
+    if !status.is_current() {
+        std::process::exit(1);
+    }
+
     Ok(())
 }
 
@@ -91,6 +216,33 @@ The system SHALL provide basic {} features.
         fs::write(spec_dir.join("spec.md"), spec_content).unwrap();
     }
 
+    #[test]
+    fn test_format_index_status_current() {
+        assert_eq!(
+            format_index_status(&IndexStatus::Current),
+            "Search index is up to date"
+        );
+    }
+
+    #[test]
+    fn test_format_index_status_asks_for_rebuild() {
+        assert_eq!(
+            format_index_status(&IndexStatus::Stale),
+            "Search index is stale: specs or changes changed since it was built\n\
+             Run 'spox index' to rebuild it."
+        );
+        let incompatible = format_index_status(&IndexStatus::Incompatible {
+            indexed: "hashing (hashing-384)".to_string(),
+            configured: "fastembed (BGESmallENV15)".to_string(),
+        });
+        assert!(incompatible.starts_with(
+            "Search index was built with hashing (hashing-384) but fastembed (BGESmallENV15) is configured"
+        ));
+        assert!(
+            format_index_status(&IndexStatus::Missing).ends_with("Run 'spox index' to rebuild it.")
+        );
+    }
+
     // Integration tests that require the embedding model are ignored by default
     // Run with: cargo test index_cmd -- --ignored
 
@@ -106,7 +258,7 @@ The system SHALL provide basic {} features.
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = run();
+        let result = run(false, OutputFormat::Text);
 
         // Restore original directory (ignore errors in parallel test execution)
         let _ = std::env::set_current_dir(original_dir);
@@ -128,7 +280,7 @@ The system SHALL provide basic {} features.
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = run();
+        let result = run(false, OutputFormat::Text);
 
         // Restore original directory (ignore errors in parallel test execution)
         let _ = std::env::set_current_dir(original_dir);
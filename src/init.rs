@@ -1,11 +1,13 @@
 //! Init command implementation for bootstrapping a new Spox project.
 
+use crate::cli::output::{print_json, OutputFormat};
 use crate::config::Config;
 use crate::core::version_lock::{
     compare_versions, current_version, get_migration_hints, UpgradeType, VersionComparison,
     VersionLock, VERSION_LOCK_FILENAME,
 };
 use crate::error::{Error, Result};
+use serde::Serialize;
 use std::fs;
 use std::path::Path;
 use std::process::Command;
@@ -42,11 +44,14 @@ const TEMPLATE_AGENT_REVIEWER_MD: &str =
     include_str!("../templates/claude/agents/spox-reviewer.md");
 const TEMPLATE_AGENT_VERIFIER_MD: &str =
     include_str!("../templates/claude/agents/spox-verifier.md");
-const TEMPLATE_CMD_ARCHIVE_MD: &str = include_str!("../templates/claude/commands/spox/archive.md");
-const TEMPLATE_CMD_IMPLEMENT_MD: &str =
+pub(crate) const TEMPLATE_CMD_ARCHIVE_MD: &str =
+    include_str!("../templates/claude/commands/spox/archive.md");
+pub(crate) const TEMPLATE_CMD_IMPLEMENT_MD: &str =
     include_str!("../templates/claude/commands/spox/implement.md");
-const TEMPLATE_CMD_PROPOSE_MD: &str = include_str!("../templates/claude/commands/spox/propose.md");
-const TEMPLATE_CMD_SETUP_MD: &str = include_str!("../templates/claude/commands/spox/setup.md");
+pub(crate) const TEMPLATE_CMD_PROPOSE_MD: &str =
+    include_str!("../templates/claude/commands/spox/propose.md");
+pub(crate) const TEMPLATE_CMD_SETUP_MD: &str =
+    include_str!("../templates/claude/commands/spox/setup.md");
 const TEMPLATE_CMD_VIBE_MD: &str = include_str!("../templates/claude/commands/spox/vibe.md");
 
 // specs/ templates
@@ -59,6 +64,15 @@ const TEMPLATE_CLAUDE_MD: &str = include_str!("../templates/spox/CLAUDE-template
 const TEMPLATE_SPOX_GITIGNORE: &str = include_str!("../templates/spox/gitignore");
 const TEMPLATE_CLAUDE_GITIGNORE: &str = include_str!("../templates/claude/gitignore");
 
+/// JSON output of the `init` command.
+#[derive(Debug, Serialize)]
+struct InitResponse {
+    /// The project directory.
+    path: String,
+    /// Whether an existing project was updated rather than created.
+    updated: bool,
+}
+
 /// Initialize or update a Spox project at the given path.
 ///
 /// On fresh projects, creates the full structure. On existing projects,
@@ -96,7 +110,7 @@ const TEMPLATE_CLAUDE_GITIGNORE: &str = include_str!("../templates/claude/gitign
 ///     |-- _changes/
 ///     +-- _archive/
 /// ```
-pub fn run(base_path: &Path) -> Result<()> {
+pub fn run(base_path: &Path, format: OutputFormat) -> Result<()> {
     // Determine if this is an update (for messaging)
     let is_update = base_path.join(".spox").exists();
 
@@ -112,6 +126,13 @@ pub fn run(base_path: &Path) -> Result<()> {
     // Create or update CLAUDE.md
     write_claude_md(base_path)?;
 
+    if format.is_json() {
+        return print_json(&InitResponse {
+            path: base_path.display().to_string(),
+            updated: is_update,
+        });
+    }
+
     // Print success message
     print_success_message(base_path, is_update);
 
@@ -329,14 +350,15 @@ fn create_or_update_version_lock(spox_dir: &Path) -> Result<()> {
                     eprintln!();
                 }
                 VersionComparison::BinaryNewer { upgrade_type } => {
-                    // Display upgrade message with optional migration hints
+                    // Display upgrade message with optional migration hints (on stderr,
+                    // so `--format json` output stays parseable)
                     if matches!(upgrade_type, UpgradeType::Minor | UpgradeType::Major) {
-                        println!();
-                        println!(
+                        eprintln!();
+                        eprintln!(
                             "{}",
                             format_upgrade_message(&last_version, version, upgrade_type)
                         );
-                        println!();
+                        eprintln!();
                     }
                 }
                 VersionComparison::Equal => {
@@ -1025,7 +1047,7 @@ mod tests {
     #[test]
     fn test_init_creates_spox_directory() {
         let temp = TempDir::new().unwrap();
-        let result = run(temp.path());
+        let result = run(temp.path(), OutputFormat::Text);
         assert!(result.is_ok());
         assert!(temp.path().join(".spox").exists());
         assert!(temp.path().join(".spox/config.toml").exists());
@@ -1034,7 +1056,7 @@ mod tests {
     #[test]
     fn test_init_creates_specs_templates_directory() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
         let templates = temp.path().join(".spox/templates");
         assert!(templates.exists());
         assert!(templates.join("spec.md").exists());
@@ -1050,7 +1072,7 @@ mod tests {
     #[test]
     fn test_init_creates_claude_directory() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
         let claude = temp.path().join(".claude");
         assert!(claude.exists());
         assert!(claude.join("agents/spox-implementer.md").exists());
@@ -1066,7 +1088,7 @@ mod tests {
     #[test]
     fn test_init_creates_specs_directory() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
         let specs = temp.path().join("specs");
         assert!(specs.exists());
         assert!(specs.join("mission.md").exists());
@@ -1077,24 +1099,24 @@ mod tests {
     #[test]
     fn test_init_succeeds_on_already_initialized_project() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Second init should succeed (update mode)
-        let result = run(temp.path());
+        let result = run(temp.path(), OutputFormat::Text);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_init_updates_tooling_files_on_reinit() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Modify a tooling file
         let implementer_file = temp.path().join(".claude/agents/spox-implementer.md");
         fs::write(&implementer_file, "old content").unwrap();
 
         // Re-run init
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Tooling file should be updated (not "old content")
         let content = fs::read_to_string(&implementer_file).unwrap();
@@ -1105,7 +1127,7 @@ mod tests {
     #[test]
     fn test_init_preserves_mission_md_on_reinit() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Modify mission.md with user content
         let mission_file = temp.path().join("specs/mission.md");
@@ -1113,7 +1135,7 @@ mod tests {
         fs::write(&mission_file, user_content).unwrap();
 
         // Re-run init
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // mission.md should be preserved
         let content = fs::read_to_string(&mission_file).unwrap();
@@ -1151,7 +1173,7 @@ mod tests {
     #[test]
     fn test_init_creates_claude_md() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
         let claude_md = temp.path().join(".claude/CLAUDE.md");
         assert!(claude_md.exists());
         let content = fs::read_to_string(&claude_md).unwrap();
@@ -1260,7 +1282,7 @@ mod tests {
     #[test]
     fn test_init_creates_setup_sh() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
         let setup_sh = temp.path().join(".spox/setup.sh");
         assert!(setup_sh.exists());
     }
@@ -1271,7 +1293,7 @@ mod tests {
         use std::os::unix::fs::PermissionsExt;
 
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
         let setup_sh = temp.path().join(".spox/setup.sh");
 
         let metadata = fs::metadata(&setup_sh).unwrap();
@@ -1285,14 +1307,14 @@ mod tests {
     #[test]
     fn test_init_updates_setup_sh_on_reinit() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Modify setup.sh with old content
         let setup_sh = temp.path().join(".spox/setup.sh");
         fs::write(&setup_sh, "old content").unwrap();
 
         // Re-run init
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // setup.sh should be updated (not "old content")
         let content = fs::read_to_string(&setup_sh).unwrap();
@@ -1413,14 +1435,14 @@ mod tests {
     #[test]
     fn test_init_creates_custom_directory() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
         assert!(temp.path().join(".spox/custom").exists());
     }
 
     #[test]
     fn test_claude_md_contains_merged_standards() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         let claude_md = temp.path().join(".claude/CLAUDE.md");
         let content = fs::read_to_string(&claude_md).unwrap();
@@ -1434,7 +1456,7 @@ mod tests {
     #[test]
     fn test_claude_md_has_no_leftover_markers() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         let claude_md = temp.path().join(".claude/CLAUDE.md");
         let content = fs::read_to_string(&claude_md).unwrap();
@@ -1651,7 +1673,7 @@ archive_folder = "custom/_archive"
     #[test]
     fn test_init_creates_spox_gitignore() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
         let gitignore = temp.path().join(".spox/.gitignore");
         assert!(gitignore.exists());
         let content = fs::read_to_string(&gitignore).unwrap();
@@ -1661,7 +1683,7 @@ archive_folder = "custom/_archive"
     #[test]
     fn test_init_creates_claude_gitignore() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
         let gitignore = temp.path().join(".claude/.gitignore");
         assert!(gitignore.exists());
         let content = fs::read_to_string(&gitignore).unwrap();
@@ -1675,7 +1697,7 @@ archive_folder = "custom/_archive"
     #[test]
     fn test_generated_claude_md_passes_markdown_linting() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         let claude_md = temp.path().join(".claude/CLAUDE.md");
         assert!(claude_md.exists(), "CLAUDE.md should be created");
@@ -2038,7 +2060,7 @@ unclosed
         let temp = TempDir::new().unwrap();
 
         // First init creates default config
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Modify config.toml with custom content
         let config_path = temp.path().join(".spox/config.toml");
@@ -2055,7 +2077,7 @@ custom = ["team-rules.md"]
         fs::write(&config_path, custom_config).unwrap();
 
         // Re-run init (should not overwrite config.toml)
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Verify custom config is preserved
         let content = fs::read_to_string(&config_path).unwrap();
@@ -2075,7 +2097,7 @@ custom = ["team-rules.md"]
         let temp = TempDir::new().unwrap();
 
         // First init
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Modify system array (user removed some defaults)
         let config_path = temp.path().join(".spox/config.toml");
@@ -2092,7 +2114,7 @@ custom = []
         fs::write(&config_path, custom_config).unwrap();
 
         // Re-run init
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Load and verify config through the normal flow
         let config = load_or_migrate_config(temp.path()).unwrap();
@@ -2109,7 +2131,7 @@ custom = []
         let temp = TempDir::new().unwrap();
 
         // First init
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Add custom rules
         let config_path = temp.path().join(".spox/config.toml");
@@ -2126,7 +2148,7 @@ custom = ["company-standards.md", "security-policy.md", "api-guidelines.md"]
         fs::write(&config_path, custom_config).unwrap();
 
         // Re-run init
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Verify custom array is preserved
         let config = load_or_migrate_config(temp.path()).unwrap();
@@ -2159,7 +2181,7 @@ archive_folder = "documentation/_archive"
         fs::write(spox_dir.join("config.toml"), old_config).unwrap();
 
         // Run init (should trigger migration)
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Verify paths are preserved and rules are added
         let config = load_or_migrate_config(temp.path()).unwrap();
@@ -2187,7 +2209,7 @@ archive_folder = "documentation/_archive"
         let temp = TempDir::new().unwrap();
 
         // Run init on fresh directory
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Load config and verify it has all expected sections with defaults
         let config = load_or_migrate_config(temp.path()).unwrap();
@@ -2221,7 +2243,7 @@ archive_folder = "documentation/_archive"
     #[test]
     fn test_init_creates_version_lock_on_fresh_project() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         let lock_path = temp.path().join(".spox/version.lock");
         assert!(lock_path.exists(), "version.lock should be created");
@@ -2241,7 +2263,7 @@ archive_folder = "documentation/_archive"
     #[test]
     fn test_init_updates_version_lock_on_reinit_with_different_version() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Manually modify the version.lock to simulate an older version
         let lock_path = temp.path().join(".spox/version.lock");
@@ -2249,7 +2271,7 @@ archive_folder = "documentation/_archive"
         lock.save(&lock_path).unwrap();
 
         // Re-run init (should add current version to updated_versions)
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         let updated_lock = crate::core::version_lock::VersionLock::load(&lock_path).unwrap();
         assert_eq!(
@@ -2267,14 +2289,14 @@ archive_folder = "documentation/_archive"
     #[test]
     fn test_init_preserves_version_lock_on_reinit_with_same_version() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         let lock_path = temp.path().join(".spox/version.lock");
         let lock_before = crate::core::version_lock::VersionLock::load(&lock_path).unwrap();
         let content_before = fs::read_to_string(&lock_path).unwrap();
 
         // Re-run init (should not modify version.lock)
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         let content_after = fs::read_to_string(&lock_path).unwrap();
         let lock_after = crate::core::version_lock::VersionLock::load(&lock_path).unwrap();
@@ -2296,7 +2318,7 @@ archive_folder = "documentation/_archive"
     #[test]
     fn test_version_lock_preserves_update_history() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         // Simulate multiple version upgrades
         let lock_path = temp.path().join(".spox/version.lock");
@@ -2305,7 +2327,7 @@ archive_folder = "documentation/_archive"
         lock.save(&lock_path).unwrap();
 
         // Re-run init with current version
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         let updated_lock = crate::core::version_lock::VersionLock::load(&lock_path).unwrap();
         assert_eq!(updated_lock.initialized_version, "0.1.0");
@@ -2321,7 +2343,7 @@ archive_folder = "documentation/_archive"
     #[test]
     fn test_version_lock_not_ignored_by_gitignore() {
         let temp = TempDir::new().unwrap();
-        run(temp.path()).unwrap();
+        run(temp.path(), OutputFormat::Text).unwrap();
 
         let gitignore = temp.path().join(".spox/.gitignore");
         let content = fs::read_to_string(&gitignore).unwrap();
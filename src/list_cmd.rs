@@ -5,9 +5,12 @@
 
 use std::path::Path;
 
+use crate::cli::output::{print_json, OutputFormat};
 use crate::config::Config;
 use crate::error::{Error, Result};
-use crate::show::dashboard::{gather_changes, gather_specs, ChangeSummary, SpecSummary};
+use crate::show::dashboard::{
+    gather_changes, gather_specs, list_changes, list_specs, ChangeSummary, SpecSummary,
+};
 
 /// Format the list of specs for display.
 ///
@@ -64,8 +67,8 @@ fn format_spec_list(specs: &[SpecSummary]) -> String {
 /// # Example Output
 /// ```text
 /// Changes:
-/// - add-2fa    2/5 tasks
-/// - fix-login  0/3 tasks
+/// - add-2fa    2/5 tasks  approved
+/// - fix-login  0/3 tasks  unapproved
 /// ```
 /// Or if empty: `No active changes.`
 fn format_change_list(changes: &[ChangeSummary]) -> String {
@@ -80,10 +83,11 @@ fn format_change_list(changes: &[ChangeSummary]) -> String {
 
     for change in changes {
         output.push_str(&format!(
-            "- {:<width$}  {}/{} tasks\n",
+            "- {:<width$}  {}/{} tasks  {}\n",
             change.name,
             change.tasks_completed,
             change.tasks_total,
+            change.approval.label(),
             width = max_name_len
         ));
     }
@@ -95,14 +99,22 @@ fn format_change_list(changes: &[ChangeSummary]) -> String {
 /// Run the `spec list` command.
 ///
 /// Displays all specs with their requirement counts in a simple list format
-/// without ANSI colors.
+/// without ANSI colors. With JSON output, prints a `ListSpecsResponse`.
+///
+/// # Arguments
+/// * `format` - Output format
 ///
 /// # Returns
 /// Returns `Ok(())` on success, or an error if config cannot be loaded.
-pub fn run_spec_list() -> Result<()> {
+pub fn run_spec_list(format: OutputFormat) -> Result<()> {
     let config_path = Path::new(".spox/config.toml");
     let config = Config::load(config_path)?;
 
+    if format.is_json() {
+        let specs = list_specs(Path::new(config.spec_folder())).map_err(Error::Other)?;
+        return print_json(&specs);
+    }
+
     let specs = gather_specs(config.spec_folder()).map_err(Error::Other)?;
     let output = format_spec_list(&specs);
     println!("{}", output);
@@ -113,14 +125,22 @@ pub fn run_spec_list() -> Result<()> {
 /// Run the `change list` command.
 ///
 /// Displays all active changes with their task progress in a simple list format
-/// without ANSI colors.
+/// without ANSI colors. With JSON output, prints a `ListChangesResponse`.
+///
+/// # Arguments
+/// * `format` - Output format
 ///
 /// # Returns
 /// Returns `Ok(())` on success, or an error if config cannot be loaded.
-pub fn run_change_list() -> Result<()> {
+pub fn run_change_list(format: OutputFormat) -> Result<()> {
     let config_path = Path::new(".spox/config.toml");
     let config = Config::load(config_path)?;
 
+    if format.is_json() {
+        let changes = list_changes(Path::new(config.changes_folder())).map_err(Error::Other)?;
+        return print_json(&changes);
+    }
+
     let changes = gather_changes(config.changes_folder()).map_err(Error::Other)?;
     let output = format_change_list(&changes);
     println!("{}", output);
@@ -131,6 +151,7 @@ pub fn run_change_list() -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::approval::ApprovalStatus;
 
     // ==================== Unit Tests for format_spec_list ====================
 
@@ -200,12 +221,14 @@ mod tests {
                 tasks_completed: 2,
                 tasks_total: 5,
                 delta_summary: String::new(),
+                approval: ApprovalStatus::Unapproved,
             },
             ChangeSummary {
                 name: "fix-login".to_string(),
                 tasks_completed: 0,
                 tasks_total: 3,
                 delta_summary: String::new(),
+                approval: ApprovalStatus::Unapproved,
             },
         ];
 
@@ -234,6 +257,7 @@ mod tests {
             tasks_completed: 5,
             tasks_total: 5,
             delta_summary: String::new(),
+            approval: ApprovalStatus::Unapproved,
         }];
 
         let output = format_change_list(&changes);
@@ -241,6 +265,31 @@ mod tests {
         assert!(output.contains("5/5 tasks"));
     }
 
+    #[test]
+    fn test_format_change_list_shows_approval_state() {
+        let changes = vec![
+            ChangeSummary {
+                name: "add-2fa".to_string(),
+                tasks_completed: 0,
+                tasks_total: 2,
+                delta_summary: String::new(),
+                approval: ApprovalStatus::Approved,
+            },
+            ChangeSummary {
+                name: "fix-login".to_string(),
+                tasks_completed: 0,
+                tasks_total: 1,
+                delta_summary: String::new(),
+                approval: ApprovalStatus::Stale,
+            },
+        ];
+
+        let output = format_change_list(&changes);
+
+        assert!(output.contains("- add-2fa    0/2 tasks  approved"));
+        assert!(output.contains("- fix-login  0/1 tasks  approval stale"));
+    }
+
     #[test]
     fn test_format_change_list_zero_tasks() {
         let changes = vec![ChangeSummary {
@@ -248,6 +297,7 @@ mod tests {
             tasks_completed: 0,
             tasks_total: 0,
             delta_summary: String::new(),
+            approval: ApprovalStatus::Unapproved,
         }];
 
         let output = format_change_list(&changes);
@@ -301,12 +351,14 @@ mod tests {
                 tasks_completed: 1,
                 tasks_total: 2,
                 delta_summary: String::new(),
+                approval: ApprovalStatus::Unapproved,
             },
             ChangeSummary {
                 name: "very-long-change-name".to_string(),
                 tasks_completed: 10,
                 tasks_total: 20,
                 delta_summary: String::new(),
+                approval: ApprovalStatus::Unapproved,
             },
         ];
 
@@ -355,6 +407,7 @@ mod tests {
             tasks_completed: 2,
             tasks_total: 5,
             delta_summary: "auth (+1)".to_string(),
+            approval: ApprovalStatus::Unapproved,
         }];
 
         let output = format_change_list(&changes);
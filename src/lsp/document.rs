@@ -0,0 +1,587 @@
+//! Language features for spec, delta spec, proposal and tasks files.
+//!
+//! Everything here works on the text of an open document, so results reflect
+//! unsaved edits. Target specs referenced by delta specs are read from disk.
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Diagnostic, DiagnosticSeverity,
+    DocumentSymbol, Location, Position, Range, SymbolKind, TextEdit, Url,
+};
+
+use crate::config::Config;
+use crate::core::archive::parse_rename;
+use crate::core::markdown::{self, DeltaOp, Document, SectionKind, Span};
+use crate::validate::{change, spec, tasks, Severity, ValidationReport};
+
+/// Prefix of requirement headings.
+const REQUIREMENT_PREFIX: &str = "### Requirement:";
+
+/// The role of a file in a Spec Oxide project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileKind {
+    /// `<spec_folder>/<id>/spec.md`
+    Spec { id: String },
+    /// `<changes_folder>/<change>/specs/<capability>/spec.md`
+    Delta {
+        change_id: String,
+        capability: String,
+    },
+    /// `<changes_folder>/<change>/proposal.md`
+    Proposal { change_id: String },
+    /// `<changes_folder>/<change>/tasks.md`
+    Tasks { change_id: String },
+}
+
+/// The project the language server works in.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    /// Project root path.
+    root: PathBuf,
+    /// Spec folder path (relative to project root).
+    spec_folder: PathBuf,
+    /// Changes folder path (relative to project root).
+    changes_folder: PathBuf,
+}
+
+impl Workspace {
+    /// Create a workspace for the project at `root`.
+    pub fn new(config: &Config, root: PathBuf) -> Self {
+        Self {
+            root,
+            spec_folder: PathBuf::from(config.spec_folder()),
+            changes_folder: PathBuf::from(config.changes_folder()),
+        }
+    }
+
+    /// Determine the role of a file from its path.
+    ///
+    /// Returns `None` for files the language server does not handle, such as
+    /// archived changes or files outside the project.
+    pub fn classify(&self, path: &Path) -> Option<FileKind> {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+
+        if let Ok(rest) = relative.strip_prefix(&self.changes_folder) {
+            return match normal_components(rest).as_slice() {
+                [change_id, "proposal.md"] => Some(FileKind::Proposal {
+                    change_id: change_id.to_string(),
+                }),
+                [change_id, "tasks.md"] => Some(FileKind::Tasks {
+                    change_id: change_id.to_string(),
+                }),
+                [change_id, "specs", capability, "spec.md"] => Some(FileKind::Delta {
+                    change_id: change_id.to_string(),
+                    capability: capability.to_string(),
+                }),
+                _ => None,
+            };
+        }
+
+        let rest = relative.strip_prefix(&self.spec_folder).ok()?;
+        match normal_components(rest).as_slice() {
+            [id, "spec.md"] if !id.starts_with('_') => Some(FileKind::Spec { id: id.to_string() }),
+            _ => None,
+        }
+    }
+
+    /// Validate a document and convert the issues to diagnostics.
+    ///
+    /// Info-level issues are not reported.
+    pub fn diagnostics(&self, kind: &FileKind, text: &str) -> Vec<Diagnostic> {
+        let report = match kind {
+            FileKind::Spec { id } => {
+                let file_path = self.spec_folder.join(id).join("spec.md");
+                spec::validate_spec_content(text, &file_path.to_string_lossy())
+            }
+            FileKind::Delta {
+                change_id,
+                capability,
+            } => change::validate_delta_content(
+                text,
+                capability,
+                &self.root.join(&self.changes_folder).join(change_id),
+                &self.root.join(&self.spec_folder),
+            ),
+            FileKind::Proposal { .. } => change::validate_proposal_content(text, "proposal.md"),
+            FileKind::Tasks { .. } => tasks::validate_tasks_content(text, "tasks.md").0,
+        };
+
+        to_diagnostics(&report, text)
+    }
+
+    /// Find the target spec requirement of the delta requirement at `position`.
+    ///
+    /// The position must be on a `### Requirement:` heading of a delta spec.
+    /// RENAMED requirements resolve to their old name.
+    pub fn definition(&self, kind: &FileKind, text: &str, position: Position) -> Option<Location> {
+        let FileKind::Delta { capability, .. } = kind else {
+            return None;
+        };
+
+        let line = position.line as usize + 1;
+        let doc = Document::parse(text);
+        let (op, requirement) = doc
+            .delta_sections()
+            .flat_map(|(op, section)| section.requirements.iter().map(move |r| (op, r)))
+            .find(|(_, r)| r.heading.span.start.line == line)?;
+
+        let name = match (op, parse_rename(&requirement.name)) {
+            (DeltaOp::Renamed, Some((from, _))) => from.to_string(),
+            _ => requirement.name.trim().to_string(),
+        };
+
+        let target_path = self.target_spec_path(capability);
+        let target = fs::read_to_string(&target_path).ok()?;
+        let target_doc = Document::parse(&target);
+        let found = target_doc
+            .spec_requirements()
+            .iter()
+            .find(|r| r.name == name)?;
+
+        Some(Location {
+            uri: Url::from_file_path(&target_path).ok()?,
+            range: to_range(&target, found.heading.span),
+        })
+    }
+
+    /// Complete requirement names after `### Requirement:` in MODIFIED, REMOVED
+    /// and RENAMED sections of a delta spec.
+    ///
+    /// Candidates are the requirements of the capability's current spec.
+    pub fn completions(
+        &self,
+        kind: &FileKind,
+        text: &str,
+        position: Position,
+    ) -> Vec<CompletionItem> {
+        let FileKind::Delta { capability, .. } = kind else {
+            return Vec::new();
+        };
+
+        let Some(line) = text.lines().nth(position.line as usize) else {
+            return Vec::new();
+        };
+        let typed = utf16_prefix(line, position.character);
+        let Some(rest) = typed.trim_start().strip_prefix(REQUIREMENT_PREFIX) else {
+            return Vec::new();
+        };
+
+        let doc = Document::parse(text);
+        let section = doc
+            .headings
+            .iter()
+            .rfind(|h| h.level == 2 && h.span.start.line <= position.line as usize + 1)
+            .map(|h| SectionKind::from_heading(&h.text));
+        if !matches!(
+            section,
+            Some(SectionKind::Delta(
+                DeltaOp::Modified | DeltaOp::Removed | DeltaOp::Renamed
+            ))
+        ) {
+            return Vec::new();
+        }
+
+        let Ok(target) = fs::read_to_string(self.target_spec_path(capability)) else {
+            return Vec::new();
+        };
+
+        // Replace whatever was typed after the prefix (and one separating space)
+        let name_start = typed.len() - rest.trim_start().len();
+        let start = Position::new(position.line, utf16_len(&typed[..name_start]));
+        let edit_range = Range::new(start, position);
+        let separator = if rest.starts_with(char::is_whitespace) {
+            ""
+        } else {
+            " "
+        };
+
+        Document::parse(&target)
+            .spec_requirements()
+            .iter()
+            .map(|r| CompletionItem {
+                label: r.name.clone(),
+                kind: Some(CompletionItemKind::REFERENCE),
+                detail: Some(format!("Requirement in spec \"{}\"", capability)),
+                filter_text: Some(r.name.clone()),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                    edit_range,
+                    format!("{}{}", separator, r.name),
+                ))),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Path to the current spec of a capability.
+    fn target_spec_path(&self, capability: &str) -> PathBuf {
+        self.root
+            .join(&self.spec_folder)
+            .join(capability)
+            .join("spec.md")
+    }
+}
+
+/// Outline of a spec or delta spec: sections, requirements and scenarios.
+#[allow(deprecated)] // DocumentSymbol::deprecated must still be initialised
+pub fn document_symbols(text: &str) -> Vec<DocumentSymbol> {
+    let doc = Document::parse(text);
+
+    doc.sections
+        .iter()
+        .map(|section| DocumentSymbol {
+            name: section.heading.text.clone(),
+            detail: None,
+            kind: SymbolKind::NAMESPACE,
+            tags: None,
+            deprecated: None,
+            range: to_range(text, section.span),
+            selection_range: to_range(text, section.heading.span),
+            children: Some(
+                section
+                    .requirements
+                    .iter()
+                    .map(|req| DocumentSymbol {
+                        name: req.name.clone(),
+                        detail: Some("Requirement".to_string()),
+                        kind: SymbolKind::CLASS,
+                        tags: None,
+                        deprecated: None,
+                        range: to_range(text, req.span),
+                        selection_range: to_range(text, req.heading.span),
+                        children: Some(
+                            req.scenarios
+                                .iter()
+                                .map(|scenario| DocumentSymbol {
+                                    name: scenario.name.clone(),
+                                    detail: Some("Scenario".to_string()),
+                                    kind: SymbolKind::METHOD,
+                                    tags: None,
+                                    deprecated: None,
+                                    range: to_range(text, scenario.span),
+                                    selection_range: to_range(text, scenario.heading.span),
+                                    children: None,
+                                })
+                                .collect(),
+                        ),
+                    })
+                    .collect(),
+            ),
+        })
+        .collect()
+}
+
+/// Convert the errors and warnings of a report to diagnostics.
+///
+/// Issues with a line number cover the text of that line; issues without one
+/// are reported on the first line.
+fn to_diagnostics(report: &ValidationReport, text: &str) -> Vec<Diagnostic> {
+    report
+        .issues
+        .iter()
+        .filter_map(|issue| {
+            let severity = match issue.severity {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+                Severity::Info => return None,
+            };
+            let line = issue.line.unwrap_or(1).max(1);
+            let content = text.lines().nth(line - 1).unwrap_or("");
+            let indent = content.len() - content.trim_start().len();
+            let range = Range::new(
+                Position::new(line as u32 - 1, utf16_len(&content[..indent])),
+                Position::new(line as u32 - 1, utf16_len(content.trim_end())),
+            );
+
+            Some(Diagnostic {
+                range,
+                severity: Some(severity),
+                source: Some("spox".to_string()),
+                message: issue.message.clone(),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Convert a markdown span (1-indexed, char columns) to an LSP range
+/// (0-indexed, UTF-16 columns).
+fn to_range(text: &str, span: Span) -> Range {
+    Range::new(to_position(text, span.start), to_position(text, span.end))
+}
+
+/// Convert a markdown position to an LSP position.
+fn to_position(text: &str, position: markdown::Position) -> Position {
+    let line = text
+        .lines()
+        .nth(position.line.saturating_sub(1))
+        .unwrap_or("");
+    let prefix: String = line
+        .chars()
+        .take(position.column.saturating_sub(1))
+        .collect();
+    Position::new(position.line.saturating_sub(1) as u32, utf16_len(&prefix))
+}
+
+/// Length of a string in UTF-16 code units.
+fn utf16_len(text: &str) -> u32 {
+    text.encode_utf16().count() as u32
+}
+
+/// The start of a line up to a UTF-16 column.
+fn utf16_prefix(line: &str, column: u32) -> &str {
+    let mut units = 0;
+    for (idx, c) in line.char_indices() {
+        if units >= column {
+            return &line[..idx];
+        }
+        units += c.len_utf16() as u32;
+    }
+    line
+}
+
+/// The normal components of a relative path as strings.
+fn normal_components(path: &Path) -> Vec<&str> {
+    path.components()
+        .filter_map(|c| match c {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const AUTH_SPEC: &str = "# auth Specification\n\n## Purpose\nThis spec defines authentication requirements for the system.\n\n## Requirements\n\n### Requirement: User Login\nThe system SHALL allow users to login.\n\n#### Scenario: Successful login\n- **WHEN** user provides valid credentials\n- **THEN** user is authenticated\n\n### Requirement: Logout\nThe system SHALL allow users to log out.\n\n#### Scenario: Logout\n- **WHEN** user logs out\n- **THEN** the session ends\n";
+
+    const MODIFY_LOGIN: &str = "## MODIFIED Requirements\n\n### Requirement: User Login\nThe system SHALL allow users to login with a second factor.\n\n#### Scenario: Successful login\n- **WHEN** user provides valid credentials\n- **THEN** a code is requested\n";
+
+    fn create_workspace() -> (TempDir, Workspace) {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        fs::create_dir_all(root.join("specs/auth")).unwrap();
+        fs::write(root.join("specs/auth/spec.md"), AUTH_SPEC).unwrap();
+
+        let change_dir = root.join("specs/_changes/add-2fa");
+        fs::create_dir_all(change_dir.join("specs/auth")).unwrap();
+        fs::write(
+            change_dir.join("proposal.md"),
+            "# Change: Add 2FA\n\n## Why\nAccounts need a second factor.\n\n## What Changes\n- Add OTP\n",
+        )
+        .unwrap();
+
+        let config: Config = toml::from_str(
+            "[paths]\nspec_folder = \"specs\"\nchanges_folder = \"specs/_changes\"\narchive_folder = \"specs/_archive\"\n\n[rules]\nsystem = []\n",
+        )
+        .unwrap();
+        let workspace = Workspace::new(&config, root.to_path_buf());
+        (temp_dir, workspace)
+    }
+
+    fn delta() -> FileKind {
+        FileKind::Delta {
+            change_id: "add-2fa".to_string(),
+            capability: "auth".to_string(),
+        }
+    }
+
+    // ==================== classify tests ====================
+
+    #[test]
+    fn test_classify_project_files() {
+        let (temp_dir, ws) = create_workspace();
+        let root = temp_dir.path();
+
+        assert_eq!(
+            ws.classify(&root.join("specs/auth/spec.md")),
+            Some(FileKind::Spec {
+                id: "auth".to_string()
+            })
+        );
+        assert_eq!(
+            ws.classify(&root.join("specs/_changes/add-2fa/specs/auth/spec.md")),
+            Some(delta())
+        );
+        assert_eq!(
+            ws.classify(&root.join("specs/_changes/add-2fa/tasks.md")),
+            Some(FileKind::Tasks {
+                change_id: "add-2fa".to_string()
+            })
+        );
+        assert_eq!(
+            ws.classify(&root.join("specs/_changes/add-2fa/proposal.md")),
+            Some(FileKind::Proposal {
+                change_id: "add-2fa".to_string()
+            })
+        );
+        assert_eq!(
+            ws.classify(&root.join("specs/_archive/2025-01-01-x/proposal.md")),
+            None
+        );
+        assert_eq!(ws.classify(&root.join("README.md")), None);
+    }
+
+    // ==================== diagnostics tests ====================
+
+    #[test]
+    fn test_spec_diagnostics_from_unsaved_text() {
+        let (_temp_dir, ws) = create_workspace();
+        let kind = FileKind::Spec {
+            id: "auth".to_string(),
+        };
+
+        assert!(ws.diagnostics(&kind, AUTH_SPEC).is_empty());
+
+        let broken = "# auth Specification\n\n## Purpose\nThis spec defines authentication requirements for the system.\n";
+        let diagnostics = ws.diagnostics(&kind, broken);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diagnostics[0].message, "Missing Requirements section");
+        assert_eq!(diagnostics[0].range.start, Position::new(0, 0));
+    }
+
+    #[test]
+    fn test_delta_diagnostics_check_target_spec() {
+        let (_temp_dir, ws) = create_workspace();
+        let text = "## MODIFIED Requirements\n\n### Requirement: Sign Up\nThe system SHALL register users.\n";
+
+        let diagnostics = ws.diagnostics(&delta(), text);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "MODIFIED requirement \"Sign Up\" does not exist in spec \"auth\""
+        );
+        assert_eq!(
+            diagnostics[0].range,
+            Range::new(Position::new(2, 0), Position::new(2, 24))
+        );
+    }
+
+    #[test]
+    fn test_tasks_diagnostics_skip_info() {
+        let (_temp_dir, ws) = create_workspace();
+        let kind = FileKind::Tasks {
+            change_id: "add-2fa".to_string(),
+        };
+
+        let diagnostics = ws.diagnostics(&kind, "## 1. Implementation\n- [ ] 1.1 Add OTP\n");
+
+        assert!(diagnostics.is_empty());
+    }
+
+    // ==================== document_symbols tests ====================
+
+    #[test]
+    fn test_document_symbols_nest_requirements_and_scenarios() {
+        let symbols = document_symbols(AUTH_SPEC);
+
+        let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Purpose", "Requirements"]);
+
+        let requirements = symbols[1].children.as_ref().unwrap();
+        assert_eq!(requirements[0].name, "User Login");
+        assert_eq!(requirements[0].kind, SymbolKind::CLASS);
+        assert_eq!(requirements[0].selection_range.start, Position::new(7, 0));
+
+        let scenarios = requirements[0].children.as_ref().unwrap();
+        assert_eq!(scenarios[0].name, "Successful login");
+        assert_eq!(scenarios[0].range.start.line, 10);
+        assert_eq!(scenarios[0].range.end.line, 12);
+    }
+
+    // ==================== definition tests ====================
+
+    #[test]
+    fn test_definition_jumps_to_target_requirement() {
+        let (temp_dir, ws) = create_workspace();
+
+        let location = ws
+            .definition(&delta(), MODIFY_LOGIN, Position::new(2, 20))
+            .unwrap();
+
+        let target = temp_dir.path().join("specs/auth/spec.md");
+        assert_eq!(location.uri, Url::from_file_path(target).unwrap());
+        assert_eq!(
+            location.range,
+            Range::new(Position::new(7, 0), Position::new(7, 27))
+        );
+    }
+
+    #[test]
+    fn test_definition_of_renamed_uses_old_name() {
+        let (_temp_dir, ws) = create_workspace();
+        let text = "## RENAMED Requirements\n\n### Requirement: Logout -> Sign Out\n";
+
+        let location = ws.definition(&delta(), text, Position::new(2, 5)).unwrap();
+
+        assert_eq!(location.range.start, Position::new(14, 0));
+    }
+
+    #[test]
+    fn test_definition_outside_heading_is_none() {
+        let (_temp_dir, ws) = create_workspace();
+
+        assert!(ws
+            .definition(&delta(), MODIFY_LOGIN, Position::new(3, 5))
+            .is_none());
+        let added = "## ADDED Requirements\n\n### Requirement: Two-Factor\nThe system SHALL ask.\n";
+        assert!(ws
+            .definition(&delta(), added, Position::new(2, 5))
+            .is_none());
+    }
+
+    // ==================== completions tests ====================
+
+    #[test]
+    fn test_completions_offer_target_requirements() {
+        let (_temp_dir, ws) = create_workspace();
+        let text = "## REMOVED Requirements\n\n### Requirement:";
+
+        let items = ws.completions(&delta(), text, Position::new(2, 16));
+
+        let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+        assert_eq!(labels, vec!["User Login", "Logout"]);
+        match &items[0].text_edit {
+            Some(CompletionTextEdit::Edit(edit)) => {
+                assert_eq!(edit.new_text, " User Login");
+                assert_eq!(
+                    edit.range,
+                    Range::new(Position::new(2, 16), Position::new(2, 16))
+                );
+            }
+            other => panic!("unexpected text edit: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_completions_replace_typed_prefix() {
+        let (_temp_dir, ws) = create_workspace();
+        let text = "## MODIFIED Requirements\n\n### Requirement: Us";
+
+        let items = ws.completions(&delta(), text, Position::new(2, 19));
+
+        match &items[0].text_edit {
+            Some(CompletionTextEdit::Edit(edit)) => {
+                assert_eq!(edit.new_text, "User Login");
+                assert_eq!(edit.range.start, Position::new(2, 17));
+            }
+            other => panic!("unexpected text edit: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_no_completions_in_added_section() {
+        let (_temp_dir, ws) = create_workspace();
+        let text = "## ADDED Requirements\n\n### Requirement: ";
+
+        assert!(ws
+            .completions(&delta(), text, Position::new(2, 17))
+            .is_empty());
+    }
+}
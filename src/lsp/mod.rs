@@ -0,0 +1,209 @@
+//! Language server for Spec Oxide markdown files.
+//!
+//! This module provides an LSP server over stdio for editors. It handles the
+//! spec files in the spec folder and the proposal, tasks and delta specs of
+//! active changes:
+//!
+//! - Diagnostics from spec, change and tasks validation on every edit
+//! - Document symbols for sections, requirements and scenarios
+//! - Go-to-definition from a delta requirement to the requirement it targets
+//! - Completion of requirement names in MODIFIED, REMOVED and RENAMED sections
+//!
+//! ## Usage
+//!
+//! The server is started via the CLI:
+//! ```bash
+//! spox lsp
+//! ```
+
+mod document;
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use tower_lsp::jsonrpc::Result as LspResult;
+use tower_lsp::lsp_types::{
+    CompletionOptions, CompletionParams, CompletionResponse, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+    DocumentSymbolParams, DocumentSymbolResponse, GotoDefinitionParams, GotoDefinitionResponse,
+    InitializeParams, InitializeResult, InitializedParams, MessageType, OneOf, ServerCapabilities,
+    ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
+    TextDocumentSyncSaveOptions, Url,
+};
+use tower_lsp::{Client, LanguageServer, LspService, Server};
+
+use crate::config::Config;
+
+use document::{document_symbols, FileKind, Workspace};
+
+/// The Spox language server.
+struct Backend {
+    /// Handle for notifications to the editor.
+    client: Client,
+    /// The project the server works in.
+    workspace: Workspace,
+    /// Text of the open documents, by URI.
+    documents: RwLock<HashMap<Url, String>>,
+}
+
+impl Backend {
+    /// The role and current text of an open document.
+    fn document(&self, uri: &Url) -> Option<(FileKind, String)> {
+        let kind = self.workspace.classify(&uri.to_file_path().ok()?)?;
+        let text = self.documents.read().ok()?.get(uri)?.clone();
+        Some((kind, text))
+    }
+
+    /// Store the text of a document and publish its diagnostics.
+    async fn update(&self, uri: Url, text: String, version: Option<i32>) {
+        if let Ok(mut documents) = self.documents.write() {
+            documents.insert(uri.clone(), text);
+        }
+        self.publish_diagnostics(uri, version).await;
+    }
+
+    /// Validate an open document and publish the diagnostics.
+    async fn publish_diagnostics(&self, uri: Url, version: Option<i32>) {
+        let Some((kind, text)) = self.document(&uri) else {
+            return;
+        };
+        let diagnostics = self.workspace.diagnostics(&kind, &text);
+        self.client
+            .publish_diagnostics(uri, diagnostics, version)
+            .await;
+    }
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, _params: InitializeParams) -> LspResult<InitializeResult> {
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Options(
+                    TextDocumentSyncOptions {
+                        open_close: Some(true),
+                        change: Some(TextDocumentSyncKind::FULL),
+                        save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+                        ..Default::default()
+                    },
+                )),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                definition_provider: Some(OneOf::Left(true)),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec![":".to_string()]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            server_info: Some(ServerInfo {
+                name: "spox".to_string(),
+                version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            }),
+        })
+    }
+
+    async fn initialized(&self, _params: InitializedParams) {
+        self.client
+            .log_message(MessageType::INFO, "spox language server initialized")
+            .await;
+    }
+
+    async fn shutdown(&self) -> LspResult<()> {
+        Ok(())
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let doc = params.text_document;
+        self.update(doc.uri, doc.text, Some(doc.version)).await;
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        // Full sync: the last change holds the complete text
+        if let Some(change) = params.content_changes.into_iter().last() {
+            let doc = params.text_document;
+            self.update(doc.uri, change.text, Some(doc.version)).await;
+        }
+    }
+
+    async fn did_save(&self, _params: DidSaveTextDocumentParams) {
+        // Delta specs are checked against the saved specs, so a saved spec or
+        // proposal can change the diagnostics of every open document
+        let uris: Vec<Url> = match self.documents.read() {
+            Ok(documents) => documents.keys().cloned().collect(),
+            Err(_) => return,
+        };
+        for uri in uris {
+            self.publish_diagnostics(uri, None).await;
+        }
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = params.text_document.uri;
+        if let Ok(mut documents) = self.documents.write() {
+            documents.remove(&uri);
+        }
+        self.client.publish_diagnostics(uri, Vec::new(), None).await;
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        let symbols = match self.document(&params.text_document.uri) {
+            Some((FileKind::Spec { .. } | FileKind::Delta { .. }, text)) => document_symbols(&text),
+            _ => return Ok(None),
+        };
+        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        let position = params.text_document_position_params;
+        let Some((kind, text)) = self.document(&position.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(self
+            .workspace
+            .definition(&kind, &text, position.position)
+            .map(GotoDefinitionResponse::Scalar))
+    }
+
+    async fn completion(&self, params: CompletionParams) -> LspResult<Option<CompletionResponse>> {
+        let position = params.text_document_position;
+        let Some((kind, text)) = self.document(&position.text_document.uri) else {
+            return Ok(None);
+        };
+        let items = self.workspace.completions(&kind, &text, position.position);
+        Ok((!items.is_empty()).then_some(CompletionResponse::Array(items)))
+    }
+}
+
+/// Run the language server over stdio.
+///
+/// The current directory is the project root.
+///
+/// # Arguments
+///
+/// * `config` - The loaded configuration
+///
+/// # Returns
+///
+/// Returns when the editor shuts the server down.
+pub async fn run_server(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
+    let project_root = std::env::current_dir()?;
+    let workspace = Workspace::new(config, project_root);
+
+    let (service, socket) = LspService::new(|client| Backend {
+        client,
+        workspace,
+        documents: RwLock::new(HashMap::new()),
+    });
+
+    Server::new(tokio::io::stdin(), tokio::io::stdout(), socket)
+        .serve(service)
+        .await;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! LSP command handler for starting the language server.
+
+use std::path::Path;
+
+use crate::cli::output::OutputFormat;
+use crate::config::Config;
+use crate::error::{Error, Result};
+
+/// Run the LSP command to start a language server over stdio.
+///
+/// This starts an LSP (Language Server Protocol) server that validates spec,
+/// proposal, tasks and delta spec files as they are edited.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `--format json` is given; stdout carries the language server protocol
+/// - Configuration cannot be loaded
+/// - The language server fails to start
+pub fn serve(format: OutputFormat) -> Result<()> {
+    if format.is_json() {
+        return Err(Error::Other(
+            "spox lsp cannot be combined with --format json".to_string(),
+        ));
+    }
+
+    // Load configuration
+    let config = Config::load(Path::new(".spox/config.toml"))?;
+
+    // Create and run the async runtime for the language server
+    let rt = tokio::runtime::Runtime::new()
+        .map_err(|e| Error::Other(format!("Failed to create async runtime: {}", e)))?;
+
+    rt.block_on(async { crate::lsp::run_server(&config).await })
+        .map_err(|e| Error::Other(format!("LSP server error: {}", e)))
+}
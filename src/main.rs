@@ -1,22 +1,32 @@
+mod change_cmd;
 mod config;
 mod config_cmd;
 mod core;
 mod error;
+mod fmt_cmd;
 mod index_cmd;
 mod init;
 mod list_cmd;
+mod lsp;
+mod lsp_cmd;
 mod mcp;
 mod mcp_cmd;
+mod search_cmd;
 mod show;
 mod show_cmd;
+mod task_cmd;
 mod validate;
 mod validate_cmd;
+mod watch;
 
 // cli module is now under src/cli/
 mod cli;
 
 use clap::Parser;
-use cli::{ChangeCommands, Cli, Commands, ConfigCommands, McpCommands, SpecCommands};
+use cli::{
+    ChangeCommands, Cli, Commands, ConfigCommands, McpCommands, OutputFormat, SpecCommands,
+    TaskCommands,
+};
 use std::path::PathBuf;
 
 fn main() {
@@ -29,7 +39,7 @@ fn main() {
             println!();
         }
         Some(cmd) => {
-            if let Err(e) = run(cmd) {
+            if let Err(e) = run(cmd, cli.format) {
                 eprintln!("error: {}", e);
                 std::process::exit(1);
             }
@@ -37,37 +47,64 @@ fn main() {
     }
 }
 
-fn run(cmd: Commands) -> error::Result<()> {
+fn run(cmd: Commands, format: OutputFormat) -> error::Result<()> {
     match cmd {
         Commands::Init { path } => {
             let target_path = path.unwrap_or_else(|| PathBuf::from("."));
-            init::run(&target_path)
+            init::run(&target_path, format)
         }
-        Commands::Show => show_cmd::run_dashboard(),
+        Commands::Show { watch } => show_cmd::run_dashboard(format, watch),
         Commands::Spec(action) => match action {
-            SpecCommands::List => list_cmd::run_spec_list(),
-            SpecCommands::Show { id } => show_cmd::run_spec_show(id),
-            SpecCommands::Validate { id, strict } => validate_cmd::run_spec_validate(id, strict),
+            SpecCommands::List => list_cmd::run_spec_list(format),
+            SpecCommands::Show { id } => show_cmd::run_spec_show(id, format),
+            SpecCommands::Validate {
+                id,
+                strict,
+                output,
+                watch,
+            } => validate_cmd::run_spec_validate(id, strict, format, output, watch),
         },
         Commands::Change(action) => match action {
-            ChangeCommands::Init { .. } => not_implemented("change init"),
-            ChangeCommands::List => list_cmd::run_change_list(),
-            ChangeCommands::Show { id, deltas_only } => show_cmd::run_change_show(id, deltas_only),
-            ChangeCommands::Validate { id, strict } => {
-                validate_cmd::run_change_validate(id, strict)
+            ChangeCommands::Init {
+                id,
+                capabilities,
+                design,
+            } => change_cmd::run_init(id, capabilities, design, format),
+            ChangeCommands::List => list_cmd::run_change_list(format),
+            ChangeCommands::Show { id, deltas_only } => {
+                show_cmd::run_change_show(id, deltas_only, format)
             }
-            ChangeCommands::Approve => not_implemented("change approve"),
+            ChangeCommands::Validate {
+                id,
+                strict,
+                output,
+                watch,
+            } => validate_cmd::run_change_validate(id, strict, format, output, watch),
+            ChangeCommands::Approve { id, by } => change_cmd::run_approve(id, by, format),
+            ChangeCommands::Conflicts => change_cmd::run_conflicts(format),
+            ChangeCommands::Archive { id, force } => change_cmd::run_archive(id, force, format),
+        },
+        Commands::Task(action) => match action {
+            TaskCommands::Done { change, number } => task_cmd::run_done(&change, &number, format),
         },
         Commands::Config(action) => match action {
-            ConfigCommands::Show { paths } => config_cmd::run_show(paths),
+            ConfigCommands::Show { paths } => config_cmd::run_show(paths, format),
         },
-        Commands::Index => index_cmd::run(),
+        Commands::Index { check } => index_cmd::run(check, format),
+        Commands::Search {
+            query,
+            top_k,
+            spec_ids,
+            mode,
+            json,
+        } => {
+            let format = if json { OutputFormat::Json } else { format };
+            search_cmd::run(&query, top_k, spec_ids, mode, format)
+        }
+        Commands::Fmt { check } => fmt_cmd::run(check, format),
+        Commands::Lsp => lsp_cmd::serve(format),
         Commands::Mcp(action) => match action {
-            McpCommands::Serve => mcp_cmd::serve(),
+            McpCommands::Serve => mcp_cmd::serve(format),
         },
     }
 }
-
-fn not_implemented(name: &str) -> error::Result<()> {
-    Err(error::Error::NotImplemented(name.to_string()))
-}
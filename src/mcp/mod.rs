@@ -7,12 +7,24 @@
 //! - `list_specs`: List all available specs with id, title, and purpose
 //! - `get_spec_requirements`: Get requirements structure without scenario bodies
 //! - `get_scenario`: Get a specific scenario's full content
-//! - `search_specs`: Semantic search over specs (requires index)
+//! - `search_specs`: Hybrid semantic and keyword search over specs, scenarios and changes (requires index)
 //! - `list_changes`: List all active change proposals with task progress
 //! - `get_change`: Get full content of a change proposal (proposal, tasks, design, deltas)
 //! - `validate_spec`: Validate spec structure and content (all specs or specific one)
 //! - `validate_change`: Validate change proposal structure and content (all changes or specific one)
-//! - `rebuild_index`: Rebuild the search index from all specs
+//! - `rebuild_index`: Rebuild the search index from all specs and changes
+//! - `create_change`: Create a change folder with a filled-in proposal
+//! - `add_delta_requirement`: Add or replace a requirement in a delta spec of a change
+//! - `update_proposal_section`: Replace a section of a change proposal
+//! - `set_task_status`: Check off or uncheck a task in the tasks of a change
+//!
+//! It also exposes these resources, as markdown:
+//! - `spox://mission`: The project mission
+//! - `spox://spec/{id}`: A spec
+//! - `spox://change/{id}/proposal`: The proposal of an active change
+//! - `spox://change/{id}/tasks`: The tasks of an active change
+//!
+//! And the `setup`, `propose`, `implement` and `archive` workflow prompts.
 //!
 //! ## Usage
 //!
@@ -24,21 +36,36 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use rmcp::model::{Implementation, ProtocolVersion, ServerCapabilities, ServerInfo};
+use rmcp::model::{
+    AnnotateAble, GetPromptRequestParam, GetPromptResult, Implementation, ListPromptsResult,
+    ListResourceTemplatesResult, ListResourcesResult, PaginatedRequestParam, ProtocolVersion,
+    RawResource, RawResourceTemplate, ReadResourceRequestParam, ReadResourceResult, Resource,
+    ResourceContents, ResourceTemplate, ServerCapabilities, ServerInfo,
+};
+use rmcp::service::RequestContext;
 use rmcp::tool;
-use rmcp::ServerHandler;
+use rmcp::Error as McpError;
 use rmcp::ServiceExt;
+use rmcp::{RoleServer, ServerHandler};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use std::fs;
 
 use crate::config::Config;
-use crate::core::index::{self, SpecIndex};
-use crate::core::spec;
-use crate::show::change::{parse_change, DeltaOp};
-use crate::show::dashboard::gather_changes;
-use crate::validate::{change as validate_change_mod, spec as validate_spec_mod, Severity};
+use crate::core::change::{self, ChangeInitOptions};
+use crate::core::embedding::LazyEmbedder;
+use crate::core::index::{
+    self, IndexFolders, IndexFreshness, ResultKind, SearchFilter, SearchMode, SearchPage,
+    SearchResult, SpecIndex,
+};
+use crate::core::markdown::DeltaOp;
+use crate::core::spec::{self, ParsedRequirement, ParsedScenario};
+use crate::show::change::{self as show_change, parse_change};
+use crate::show::dashboard::{self, gather_changes};
+use crate::validate::{response, tasks as validate_tasks_mod};
+
+mod prompts;
 
 // =============================================================================
 // Request/Response Types
@@ -124,29 +151,114 @@ pub struct SearchSpecsRequest {
     /// Number of results to return (default 10).
     #[schemars(description = "Number of results to return (default 10)")]
     pub top_k: Option<usize>,
+    /// How to rank results: semantic, keyword or hybrid (default hybrid).
+    #[schemars(
+        description = "How to rank results: 'semantic' (by meaning), 'keyword' (exact words and identifiers) or 'hybrid' (both, default)"
+    )]
+    pub mode: Option<String>,
+    /// Only return results of these kinds (default all).
+    #[schemars(
+        description = "Only return results of these kinds: 'spec', 'requirement', 'scenario', 'change' (active change proposals and deltas) or 'archive' (archived changes). Default all"
+    )]
+    pub kinds: Option<Vec<String>>,
+    /// Only return results of these specs (default all).
+    #[schemars(
+        description = "Only return results of these spec IDs, including delta requirements of active and archived changes for them. Default all"
+    )]
+    pub spec_ids: Option<Vec<String>>,
+    /// Never return results of these specs.
+    #[schemars(description = "Never return results of these spec IDs")]
+    pub exclude_spec_ids: Option<Vec<String>>,
+    /// Only return results scoring at least this much.
+    #[schemars(description = "Only return results with at least this score, from 0.0 to 1.0")]
+    pub min_score: Option<f32>,
+    /// Number of top results to skip, for paging (default 0).
+    #[schemars(
+        description = "Number of top results to skip, for paging through results with top_k (default 0)"
+    )]
+    pub offset: Option<usize>,
 }
 
 /// A single search result.
 #[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct SearchResultItem {
-    /// The spec ID.
+    /// What matched: spec, requirement, scenario, change or archive.
+    pub kind: String,
+    /// The spec ID (for changes, the capability of the matched delta; empty
+    /// if the proposal matched).
     pub spec_id: String,
-    /// The requirement name (if matched on a requirement).
+    /// The requirement name (if matched on a requirement, scenario or delta).
     pub requirement: Option<String>,
+    /// The scenario name (if matched on a scenario).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scenario: Option<String>,
+    /// The change ID (if matched on an active or archived change).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_id: Option<String>,
     /// The relevance score (0.0 to 1.0).
     pub score: f32,
     /// A snippet of the matched text.
     pub snippet: String,
 }
 
+impl From<SearchResult> for SearchResultItem {
+    fn from(result: SearchResult) -> Self {
+        Self {
+            kind: result.kind.as_str().to_string(),
+            spec_id: result.spec_id,
+            requirement: result.requirement,
+            scenario: result.scenario,
+            change_id: result.change_id,
+            score: result.score,
+            snippet: result.snippet,
+        }
+    }
+}
+
 /// Response for search_specs tool.
 #[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct SearchSpecsResponse {
     /// Search results ordered by relevance.
     pub results: Vec<SearchResultItem>,
+    /// Number of results matching the query and filters across all pages.
+    /// A lower bound for semantic and hybrid searches of an index with at least
+    /// 2000 texts, unless they are filtered by spec or kind.
+    pub total_matches: usize,
     /// Whether the search index was auto-built (true if index was missing and auto-built).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index_built: Option<bool>,
+    /// Whether an out-of-date search index was updated before searching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_updated: Option<bool>,
+    /// Warning about the results, e.g. when they come from an out-of-date index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+impl SearchSpecsResponse {
+    /// Build the response for a page of results searched in an index that
+    /// `ensure_index` returned with the given freshness.
+    pub fn from_page(page: SearchPage, freshness: &IndexFreshness) -> Self {
+        let warning = match freshness {
+            IndexFreshness::Stale(reason) => Some(format!(
+                "Specs or changes changed since the search index was built and it could not be \
+                 updated ({}). Results may be out of date; run 'spox index' to rebuild it.",
+                reason
+            )),
+            _ => None,
+        };
+        Self {
+            results: page
+                .results
+                .into_iter()
+                .map(SearchResultItem::from)
+                .collect(),
+            total_matches: page.total,
+            index_built: Some(*freshness == IndexFreshness::Built),
+            index_updated: Some(*freshness == IndexFreshness::Updated),
+            warning,
+        }
+    }
 }
 
 // =============================================================================
@@ -318,6 +430,236 @@ pub struct ValidationResponse {
 pub struct RebuildIndexResponse {
     /// The number of specs indexed.
     pub specs_indexed: usize,
+    /// The number of active and archived changes indexed.
+    pub changes_indexed: usize,
+    /// Texts (purposes, requirements, scenarios, proposals, deltas) that were newly embedded.
+    pub added: usize,
+    /// Texts that were re-embedded because they changed.
+    pub updated: usize,
+    /// Texts that were dropped from the index.
+    pub removed: usize,
+}
+
+// =============================================================================
+// Authoring Request/Response Types
+// =============================================================================
+
+/// Request parameters for create_change.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateChangeRequest {
+    /// The ID of the new change.
+    #[schemars(description = "The change ID: verb-led kebab-case, e.g. 'add-two-factor-auth'")]
+    pub change_id: String,
+    /// The proposal title.
+    #[schemars(description = "Brief description of the change, used as the proposal title")]
+    pub title: String,
+    /// The Why section.
+    #[schemars(description = "The Why section: 1-2 sentences on the problem or opportunity")]
+    pub why: String,
+    /// The What Changes section.
+    #[schemars(
+        description = "The What Changes section: a bullet list of changes, marking breaking changes with **BREAKING**"
+    )]
+    pub what_changes: String,
+    /// The Impact section (template text if not specified).
+    #[schemars(
+        description = "The Impact section: affected specs, new capabilities and affected code (keeps the template text if not specified)"
+    )]
+    pub impact: Option<String>,
+}
+
+/// A scenario of a delta requirement.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ScenarioInput {
+    /// The scenario name.
+    #[schemars(description = "The scenario name")]
+    pub name: String,
+    /// The WHEN clause.
+    #[schemars(description = "The WHEN clause, without the WHEN keyword")]
+    pub when: String,
+    /// The THEN clause followed by AND clauses.
+    #[schemars(description = "The THEN clause followed by any AND clauses, without the keywords")]
+    pub then: Vec<String>,
+}
+
+/// Request parameters for add_delta_requirement.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AddDeltaRequirementRequest {
+    /// The change ID.
+    #[schemars(description = "The ID of the active change")]
+    pub change_id: String,
+    /// The delta operation.
+    #[schemars(description = "The delta operation: added, modified, removed or renamed")]
+    pub operation: String,
+    /// The capability the delta applies to.
+    #[schemars(description = "The capability (spec ID) the requirement belongs to, in kebab-case")]
+    pub capability: String,
+    /// The requirement name.
+    #[schemars(
+        description = "The requirement name; for renamed, 'Old Name -> New Name'. A requirement with the same operation and name is replaced"
+    )]
+    pub requirement: String,
+    /// The requirement text.
+    #[schemars(
+        description = "The requirement text using SHALL or MUST; for modified, the complete updated text; for removed, the **Reason** and **Migration**"
+    )]
+    pub description: Option<String>,
+    /// The scenarios of the requirement.
+    #[schemars(
+        description = "The scenarios of the requirement; added and modified requirements need at least one"
+    )]
+    pub scenarios: Option<Vec<ScenarioInput>>,
+}
+
+/// Request parameters for update_proposal_section.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateProposalSectionRequest {
+    /// The change ID.
+    #[schemars(description = "The ID of the active change")]
+    pub change_id: String,
+    /// The section heading.
+    #[schemars(
+        description = "The section heading, e.g. 'Why', 'What Changes' or 'Impact'. A missing section is appended"
+    )]
+    pub section: String,
+    /// The new section body.
+    #[schemars(
+        description = "The new section body in markdown, without the heading; ### subheadings are allowed"
+    )]
+    pub content: String,
+}
+
+/// Response for the change authoring tools.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ChangeWriteResponse {
+    /// The change ID.
+    pub change_id: String,
+    /// The written files, relative to the change directory.
+    pub files: Vec<String>,
+    /// The validate_change result for the change after the write.
+    pub validation: ValidationResponse,
+}
+
+/// Request parameters for set_task_status.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SetTaskStatusRequest {
+    /// The change ID.
+    #[schemars(description = "The ID of the active change")]
+    pub change_id: String,
+    /// The task number.
+    #[schemars(description = "The numbered prefix of the task in tasks.md, e.g. '1.2' or '1.2.1'")]
+    pub task: String,
+    /// Whether the task is done.
+    #[schemars(description = "true to check the task off, false to uncheck it")]
+    pub done: bool,
+}
+
+/// Response for set_task_status tool.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SetTaskStatusResponse {
+    /// The change ID.
+    pub change_id: String,
+    /// The task number.
+    pub task: String,
+    /// Whether the task is now done.
+    pub done: bool,
+    /// Task progress of the change after the update.
+    pub task_progress: TaskProgress,
+}
+
+// =============================================================================
+// Resource Types
+// =============================================================================
+
+/// MIME type of every resource the server exposes.
+const MARKDOWN_MIME_TYPE: &str = "text/markdown";
+
+/// A resource addressed by a `spox://` URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpoxResource {
+    /// `spox://mission`: the project mission in `mission.md` of the spec folder.
+    Mission,
+    /// `spox://spec/{id}`: the `spec.md` of a spec.
+    Spec(String),
+    /// `spox://change/{id}/proposal`: the `proposal.md` of an active change.
+    ChangeProposal(String),
+    /// `spox://change/{id}/tasks`: the `tasks.md` of an active change.
+    ChangeTasks(String),
+}
+
+impl SpoxResource {
+    /// Parse a `spox://` URI, returning `None` for unknown URIs.
+    ///
+    /// IDs must be a single path segment, so a URI cannot reach outside the
+    /// spec and changes folders.
+    pub fn parse(uri: &str) -> Option<Self> {
+        let path = uri.strip_prefix("spox://")?;
+        let is_id = |id: &str| !id.is_empty() && id != "." && id != ".." && !id.contains('\\');
+
+        match path.split('/').collect::<Vec<_>>().as_slice() {
+            ["mission"] => Some(SpoxResource::Mission),
+            ["spec", id] if is_id(id) => Some(SpoxResource::Spec(id.to_string())),
+            ["change", id, "proposal"] if is_id(id) => {
+                Some(SpoxResource::ChangeProposal(id.to_string()))
+            }
+            ["change", id, "tasks"] if is_id(id) => Some(SpoxResource::ChangeTasks(id.to_string())),
+            _ => None,
+        }
+    }
+
+    /// The `spox://` URI of this resource.
+    pub fn uri(&self) -> String {
+        match self {
+            SpoxResource::Mission => "spox://mission".to_string(),
+            SpoxResource::Spec(id) => format!("spox://spec/{}", id),
+            SpoxResource::ChangeProposal(id) => format!("spox://change/{}/proposal", id),
+            SpoxResource::ChangeTasks(id) => format!("spox://change/{}/tasks", id),
+        }
+    }
+
+    /// Describe this resource for `resources/list`.
+    fn describe(&self, name: String, description: String) -> Resource {
+        RawResource {
+            uri: self.uri(),
+            name,
+            description: Some(description),
+            mime_type: Some(MARKDOWN_MIME_TYPE.to_string()),
+            size: None,
+        }
+        .no_annotation()
+    }
+}
+
+/// The resource templates for `resources/templates/list`.
+fn resource_templates() -> Vec<ResourceTemplate> {
+    [
+        (
+            "spox://spec/{spec_id}",
+            "Spec",
+            "Full markdown of a spec, with purpose, requirements and scenarios",
+        ),
+        (
+            "spox://change/{change_id}/proposal",
+            "Change proposal",
+            "The proposal.md of an active change, with its Why and What Changes",
+        ),
+        (
+            "spox://change/{change_id}/tasks",
+            "Change tasks",
+            "The tasks.md checklist of an active change",
+        ),
+    ]
+    .into_iter()
+    .map(|(uri_template, name, description)| {
+        RawResourceTemplate {
+            uri_template: uri_template.to_string(),
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            mime_type: Some(MARKDOWN_MIME_TYPE.to_string()),
+        }
+        .no_annotation()
+    })
+    .collect()
 }
 
 // =============================================================================
@@ -335,12 +677,19 @@ pub struct SpoxServer {
     spec_folder: String,
     /// Changes folder path (relative to project root).
     changes_folder: String,
+    /// Archive folder path (relative to project root).
+    archive_folder: String,
     /// The search index (if available).
     /// Note: This field is retained for backwards compatibility with the original
     /// search_specs implementation. The current implementation uses ensure_index
     /// which auto-builds the index when missing.
     #[allow(dead_code)]
     index: Option<Arc<SpecIndex>>,
+    /// The embedding model, loaded on the first search or rebuild and kept for
+    /// the lifetime of the server.
+    embedder: Arc<LazyEmbedder>,
+    /// The loaded configuration, used to scaffold new changes.
+    config: Arc<Config>,
 }
 
 impl SpoxServer {
@@ -359,10 +708,13 @@ impl SpoxServer {
         let index = index::load_index(&index_path).ok().map(Arc::new);
 
         Self {
-            project_root,
             spec_folder: config.spec_folder().to_string(),
             changes_folder: config.changes_folder().to_string(),
+            archive_folder: config.archive_folder().to_string(),
             index,
+            embedder: Arc::new(LazyEmbedder::from_config(config, &project_root)),
+            config: Arc::new(config.clone()),
+            project_root,
         }
     }
 
@@ -376,21 +728,18 @@ impl SpoxServer {
         self.project_root.join(&self.changes_folder)
     }
 
+    /// Get the full paths of the folders the search index is built from.
+    fn index_folders(&self) -> IndexFolders {
+        IndexFolders {
+            specs: self.specs_path(),
+            changes: self.changes_path(),
+            archive: self.project_root.join(&self.archive_folder),
+        }
+    }
+
     /// Core implementation for list_specs.
     pub fn do_list_specs(&self) -> Result<ListSpecsResponse, String> {
-        let specs = spec::parse_all_specs(&self.specs_path())
-            .map_err(|e| format!("Failed to parse specs: {}", e))?;
-
-        let summaries = specs
-            .into_iter()
-            .map(|s| SpecSummary {
-                id: s.id,
-                title: s.title,
-                purpose: s.purpose,
-            })
-            .collect();
-
-        Ok(ListSpecsResponse { specs: summaries })
+        dashboard::list_specs(&self.specs_path())
     }
 
     /// Core implementation for get_spec_requirements.
@@ -472,84 +821,70 @@ impl SpoxServer {
         &self,
         query: &str,
         top_k: usize,
+        mode: SearchMode,
+        filter: &SearchFilter,
     ) -> Result<SearchSpecsResponse, String> {
         let idx = self.index.as_ref().ok_or(
             "Search index not available. Run 'spox index' first to build the search index.",
         )?;
 
-        let results =
-            index::search(idx, query, top_k).map_err(|e| format!("Search failed: {}", e))?;
+        let page = index::search(idx, query, 0, top_k, mode, filter, self.embedder.as_ref())
+            .map_err(|e| format!("Search failed: {}", e))?;
 
-        let items = results
+        let items = page
+            .results
             .into_iter()
-            .map(|r| SearchResultItem {
-                spec_id: r.spec_id,
-                requirement: r.requirement,
-                score: r.score,
-                snippet: r.snippet,
-            })
+            .map(SearchResultItem::from)
             .collect();
 
         Ok(SearchSpecsResponse {
             results: items,
+            total_matches: page.total,
             index_built: None,
+            index_updated: None,
+            warning: None,
         })
     }
 
     /// Core implementation for search_specs with auto-build support.
     ///
-    /// This method will auto-build the index if it doesn't exist, and indicate
-    /// in the response whether the index was auto-built.
+    /// This method will auto-build the index if it doesn't exist and update it
+    /// if specs or changes changed since it was built, and indicate in the
+    /// response what it did. If an out-of-date index cannot be updated, the
+    /// response carries a warning instead.
     pub fn do_search_specs_with_auto_build(
         &self,
         query: &str,
+        offset: usize,
         top_k: usize,
+        mode: SearchMode,
+        filter: &SearchFilter,
     ) -> Result<SearchSpecsResponse, String> {
-        let index_path = self.project_root.join(".spox/search_index.bin");
-        let was_missing = !index_path.exists();
-
-        // Use ensure_index to get or build the index
-        let idx = index::ensure_index(&self.project_root, &self.specs_path())
-            .map_err(|e| format!("Failed to ensure index: {}", e))?;
-
-        let results =
-            index::search(&idx, query, top_k).map_err(|e| format!("Search failed: {}", e))?;
-
-        let items = results
-            .into_iter()
-            .map(|r| SearchResultItem {
-                spec_id: r.spec_id,
-                requirement: r.requirement,
-                score: r.score,
-                snippet: r.snippet,
-            })
-            .collect();
+        // Use ensure_index to get, build or update the index
+        let (idx, freshness) = index::ensure_index(
+            &self.project_root,
+            &self.index_folders(),
+            self.embedder.as_ref(),
+        )
+        .map_err(|e| format!("Failed to ensure index: {}", e))?;
+
+        let page = index::search(
+            &idx,
+            query,
+            offset,
+            top_k,
+            mode,
+            filter,
+            self.embedder.as_ref(),
+        )
+        .map_err(|e| format!("Search failed: {}", e))?;
 
-        Ok(SearchSpecsResponse {
-            results: items,
-            index_built: Some(was_missing),
-        })
+        Ok(SearchSpecsResponse::from_page(page, &freshness))
     }
 
     /// Core implementation for list_changes.
     pub fn do_list_changes(&self) -> Result<ListChangesResponse, String> {
-        let changes_path = self.changes_path();
-        let changes = gather_changes(changes_path.to_str().unwrap_or(""))
-            .map_err(|e| format!("Failed to list changes: {}", e))?;
-
-        let summaries = changes
-            .into_iter()
-            .map(|c| ChangeSummaryMcp {
-                id: c.name.clone(),
-                title: c.name.replace('-', " "),
-                task_progress: TaskProgress {
-                    completed: c.tasks_completed,
-                    total: c.tasks_total,
-                },
-            })
-            .collect();
-
-        Ok(ListChangesResponse { changes: summaries })
+        dashboard::list_changes(&self.changes_path())
     }
 
     /// Core implementation for get_change.
@@ -558,157 +893,7 @@ impl SpoxServer {
         change_id: &str,
         section: Option<&str>,
     ) -> Result<GetChangeResponse, String> {
-        let change_path = self.changes_path().join(change_id);
-
-        if !change_path.exists() {
-            return Err(format!("Change '{}' not found", change_id));
-        }
-
-        let info = parse_change(&change_path)
-            .map_err(|e| format!("Failed to parse change '{}': {}", change_id, e))?;
-
-        // Build response based on section filter
-        match section {
-            Some("proposal") => Ok(GetChangeResponse {
-                change_id: change_id.to_string(),
-                proposal: Some(ProposalContent {
-                    why: info.why,
-                    what_changes: info.what_changes,
-                }),
-                tasks: None,
-                design: None,
-                deltas: None,
-            }),
-            Some("tasks") => {
-                let tasks_path = change_path.join("tasks.md");
-                let content = if tasks_path.exists() {
-                    fs::read_to_string(&tasks_path)
-                        .map_err(|e| format!("Failed to read tasks.md: {}", e))?
-                } else {
-                    String::new()
-                };
-                Ok(GetChangeResponse {
-                    change_id: change_id.to_string(),
-                    proposal: None,
-                    tasks: Some(TasksContent {
-                        content,
-                        completed: info.tasks_completed,
-                        total: info.tasks_total,
-                    }),
-                    design: None,
-                    deltas: None,
-                })
-            }
-            Some("design") => {
-                let design_path = change_path.join("design.md");
-                let design = if design_path.exists() {
-                    Some(
-                        fs::read_to_string(&design_path)
-                            .map_err(|e| format!("Failed to read design.md: {}", e))?,
-                    )
-                } else {
-                    None
-                };
-                Ok(GetChangeResponse {
-                    change_id: change_id.to_string(),
-                    proposal: None,
-                    tasks: None,
-                    design,
-                    deltas: None,
-                })
-            }
-            Some("deltas") => {
-                let deltas = info
-                    .deltas
-                    .into_iter()
-                    .map(|g| DeltaGroupMcp {
-                        capability: g.capability,
-                        items: g
-                            .items
-                            .into_iter()
-                            .map(|i| DeltaItemMcp {
-                                operation: match i.operation {
-                                    DeltaOp::Added => "added".to_string(),
-                                    DeltaOp::Modified => "modified".to_string(),
-                                    DeltaOp::Removed => "removed".to_string(),
-                                    DeltaOp::Renamed => "renamed".to_string(),
-                                },
-                                name: i.name,
-                                scenarios: i.scenarios,
-                            })
-                            .collect(),
-                    })
-                    .collect();
-                Ok(GetChangeResponse {
-                    change_id: change_id.to_string(),
-                    proposal: None,
-                    tasks: None,
-                    design: None,
-                    deltas: Some(deltas),
-                })
-            }
-            Some(unknown) => Err(format!(
-                "Unknown section '{}'. Valid sections: proposal, tasks, design, deltas",
-                unknown
-            )),
-            None => {
-                // Return all sections
-                let tasks_path = change_path.join("tasks.md");
-                let tasks_content = if tasks_path.exists() {
-                    fs::read_to_string(&tasks_path)
-                        .map_err(|e| format!("Failed to read tasks.md: {}", e))?
-                } else {
-                    String::new()
-                };
-
-                let design_path = change_path.join("design.md");
-                let design = if design_path.exists() {
-                    Some(
-                        fs::read_to_string(&design_path)
-                            .map_err(|e| format!("Failed to read design.md: {}", e))?,
-                    )
-                } else {
-                    None
-                };
-
-                let deltas = info
-                    .deltas
-                    .into_iter()
-                    .map(|g| DeltaGroupMcp {
-                        capability: g.capability,
-                        items: g
-                            .items
-                            .into_iter()
-                            .map(|i| DeltaItemMcp {
-                                operation: match i.operation {
-                                    DeltaOp::Added => "added".to_string(),
-                                    DeltaOp::Modified => "modified".to_string(),
-                                    DeltaOp::Removed => "removed".to_string(),
-                                    DeltaOp::Renamed => "renamed".to_string(),
-                                },
-                                name: i.name,
-                                scenarios: i.scenarios,
-                            })
-                            .collect(),
-                    })
-                    .collect();
-
-                Ok(GetChangeResponse {
-                    change_id: change_id.to_string(),
-                    proposal: Some(ProposalContent {
-                        why: info.why,
-                        what_changes: info.what_changes,
-                    }),
-                    tasks: Some(TasksContent {
-                        content: tasks_content,
-                        completed: info.tasks_completed,
-                        total: info.tasks_total,
-                    }),
-                    design,
-                    deltas: Some(deltas),
-                })
-            }
-        }
+        show_change::get_change(&self.changes_path(), change_id, section)
     }
 
     /// Core implementation for validate_spec.
@@ -716,98 +901,7 @@ impl SpoxServer {
     /// If `spec_id` is provided, validates only that spec.
     /// Otherwise, validates all specs in the project.
     pub fn do_validate_spec(&self, spec_id: Option<&str>) -> Result<ValidationResponse, String> {
-        match spec_id {
-            Some(id) => {
-                // Validate single spec
-                let spec_path = self.specs_path().join(id).join("spec.md");
-
-                if !spec_path.exists() {
-                    return Err(format!("spec not found: '{}'", id));
-                }
-
-                let report = validate_spec_mod::validate_spec(&spec_path);
-                Ok(self.convert_validation_report(&report, None))
-            }
-            None => {
-                // Validate all specs
-                let specs_path = self.specs_path();
-                if !specs_path.exists() {
-                    return Err(format!(
-                        "Spec folder '{}' does not exist",
-                        specs_path.display()
-                    ));
-                }
-
-                let mut total_errors = 0;
-                let mut total_warnings = 0;
-                let mut all_errors = Vec::new();
-                let mut all_warnings = Vec::new();
-
-                let entries: Vec<_> = fs::read_dir(&specs_path)
-                    .map_err(|e| format!("Failed to read spec folder: {}", e))?
-                    .filter_map(|e| e.ok())
-                    .collect();
-
-                // Sort entries and filter to spec directories
-                let mut sorted_entries: Vec<_> = entries
-                    .iter()
-                    .filter(|e| e.path().is_dir())
-                    .filter(|e| {
-                        // Skip directories starting with _ (like _changes, _archive)
-                        let name = e.file_name().to_string_lossy().to_string();
-                        !name.starts_with('_')
-                    })
-                    .collect();
-                sorted_entries.sort_by_key(|e| e.file_name());
-
-                for entry in sorted_entries {
-                    let spec_md_path = entry.path().join("spec.md");
-                    if !spec_md_path.exists() {
-                        continue;
-                    }
-
-                    let report = validate_spec_mod::validate_spec(&spec_md_path);
-                    total_errors += report.errors;
-                    total_warnings += report.warnings;
-
-                    // Collect errors and warnings
-                    for issue in &report.issues {
-                        match issue.severity {
-                            Severity::Error => all_errors.push(ValidationError {
-                                file: issue.file.clone(),
-                                line: issue.line,
-                                description: issue.message.clone(),
-                                section: None,
-                            }),
-                            Severity::Warning => all_warnings.push(ValidationWarning {
-                                file: issue.file.clone(),
-                                line: issue.line,
-                                description: issue.message.clone(),
-                                section: None,
-                            }),
-                            Severity::Info => {} // Skip info messages
-                        }
-                    }
-                }
-
-                let valid = total_errors == 0;
-                let summary = if valid {
-                    format!("All specs valid ({} warnings)", total_warnings)
-                } else {
-                    format!(
-                        "Validation failed: {} errors, {} warnings",
-                        total_errors, total_warnings
-                    )
-                };
-
-                Ok(ValidationResponse {
-                    valid,
-                    errors: all_errors,
-                    warnings: all_warnings,
-                    summary,
-                })
-            }
-        }
+        response::spec_validation_response(&self.specs_path(), spec_id)
     }
 
     /// Core implementation for validate_change.
@@ -818,202 +912,231 @@ impl SpoxServer {
         &self,
         change_id: Option<&str>,
     ) -> Result<ValidationResponse, String> {
-        match change_id {
-            Some(id) => {
-                // Validate single change
-                let change_path = self.changes_path().join(id);
-
-                if !change_path.exists() {
-                    return Err(format!("change not found: '{}'", id));
-                }
+        response::change_validation_response(&self.changes_path(), &self.specs_path(), change_id)
+    }
 
-                let result = validate_change_mod::validate_change(&change_path);
-                Ok(self.convert_change_validation_result(&result))
-            }
-            None => {
-                // Validate all changes
-                let changes_path = self.changes_path();
-                if !changes_path.exists() {
-                    return Err(format!(
-                        "Changes folder '{}' does not exist",
-                        changes_path.display()
-                    ));
-                }
+    /// Core implementation for rebuild_index.
+    ///
+    /// Rebuilds the search index from all specs and changes, re-embedding only changed text.
+    pub fn do_rebuild_index(&self) -> Result<RebuildIndexResponse, String> {
+        let changes = index::rebuild_index(&self.project_root, self.embedder.as_ref())
+            .map_err(|e| format!("Failed to rebuild index: {}", e))?;
 
-                let mut total_errors = 0;
-                let mut total_warnings = 0;
-                let mut all_errors = Vec::new();
-                let mut all_warnings = Vec::new();
-
-                let entries: Vec<_> = fs::read_dir(&changes_path)
-                    .map_err(|e| format!("Failed to read changes folder: {}", e))?
-                    .filter_map(|e| e.ok())
-                    .collect();
-
-                // Sort entries and filter to change directories
-                let mut sorted_entries: Vec<_> =
-                    entries.iter().filter(|e| e.path().is_dir()).collect();
-                sorted_entries.sort_by_key(|e| e.file_name());
-
-                for entry in sorted_entries {
-                    let result = validate_change_mod::validate_change(&entry.path());
-                    total_errors += result.report.errors;
-                    total_warnings += result.report.warnings;
-
-                    let change_id = entry.file_name().to_string_lossy().to_string();
-
-                    // Collect errors and warnings with change context
-                    for issue in &result.report.issues {
-                        let section = Self::extract_section_from_file(&issue.file);
-                        match issue.severity {
-                            Severity::Error => all_errors.push(ValidationError {
-                                file: format!("{}/{}", change_id, issue.file),
-                                line: issue.line,
-                                description: issue.message.clone(),
-                                section,
-                            }),
-                            Severity::Warning => all_warnings.push(ValidationWarning {
-                                file: format!("{}/{}", change_id, issue.file),
-                                line: issue.line,
-                                description: issue.message.clone(),
-                                section,
-                            }),
-                            Severity::Info => {} // Skip info messages
-                        }
-                    }
-                }
+        Ok(RebuildIndexResponse {
+            specs_indexed: changes.specs,
+            changes_indexed: changes.changes,
+            added: changes.added,
+            updated: changes.updated,
+            removed: changes.removed,
+        })
+    }
 
-                let valid = total_errors == 0;
-                let summary = if valid {
-                    format!("All changes valid ({} warnings)", total_warnings)
-                } else {
-                    format!(
-                        "Validation failed: {} errors, {} warnings",
-                        total_errors, total_warnings
-                    )
-                };
+    /// Resolve the directory of an existing active change.
+    fn existing_change_dir(&self, change_id: &str) -> Result<PathBuf, String> {
+        change::validate_name("change ID", change_id).map_err(|e| e.to_string())?;
 
-                Ok(ValidationResponse {
-                    valid,
-                    errors: all_errors,
-                    warnings: all_warnings,
-                    summary,
-                })
-            }
+        let change_path = self.changes_path().join(change_id);
+        if !change_path.is_dir() {
+            return Err(format!("Change '{}' not found", change_id));
         }
+        Ok(change_path)
     }
 
-    /// Convert a ValidationReport to ValidationResponse.
-    fn convert_validation_report(
+    /// Validate a change after a write and report the written files.
+    fn change_write_response(
         &self,
-        report: &crate::validate::ValidationReport,
-        _section_context: Option<&str>,
-    ) -> ValidationResponse {
-        let mut errors = Vec::new();
-        let mut warnings = Vec::new();
-
-        for issue in &report.issues {
-            match issue.severity {
-                Severity::Error => errors.push(ValidationError {
-                    file: issue.file.clone(),
-                    line: issue.line,
-                    description: issue.message.clone(),
-                    section: None,
-                }),
-                Severity::Warning => warnings.push(ValidationWarning {
-                    file: issue.file.clone(),
-                    line: issue.line,
-                    description: issue.message.clone(),
-                    section: None,
-                }),
-                Severity::Info => {} // Skip info messages
-            }
+        change_id: &str,
+        files: Vec<String>,
+    ) -> Result<ChangeWriteResponse, String> {
+        Ok(ChangeWriteResponse {
+            change_id: change_id.to_string(),
+            files,
+            validation: self.do_validate_change(Some(change_id))?,
+        })
+    }
+
+    /// Core implementation for create_change.
+    ///
+    /// Scaffolds the change from the templates like `spox change init`, then
+    /// fills in the proposal title and sections. The change folder is removed
+    /// again if the proposal cannot be written.
+    pub fn do_create_change(
+        &self,
+        change_id: &str,
+        title: &str,
+        why: &str,
+        what_changes: &str,
+        impact: Option<&str>,
+    ) -> Result<ChangeWriteResponse, String> {
+        let scaffold = change::init_change(
+            &self.project_root,
+            &self.config,
+            change_id,
+            &ChangeInitOptions::default(),
+        )
+        .map_err(|e| format!("Failed to create change '{}': {}", change_id, e))?;
+
+        let change_dir = &scaffold.change_dir;
+        let written = change::set_proposal_title(change_dir, title)
+            .and_then(|_| change::set_proposal_section(change_dir, "Why", why))
+            .and_then(|_| change::set_proposal_section(change_dir, "What Changes", what_changes))
+            .and_then(|_| match impact {
+                Some(impact) => change::set_proposal_section(change_dir, "Impact", impact),
+                None => Ok(()),
+            });
+        if let Err(e) = written {
+            let _ = fs::remove_dir_all(change_dir);
+            return Err(format!("Failed to create change '{}': {}", change_id, e));
         }
 
-        let summary = if report.valid {
-            format!("Valid ({} warnings)", report.warnings)
-        } else {
-            format!(
-                "Validation failed: {} errors, {} warnings",
-                report.errors, report.warnings
-            )
-        };
+        self.change_write_response(change_id, scaffold.files)
+    }
 
-        ValidationResponse {
-            valid: report.valid,
-            errors,
-            warnings,
-            summary,
-        }
+    /// Core implementation for add_delta_requirement.
+    pub fn do_add_delta_requirement(
+        &self,
+        change_id: &str,
+        op: DeltaOp,
+        capability: &str,
+        requirement: &ParsedRequirement,
+    ) -> Result<ChangeWriteResponse, String> {
+        let change_path = self.existing_change_dir(change_id)?;
+
+        let path = change::add_delta_requirement(&change_path, capability, op, requirement)
+            .map_err(|e| format!("Failed to add requirement to '{}': {}", change_id, e))?;
+        let file = path
+            .strip_prefix(&change_path)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        self.change_write_response(change_id, vec![file])
     }
 
-    /// Convert a ChangeValidationResult to ValidationResponse.
-    fn convert_change_validation_result(
+    /// Core implementation for update_proposal_section.
+    pub fn do_update_proposal_section(
         &self,
-        result: &validate_change_mod::ChangeValidationResult,
-    ) -> ValidationResponse {
-        let mut errors = Vec::new();
-        let mut warnings = Vec::new();
-
-        for issue in &result.report.issues {
-            let section = Self::extract_section_from_file(&issue.file);
-            match issue.severity {
-                Severity::Error => errors.push(ValidationError {
-                    file: issue.file.clone(),
-                    line: issue.line,
-                    description: issue.message.clone(),
-                    section,
-                }),
-                Severity::Warning => warnings.push(ValidationWarning {
-                    file: issue.file.clone(),
-                    line: issue.line,
-                    description: issue.message.clone(),
-                    section,
-                }),
-                Severity::Info => {} // Skip info messages
-            }
-        }
+        change_id: &str,
+        section: &str,
+        content: &str,
+    ) -> Result<ChangeWriteResponse, String> {
+        let change_path = self.existing_change_dir(change_id)?;
 
-        let summary = if result.report.valid {
-            format!("Valid ({} warnings)", result.report.warnings)
-        } else {
-            format!(
-                "Validation failed: {} errors, {} warnings",
-                result.report.errors, result.report.warnings
-            )
-        };
+        change::set_proposal_section(&change_path, section, content)
+            .map_err(|e| format!("Failed to update proposal of '{}': {}", change_id, e))?;
+
+        self.change_write_response(change_id, vec!["proposal.md".to_string()])
+    }
 
-        ValidationResponse {
-            valid: result.report.valid,
-            errors,
-            warnings,
-            summary,
+    /// Core implementation for set_task_status.
+    ///
+    /// Rewrites only the checkbox of the task in `tasks.md`.
+    pub fn do_set_task_status(
+        &self,
+        change_id: &str,
+        task: &str,
+        done: bool,
+    ) -> Result<SetTaskStatusResponse, String> {
+        let tasks_path = self.existing_change_dir(change_id)?.join("tasks.md");
+        if !tasks_path.is_file() {
+            return Err(format!("Change '{}' has no tasks.md", change_id));
         }
+
+        let stats = validate_tasks_mod::set_task_status(&tasks_path, task, done)
+            .map_err(|e| format!("Failed to update tasks of '{}': {}", change_id, e))?;
+
+        Ok(SetTaskStatusResponse {
+            change_id: change_id.to_string(),
+            task: task.trim().to_string(),
+            done,
+            task_progress: TaskProgress {
+                completed: stats.completed,
+                total: stats.total,
+            },
+        })
     }
 
-    /// Extract section name from file path for change validation context.
-    fn extract_section_from_file(file: &str) -> Option<String> {
-        if file.contains("proposal.md") {
-            Some("proposal".to_string())
-        } else if file.contains("tasks.md") {
-            Some("tasks".to_string())
-        } else if file.contains("design.md") {
-            Some("design".to_string())
-        } else if file.contains("specs/") || file.contains("spec.md") {
-            Some("deltas".to_string())
-        } else {
-            None
+    /// Core implementation for resources/list.
+    ///
+    /// Lists the mission, every spec, and the proposal and tasks of every
+    /// active change, skipping files that don't exist.
+    pub fn do_list_resources(&self) -> Result<Vec<Resource>, String> {
+        let mut resources = Vec::new();
+
+        if self.specs_path().join("mission.md").exists() {
+            resources.push(SpoxResource::Mission.describe(
+                "Mission".to_string(),
+                "Project mission, tech stack and conventions".to_string(),
+            ));
         }
+
+        let specs = spec::parse_all_specs(&self.specs_path())
+            .map_err(|e| format!("Failed to parse specs: {}", e))?;
+        for s in specs {
+            resources.push(SpoxResource::Spec(s.id).describe(s.title, s.purpose));
+        }
+
+        let changes_path = self.changes_path();
+        let changes = gather_changes(changes_path.to_str().unwrap_or(""))
+            .map_err(|e| format!("Failed to list changes: {}", e))?;
+        for c in changes {
+            let change_path = changes_path.join(&c.name);
+            if change_path.join("proposal.md").exists() {
+                resources.push(SpoxResource::ChangeProposal(c.name.clone()).describe(
+                    format!("{} proposal", c.name),
+                    format!("Why and what changes in change '{}'", c.name),
+                ));
+            }
+            if change_path.join("tasks.md").exists() {
+                resources.push(SpoxResource::ChangeTasks(c.name.clone()).describe(
+                    format!("{} tasks", c.name),
+                    format!(
+                        "Tasks of change '{}' ({}/{} done)",
+                        c.name, c.tasks_completed, c.tasks_total
+                    ),
+                ));
+            }
+        }
+
+        Ok(resources)
     }
 
-    /// Core implementation for rebuild_index.
+    /// Core implementation for resources/read.
     ///
-    /// Rebuilds the search index from all specs.
-    pub fn do_rebuild_index(&self) -> Result<RebuildIndexResponse, String> {
-        let specs_indexed = index::rebuild_index(&self.project_root)
-            .map_err(|e| format!("Failed to rebuild index: {}", e))?;
+    /// Returns the markdown of the resource. Specs must parse and changes
+    /// must have a readable proposal, as for `get_spec_requirements` and
+    /// `get_change`.
+    pub fn do_read_resource(&self, resource: &SpoxResource) -> Result<String, String> {
+        let path = match resource {
+            SpoxResource::Mission => self.specs_path().join("mission.md"),
+            SpoxResource::Spec(spec_id) => {
+                spec::get_spec_by_id(&self.specs_path(), spec_id)
+                    .map_err(|e| format!("Failed to get spec '{}': {}", spec_id, e))?;
+                self.specs_path().join(spec_id).join("spec.md")
+            }
+            SpoxResource::ChangeProposal(change_id) | SpoxResource::ChangeTasks(change_id) => {
+                let change_path = self.changes_path().join(change_id);
+                if !change_path.exists() {
+                    return Err(format!("Change '{}' not found", change_id));
+                }
+                parse_change(&change_path)
+                    .map_err(|e| format!("Failed to parse change '{}': {}", change_id, e))?;
+                match resource {
+                    SpoxResource::ChangeTasks(_) => change_path.join("tasks.md"),
+                    _ => change_path.join("proposal.md"),
+                }
+            }
+        };
 
-        Ok(RebuildIndexResponse { specs_indexed })
+        fs::read_to_string(&path).map_err(|e| {
+            format!(
+                "Failed to read {}: {}",
+                path.strip_prefix(&self.project_root)
+                    .unwrap_or(&path)
+                    .display(),
+                e
+            )
+        })
     }
 }
 
@@ -1062,13 +1185,32 @@ impl SpoxServer {
         }
     }
 
-    /// Search specs semantically.
+    /// Search specs by meaning and keywords.
     #[tool(
-        description = "Search specs semantically. Automatically builds search index if missing. Returns ranked results with index_built flag if index was auto-built."
+        description = "Search specs, scenarios, active changes and archived changes by meaning and by exact keywords such as config keys and command names. Automatically builds the search index if missing and updates it if specs or changes changed. Can be restricted to spec IDs, kinds and a minimum score, and paged with offset. Returns ranked results with their kind, the total number of matches, index_built and index_updated flags, and a warning if the results come from an out-of-date index."
     )]
     async fn search_specs(&self, #[tool(aggr)] req: SearchSpecsRequest) -> String {
         let top_k = req.top_k.unwrap_or(10);
-        match self.do_search_specs_with_auto_build(&req.query, top_k) {
+        let mode = match req.mode.as_deref().map(str::parse).transpose() {
+            Ok(mode) => mode.unwrap_or_default(),
+            Err(e) => return format!("{{\"error\": \"{}\"}}", e),
+        };
+        let kinds = req.kinds.unwrap_or_default();
+        let filter = match kinds
+            .iter()
+            .map(|kind| kind.parse::<ResultKind>())
+            .collect()
+        {
+            Ok(kinds) => SearchFilter {
+                kinds,
+                spec_ids: req.spec_ids.unwrap_or_default(),
+                exclude_spec_ids: req.exclude_spec_ids.unwrap_or_default(),
+                min_score: req.min_score,
+            },
+            Err(e) => return format!("{{\"error\": \"{}\"}}", e),
+        };
+        let offset = req.offset.unwrap_or(0);
+        match self.do_search_specs_with_auto_build(&req.query, offset, top_k, mode, &filter) {
             Ok(response) => serde_json::to_string_pretty(&response).unwrap_or_else(|e| {
                 format!("{{\"error\": \"Failed to serialize response: {}\"}}", e)
             }),
@@ -1087,12 +1229,70 @@ impl SpoxServer {
         }
     }
 
-    /// Get details of a specific change.
+    /// Get details of a specific change.
+    #[tool(
+        description = "Get the full content of a change proposal including proposal, tasks, design (if present), and spec deltas."
+    )]
+    async fn get_change(&self, #[tool(aggr)] req: GetChangeRequest) -> String {
+        match self.do_get_change(&req.change_id, req.section.as_deref()) {
+            Ok(response) => serde_json::to_string_pretty(&response).unwrap_or_else(|e| {
+                format!("{{\"error\": \"Failed to serialize response: {}\"}}", e)
+            }),
+            Err(e) => format!("{{\"error\": \"{}\"}}", e),
+        }
+    }
+
+    /// Validate spec file(s).
+    #[tool(
+        description = "Validate spec structure and content. Called without parameters validates all specs. Called with spec_id validates only that spec."
+    )]
+    async fn validate_spec(&self, #[tool(aggr)] req: ValidateSpecRequest) -> String {
+        match self.do_validate_spec(req.spec_id.as_deref()) {
+            Ok(response) => serde_json::to_string_pretty(&response).unwrap_or_else(|e| {
+                format!("{{\"error\": \"Failed to serialize response: {}\"}}", e)
+            }),
+            Err(e) => format!("{{\"error\": \"{}\"}}", e),
+        }
+    }
+
+    /// Validate change proposal(s).
+    #[tool(
+        description = "Validate change proposal structure and content. Called without parameters validates all active changes. Called with change_id validates only that change."
+    )]
+    async fn validate_change(&self, #[tool(aggr)] req: ValidateChangeRequest) -> String {
+        match self.do_validate_change(req.change_id.as_deref()) {
+            Ok(response) => serde_json::to_string_pretty(&response).unwrap_or_else(|e| {
+                format!("{{\"error\": \"Failed to serialize response: {}\"}}", e)
+            }),
+            Err(e) => format!("{{\"error\": \"{}\"}}", e),
+        }
+    }
+
+    /// Rebuild the search index.
+    #[tool(
+        description = "Rebuild the search index from all specs and active and archived changes. Only changed text is re-embedded. Returns the count of specs and changes indexed and of added, updated and removed entries."
+    )]
+    async fn rebuild_index(&self) -> String {
+        match self.do_rebuild_index() {
+            Ok(response) => serde_json::to_string_pretty(&response).unwrap_or_else(|e| {
+                format!("{{\"error\": \"Failed to serialize response: {}\"}}", e)
+            }),
+            Err(e) => format!("{{\"error\": \"{}\"}}", e),
+        }
+    }
+
+    /// Create a change with a filled-in proposal.
     #[tool(
-        description = "Get the full content of a change proposal including proposal, tasks, design (if present), and spec deltas."
+        description = "Create an active change from the change templates, with the proposal title, Why, What Changes and optionally Impact filled in. Add delta specs with add_delta_requirement. Returns the created files and the validate_change result."
     )]
-    async fn get_change(&self, #[tool(aggr)] req: GetChangeRequest) -> String {
-        match self.do_get_change(&req.change_id, req.section.as_deref()) {
+    async fn create_change(&self, #[tool(aggr)] req: CreateChangeRequest) -> String {
+        match self.do_create_change(
+            &req.change_id,
+            &req.title,
+            &req.why,
+            &req.what_changes,
+            req.impact.as_deref(),
+        ) {
             Ok(response) => serde_json::to_string_pretty(&response).unwrap_or_else(|e| {
                 format!("{{\"error\": \"Failed to serialize response: {}\"}}", e)
             }),
@@ -1100,12 +1300,31 @@ impl SpoxServer {
         }
     }
 
-    /// Validate spec file(s).
+    /// Add a requirement to a delta spec of a change.
     #[tool(
-        description = "Validate spec structure and content. Called without parameters validates all specs. Called with spec_id validates only that spec."
+        description = "Add an ADDED, MODIFIED, REMOVED or RENAMED requirement with its scenarios to the delta spec of a capability in an active change, creating the delta spec if needed. A requirement with the same operation and name is replaced. Returns the written file and the validate_change result."
     )]
-    async fn validate_spec(&self, #[tool(aggr)] req: ValidateSpecRequest) -> String {
-        match self.do_validate_spec(req.spec_id.as_deref()) {
+    async fn add_delta_requirement(&self, #[tool(aggr)] req: AddDeltaRequirementRequest) -> String {
+        let op = match req.operation.parse::<DeltaOp>() {
+            Ok(op) => op,
+            Err(e) => return format!("{{\"error\": \"{}\"}}", e),
+        };
+        let requirement = ParsedRequirement {
+            name: req.requirement,
+            description: req.description.unwrap_or_default().trim().to_string(),
+            scenarios: req
+                .scenarios
+                .unwrap_or_default()
+                .into_iter()
+                .map(|s| ParsedScenario {
+                    name: s.name,
+                    when_clause: s.when,
+                    then_clauses: s.then,
+                })
+                .collect(),
+        };
+
+        match self.do_add_delta_requirement(&req.change_id, op, &req.capability, &requirement) {
             Ok(response) => serde_json::to_string_pretty(&response).unwrap_or_else(|e| {
                 format!("{{\"error\": \"Failed to serialize response: {}\"}}", e)
             }),
@@ -1113,12 +1332,15 @@ impl SpoxServer {
         }
     }
 
-    /// Validate change proposal(s).
+    /// Replace a section of a change proposal.
     #[tool(
-        description = "Validate change proposal structure and content. Called without parameters validates all active changes. Called with change_id validates only that change."
+        description = "Replace the body of a section (e.g. Why, What Changes, Impact) of an active change's proposal.md, appending the section if missing. Returns the validate_change result."
     )]
-    async fn validate_change(&self, #[tool(aggr)] req: ValidateChangeRequest) -> String {
-        match self.do_validate_change(req.change_id.as_deref()) {
+    async fn update_proposal_section(
+        &self,
+        #[tool(aggr)] req: UpdateProposalSectionRequest,
+    ) -> String {
+        match self.do_update_proposal_section(&req.change_id, &req.section, &req.content) {
             Ok(response) => serde_json::to_string_pretty(&response).unwrap_or_else(|e| {
                 format!("{{\"error\": \"Failed to serialize response: {}\"}}", e)
             }),
@@ -1126,12 +1348,12 @@ impl SpoxServer {
         }
     }
 
-    /// Rebuild the search index.
+    /// Check off or uncheck a task of a change.
     #[tool(
-        description = "Rebuild the search index from all specs. Returns the count of specs indexed."
+        description = "Check off (done: true) or uncheck (done: false) a task in an active change's tasks.md by its number, e.g. '1.2'. Only that checkbox is rewritten. Returns the updated task progress."
     )]
-    async fn rebuild_index(&self) -> String {
-        match self.do_rebuild_index() {
+    async fn set_task_status(&self, #[tool(aggr)] req: SetTaskStatusRequest) -> String {
+        match self.do_set_task_status(&req.change_id, &req.task, req.done) {
             Ok(response) => serde_json::to_string_pretty(&response).unwrap_or_else(|e| {
                 format!("{{\"error\": \"Failed to serialize response: {}\"}}", e)
             }),
@@ -1149,7 +1371,11 @@ impl ServerHandler for SpoxServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: ProtocolVersion::V_2024_11_05,
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_prompts()
+                .build(),
             server_info: Implementation {
                 name: "spox".into(),
                 version: env!("CARGO_PKG_VERSION").into(),
@@ -1157,15 +1383,84 @@ impl ServerHandler for SpoxServer {
             instructions: Some(
                 "Spox is a spec-driven development tool. Use list_specs to discover available specs, \
                  get_spec_requirements to see the structure of a spec, get_scenario for full scenario \
-                 details, and search_specs to find relevant content across all specs. Use list_changes \
+                 details, and search_specs to find relevant content across specs and changes. Use list_changes \
                  to see active change proposals, and get_change to retrieve change details. Use \
                  validate_spec to validate spec structure and content (all specs or a specific one), \
                  validate_change to validate change proposals (all changes or a specific one), and \
-                 rebuild_index to rebuild the search index from all specs."
+                 rebuild_index to rebuild the search index from all specs. Author changes with \
+                 create_change, add_delta_requirement and update_proposal_section instead of writing \
+                 files directly, and check off tasks with set_task_status. Specs, change proposals and \
+                 tasks, and the project mission can also be read as spox:// resources. The setup, propose, \
+                 implement and archive prompts walk through the spec-driven workflow."
                     .into(),
             ),
         }
     }
+
+    async fn list_prompts(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult {
+            prompts: prompts::list_prompts(),
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        prompts::get_prompt(&request.name, request.arguments.as_ref())
+            .map_err(|e| McpError::invalid_params(e, None))
+    }
+
+    async fn list_resources(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let resources = self
+            .do_list_resources()
+            .map_err(|e| McpError::internal_error(e, None))?;
+        Ok(ListResourcesResult {
+            resources,
+            next_cursor: None,
+        })
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult {
+            resource_templates: resource_templates(),
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let resource = SpoxResource::parse(&request.uri).ok_or_else(|| {
+            McpError::invalid_params(format!("Unknown resource URI '{}'", request.uri), None)
+        })?;
+        let text = self
+            .do_read_resource(&resource)
+            .map_err(|e| McpError::resource_not_found(e, None))?;
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: request.uri,
+                mime_type: Some(MARKDOWN_MIME_TYPE.to_string()),
+                text,
+            }],
+        })
+    }
 }
 
 // =============================================================================
@@ -1484,7 +1779,8 @@ The system SHALL do something basic.
         let server = SpoxServer::new(&config, temp_dir.path().to_path_buf());
 
         // Without index, the original search method should fail
-        let result = server.do_search_specs("login", 10);
+        let result =
+            server.do_search_specs("login", 10, SearchMode::Hybrid, &SearchFilter::default());
 
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("index"));
@@ -1514,7 +1810,13 @@ The system SHALL do something basic.
         assert!(!index_path.exists(), "Index should not exist before search");
 
         // Search should auto-build the index and succeed
-        let result = server.do_search_specs_with_auto_build("login", 10);
+        let result = server.do_search_specs_with_auto_build(
+            "login",
+            0,
+            10,
+            SearchMode::Hybrid,
+            &SearchFilter::default(),
+        );
 
         assert!(
             result.is_ok(),
@@ -1560,7 +1862,12 @@ The system SHALL do something basic.
         // Build the index first
         let index_path = spox_dir.join("search_index.bin");
         let specs = crate::core::spec::parse_all_specs(&specs_dir).unwrap();
-        let index = crate::core::index::build_index(&specs).unwrap();
+        let index = crate::core::index::build_index(
+            &specs,
+            &[],
+            &crate::core::embedding::FastEmbedder::new().unwrap(),
+        )
+        .unwrap();
         crate::core::index::save_index(&index, &index_path).unwrap();
 
         assert!(index_path.exists(), "Index should exist before search");
@@ -1569,7 +1876,13 @@ The system SHALL do something basic.
         let server = SpoxServer::new(&config, project_root.to_path_buf());
 
         // Search should use existing index
-        let result = server.do_search_specs_with_auto_build("login", 10);
+        let result = server.do_search_specs_with_auto_build(
+            "login",
+            0,
+            10,
+            SearchMode::Hybrid,
+            &SearchFilter::default(),
+        );
 
         assert!(result.is_ok(), "Search should succeed: {:?}", result);
 
@@ -1586,6 +1899,157 @@ The system SHALL do something basic.
         assert!(!response.results.is_empty(), "Should have search results");
     }
 
+    #[test]
+    fn test_search_specs_keyword_mode_finds_identifier() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_root = temp_dir.path();
+        fs::create_dir_all(project_root.join(".spox")).unwrap();
+        let specs_dir = project_root.join("specs");
+        fs::create_dir_all(&specs_dir).unwrap();
+        create_test_spec(&specs_dir, "auth", VALID_SPEC);
+        create_test_spec(&specs_dir, "simple", SIMPLE_SPEC);
+
+        let mut config = create_test_config("specs");
+        config.search.backend = crate::config::EmbeddingBackend::Hashing;
+        let server = SpoxServer::new(&config, project_root.to_path_buf());
+
+        let response = server
+            .do_search_specs_with_auto_build(
+                "logout",
+                0,
+                10,
+                SearchMode::Keyword,
+                &SearchFilter {
+                    kinds: vec![ResultKind::Requirement],
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(response.index_built, Some(true));
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results[0].kind, "requirement");
+        assert_eq!(response.results[0].spec_id, "auth");
+        assert_eq!(
+            response.results[0].requirement.as_deref(),
+            Some("User Logout")
+        );
+    }
+
+    #[test]
+    fn test_search_specs_pages_filtered_results() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_root = temp_dir.path();
+        fs::create_dir_all(project_root.join(".spox")).unwrap();
+        let specs_dir = project_root.join("specs");
+        fs::create_dir_all(&specs_dir).unwrap();
+        create_test_spec(&specs_dir, "auth", VALID_SPEC);
+        create_test_spec(&specs_dir, "simple", SIMPLE_SPEC);
+
+        let mut config = create_test_config("specs");
+        config.search.backend = crate::config::EmbeddingBackend::Hashing;
+        let server = SpoxServer::new(&config, project_root.to_path_buf());
+        let filter = SearchFilter {
+            exclude_spec_ids: vec!["simple".to_string()],
+            ..Default::default()
+        };
+
+        let all = server
+            .do_search_specs_with_auto_build("user", 0, 100, SearchMode::Keyword, &filter)
+            .unwrap();
+        let second = server
+            .do_search_specs_with_auto_build("user", 1, 1, SearchMode::Keyword, &filter)
+            .unwrap();
+
+        assert!(all.total_matches > 1);
+        assert_eq!(all.results.len(), all.total_matches);
+        assert!(all.results.iter().all(|r| r.spec_id == "auth"));
+        assert_eq!(second.total_matches, all.total_matches);
+        assert_eq!(second.results.len(), 1);
+        assert_eq!(second.results[0].snippet, all.results[1].snippet);
+    }
+
+    #[test]
+    fn test_repeated_searches_reuse_embedder() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_root = temp_dir.path();
+        fs::create_dir_all(project_root.join(".spox")).unwrap();
+        let specs_dir = project_root.join("specs");
+        fs::create_dir_all(&specs_dir).unwrap();
+        create_test_spec(&specs_dir, "auth", VALID_SPEC);
+
+        let mut config = create_test_config("specs");
+        config.search.backend = crate::config::EmbeddingBackend::Hashing;
+        let server = SpoxServer::new(&config, project_root.to_path_buf());
+        // Clones share the embedder, like the per-request handlers of the MCP server
+        let clone = server.clone();
+        assert!(Arc::ptr_eq(&server.embedder, &clone.embedder));
+        assert!(!server.embedder.is_loaded());
+
+        let search = |server: &SpoxServer, query: &str| {
+            server
+                .do_search_specs_with_auto_build(
+                    query,
+                    0,
+                    5,
+                    SearchMode::Hybrid,
+                    &SearchFilter::default(),
+                )
+                .unwrap()
+        };
+
+        // The first search loads the backend, for every clone
+        search(&server, "login");
+        assert!(clone.embedder.is_loaded());
+
+        for query in ["password", "logout", "session"] {
+            search(&clone, query);
+            assert!(Arc::ptr_eq(&server.embedder, &clone.embedder));
+            assert!(server.embedder.is_loaded());
+        }
+    }
+
+    #[test]
+    #[ignore] // Benchmark; requires fastembed model download
+    fn bench_repeated_search_latency() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_root = temp_dir.path();
+        fs::create_dir_all(project_root.join(".spox")).unwrap();
+        let specs_dir = project_root.join("specs");
+        fs::create_dir_all(&specs_dir).unwrap();
+        create_test_spec(&specs_dir, "auth", VALID_SPEC);
+
+        let config = create_test_config("specs");
+        // Build the index up front so the first timed search only loads the model
+        SpoxServer::new(&config, project_root.to_path_buf())
+            .do_rebuild_index()
+            .unwrap();
+        let server = SpoxServer::new(&config, project_root.to_path_buf());
+        let search = |query: &str| {
+            let start = std::time::Instant::now();
+            server
+                .do_search_specs_with_auto_build(
+                    query,
+                    0,
+                    5,
+                    SearchMode::Hybrid,
+                    &SearchFilter::default(),
+                )
+                .unwrap();
+            start.elapsed()
+        };
+
+        let first = search("login");
+        let repeated = (search("password") + search("logout") + search("session")) / 3;
+
+        assert!(
+            repeated < first,
+            "Repeated searches ({:?}) should be faster than the first ({:?})",
+            repeated,
+            first
+        );
+    }
+
     // ==================== ServerHandler tests ====================
 
     #[test]
@@ -1602,6 +2066,9 @@ The system SHALL do something basic.
         assert_eq!(info.server_info.name, "spox");
         assert!(info.instructions.is_some());
         assert!(info.instructions.unwrap().contains("list_specs"));
+        assert!(info.capabilities.tools.is_some());
+        assert!(info.capabilities.resources.is_some());
+        assert!(info.capabilities.prompts.is_some());
     }
 
     // ==================== list_changes tests ====================
@@ -2090,4 +2557,399 @@ system = ["mcp"]
         let response = result.unwrap();
         assert_eq!(response.specs_indexed, 0);
     }
+
+    // ==================== authoring tests ====================
+
+    fn create_authoring_project(root: &std::path::Path) -> SpoxServer {
+        let templates = root.join(change::CHANGE_TEMPLATES_DIR);
+        fs::create_dir_all(&templates).unwrap();
+        for (name, content) in [
+            (
+                "proposal.md",
+                include_str!("../../templates/specs/change/proposal.md"),
+            ),
+            (
+                "tasks.md",
+                include_str!("../../templates/specs/change/tasks.md"),
+            ),
+        ] {
+            fs::write(templates.join(name), content).unwrap();
+        }
+        let specs_dir = root.join("specs");
+        create_test_spec(&specs_dir, "auth", VALID_SPEC);
+        fs::create_dir_all(root.join("specs/_changes")).unwrap();
+
+        let config = create_test_config("specs");
+        SpoxServer::new(&config, root.to_path_buf())
+    }
+
+    fn two_factor_requirement() -> ParsedRequirement {
+        ParsedRequirement {
+            name: "Two Factor Login".to_string(),
+            description: "The system SHALL ask for a one-time code after the password.".to_string(),
+            scenarios: vec![ParsedScenario {
+                name: "Code required".to_string(),
+                when_clause: "user provides valid email and password".to_string(),
+                then_clauses: vec!["the system asks for a one-time code".to_string()],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_create_change_and_add_delta_requirement() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let server = create_authoring_project(root);
+
+        let created = server
+            .do_create_change(
+                "add-2fa",
+                "Add two-factor login",
+                "Passwords alone are phished too often, so logins need a second factor.",
+                "- Ask for a one-time code after the password",
+                Some("- Affected specs: auth"),
+            )
+            .unwrap();
+
+        assert_eq!(created.files, vec!["proposal.md", "tasks.md"]);
+        assert!(!created.validation.valid);
+        assert!(created
+            .validation
+            .errors
+            .iter()
+            .any(|e| e.description.contains("at least one delta spec")));
+        let proposal = fs::read_to_string(root.join("specs/_changes/add-2fa/proposal.md")).unwrap();
+        assert!(proposal.starts_with("# Change: Add two-factor login\n\n## Why\nPasswords"));
+        assert!(proposal.contains("## What Changes\n- Ask for a one-time code"));
+        assert!(proposal.ends_with("## Impact\n- Affected specs: auth\n"));
+
+        let added = server
+            .do_add_delta_requirement("add-2fa", DeltaOp::Added, "auth", &two_factor_requirement())
+            .unwrap();
+
+        assert_eq!(added.files, vec!["specs/auth/spec.md"]);
+        assert!(added.validation.valid, "{:?}", added.validation.errors);
+        let delta =
+            fs::read_to_string(root.join("specs/_changes/add-2fa/specs/auth/spec.md")).unwrap();
+        assert!(delta.starts_with("## ADDED Requirements\n\n### Requirement: Two Factor Login\n"));
+    }
+
+    #[test]
+    fn test_add_delta_requirement_reports_validation_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let server = create_authoring_project(root);
+        server
+            .do_create_change(
+                "drop-sessions",
+                "Drop sessions",
+                "Sessions are replaced by tokens everywhere in the system now.",
+                "- Remove session handling",
+                None,
+            )
+            .unwrap();
+        let mut missing = two_factor_requirement();
+        missing.name = "Session Timeout".to_string();
+
+        let response = server
+            .do_add_delta_requirement("drop-sessions", DeltaOp::Modified, "auth", &missing)
+            .unwrap();
+
+        assert!(!response.validation.valid);
+        assert!(response
+            .validation
+            .errors
+            .iter()
+            .any(|e| e.description.contains("Session Timeout")));
+    }
+
+    #[test]
+    fn test_create_change_rejects_invalid_or_existing_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let server = create_authoring_project(root);
+        fs::create_dir_all(root.join("specs/_changes/add-2fa")).unwrap();
+
+        let existing = server
+            .do_create_change("add-2fa", "Title", "Why.", "- What", None)
+            .unwrap_err();
+        let invalid = server
+            .do_create_change("../escape", "Title", "Why.", "- What", None)
+            .unwrap_err();
+
+        assert!(existing.contains("already exists"), "{}", existing);
+        assert!(invalid.contains("kebab-case"), "{}", invalid);
+    }
+
+    #[test]
+    fn test_create_change_removes_folder_on_invalid_proposal() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let server = create_authoring_project(root);
+
+        let err = server
+            .do_create_change(
+                "add-2fa",
+                "Title",
+                "Why.\n\n## Impact\nNone",
+                "- What",
+                None,
+            )
+            .unwrap_err();
+
+        assert!(err.contains("headings"), "{}", err);
+        assert!(!root.join("specs/_changes/add-2fa").exists());
+    }
+
+    #[test]
+    fn test_update_proposal_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let server = create_authoring_project(root);
+        server
+            .do_create_change(
+                "add-2fa",
+                "Add two-factor login",
+                "Short.",
+                "- Ask for a code",
+                None,
+            )
+            .unwrap();
+
+        let response = server
+            .do_update_proposal_section(
+                "add-2fa",
+                "why",
+                "Passwords alone are phished too often, so logins need a second factor.",
+            )
+            .unwrap();
+
+        assert_eq!(response.files, vec!["proposal.md"]);
+        let proposal = fs::read_to_string(root.join("specs/_changes/add-2fa/proposal.md")).unwrap();
+        assert!(proposal.contains("## Why\nPasswords alone are phished"));
+        assert!(!proposal.contains("Short."));
+    }
+
+    #[test]
+    fn test_authoring_tools_require_existing_change() {
+        let temp_dir = TempDir::new().unwrap();
+        let server = create_authoring_project(temp_dir.path());
+
+        let missing = server
+            .do_update_proposal_section("nope", "Why", "Text")
+            .unwrap_err();
+        let escaping = server
+            .do_add_delta_requirement("..", DeltaOp::Added, "auth", &two_factor_requirement())
+            .unwrap_err();
+
+        assert!(missing.contains("not found"), "{}", missing);
+        assert!(escaping.contains("kebab-case"), "{}", escaping);
+    }
+
+    // ==================== set_task_status tests ====================
+
+    #[test]
+    fn test_set_task_status() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let server = create_resource_project(root);
+
+        let response = server.do_set_task_status("add-2fa", "1.2", true).unwrap();
+
+        assert_eq!(response.task, "1.2");
+        assert!(response.done);
+        assert_eq!(response.task_progress.completed, 2);
+        assert_eq!(response.task_progress.total, 3);
+        let tasks = fs::read_to_string(root.join("specs/_changes/add-2fa/tasks.md")).unwrap();
+        assert!(tasks.contains("- [x] 1.1 First task\n- [x] 1.2 Second task\n- [ ] 1.3"));
+
+        let response = server.do_set_task_status("add-2fa", "1.1", false).unwrap();
+        assert_eq!(response.task_progress.completed, 1);
+    }
+
+    #[test]
+    fn test_set_task_status_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let server = create_resource_project(root);
+        fs::remove_file(root.join("specs/_changes/add-2fa/tasks.md")).unwrap();
+        create_test_change(
+            &root.join("specs/_changes"),
+            "add-sso",
+            TEST_TASKS,
+            TEST_DELTA,
+        );
+
+        let no_tasks = server
+            .do_set_task_status("add-2fa", "1.1", true)
+            .unwrap_err();
+        let unknown_task = server
+            .do_set_task_status("add-sso", "4.1", true)
+            .unwrap_err();
+        let missing = server.do_set_task_status("nope", "1.1", true).unwrap_err();
+
+        assert!(no_tasks.contains("no tasks.md"), "{}", no_tasks);
+        assert!(
+            unknown_task.contains("Task '4.1' not found"),
+            "{}",
+            unknown_task
+        );
+        assert!(missing.contains("not found"), "{}", missing);
+    }
+
+    // ==================== resources tests ====================
+
+    fn create_resource_project(root: &std::path::Path) -> SpoxServer {
+        let specs_dir = root.join("specs");
+        fs::create_dir_all(&specs_dir).unwrap();
+        fs::write(specs_dir.join("mission.md"), "# Mission\n\nShip specs.\n").unwrap();
+        create_test_spec(&specs_dir, "auth", VALID_SPEC);
+        create_test_change(
+            &root.join("specs/_changes"),
+            "add-2fa",
+            TEST_TASKS,
+            TEST_DELTA,
+        );
+
+        let config = create_test_config("specs");
+        SpoxServer::new(&config, root.to_path_buf())
+    }
+
+    #[test]
+    fn test_parse_resource_uri() {
+        assert_eq!(
+            SpoxResource::parse("spox://mission"),
+            Some(SpoxResource::Mission)
+        );
+        assert_eq!(
+            SpoxResource::parse("spox://spec/auth"),
+            Some(SpoxResource::Spec("auth".to_string()))
+        );
+        assert_eq!(
+            SpoxResource::parse("spox://change/add-2fa/proposal"),
+            Some(SpoxResource::ChangeProposal("add-2fa".to_string()))
+        );
+        assert_eq!(
+            SpoxResource::parse("spox://change/add-2fa/tasks"),
+            Some(SpoxResource::ChangeTasks("add-2fa".to_string()))
+        );
+        assert_eq!(
+            SpoxResource::parse("spox://change/add-2fa/tasks")
+                .unwrap()
+                .uri(),
+            "spox://change/add-2fa/tasks"
+        );
+    }
+
+    #[test]
+    fn test_parse_resource_uri_rejects_unknown_and_escaping_uris() {
+        for uri in [
+            "file:///etc/passwd",
+            "spox://spec",
+            "spox://spec/",
+            "spox://spec/..",
+            "spox://spec/auth/extra",
+            "spox://change/add-2fa",
+            "spox://change/../proposal",
+            "spox://change/add-2fa/design",
+        ] {
+            assert_eq!(SpoxResource::parse(uri), None, "{}", uri);
+        }
+    }
+
+    #[test]
+    fn test_list_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let server = create_resource_project(temp_dir.path());
+
+        let resources = server.do_list_resources().unwrap();
+        let uris: Vec<&str> = resources.iter().map(|r| r.uri.as_str()).collect();
+
+        assert_eq!(
+            uris,
+            vec![
+                "spox://mission",
+                "spox://spec/auth",
+                "spox://change/add-2fa/proposal",
+                "spox://change/add-2fa/tasks",
+            ]
+        );
+        assert_eq!(resources[1].name, "Auth Specification");
+        assert_eq!(resources[1].mime_type.as_deref(), Some("text/markdown"));
+        assert!(resources[3]
+            .description
+            .as_deref()
+            .unwrap()
+            .contains("1/3 done"));
+    }
+
+    #[test]
+    fn test_list_resources_without_mission() {
+        let temp_dir = TempDir::new().unwrap();
+        let server = create_resource_project(temp_dir.path());
+        fs::remove_file(temp_dir.path().join("specs/mission.md")).unwrap();
+
+        let resources = server.do_list_resources().unwrap();
+
+        assert!(resources.iter().all(|r| r.uri != "spox://mission"));
+        assert_eq!(resources.len(), 3);
+    }
+
+    #[test]
+    fn test_resource_templates() {
+        let templates = resource_templates();
+        let uri_templates: Vec<&str> = templates.iter().map(|t| t.uri_template.as_str()).collect();
+
+        assert_eq!(
+            uri_templates,
+            vec![
+                "spox://spec/{spec_id}",
+                "spox://change/{change_id}/proposal",
+                "spox://change/{change_id}/tasks",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let server = create_resource_project(temp_dir.path());
+
+        let mission = server.do_read_resource(&SpoxResource::Mission).unwrap();
+        let spec = server
+            .do_read_resource(&SpoxResource::Spec("auth".to_string()))
+            .unwrap();
+        let proposal = server
+            .do_read_resource(&SpoxResource::ChangeProposal("add-2fa".to_string()))
+            .unwrap();
+        let tasks = server
+            .do_read_resource(&SpoxResource::ChangeTasks("add-2fa".to_string()))
+            .unwrap();
+
+        assert_eq!(mission, "# Mission\n\nShip specs.\n");
+        assert_eq!(spec, VALID_SPEC);
+        assert!(proposal.contains("This is a test change."));
+        assert_eq!(tasks, TEST_TASKS);
+    }
+
+    #[test]
+    fn test_read_missing_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let server = create_resource_project(temp_dir.path());
+        fs::remove_file(temp_dir.path().join("specs/_changes/add-2fa/tasks.md")).unwrap();
+
+        let spec_err = server
+            .do_read_resource(&SpoxResource::Spec("payments".to_string()))
+            .unwrap_err();
+        let change_err = server
+            .do_read_resource(&SpoxResource::ChangeProposal("nope".to_string()))
+            .unwrap_err();
+        let tasks_err = server
+            .do_read_resource(&SpoxResource::ChangeTasks("add-2fa".to_string()))
+            .unwrap_err();
+
+        assert!(spec_err.contains("payments"), "{}", spec_err);
+        assert!(change_err.contains("not found"), "{}", change_err);
+        assert!(tasks_err.contains("tasks.md"), "{}", tasks_err);
+    }
 }
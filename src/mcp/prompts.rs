@@ -0,0 +1,246 @@
+//! Workflow prompts for MCP clients.
+//!
+//! The propose, implement, archive and setup workflows are rendered from the
+//! same embedded templates that `spox init` installs as Claude Code slash
+//! commands, so every MCP client gets the same instructions. Rendering drops
+//! the YAML frontmatter, fills in the prompt arguments and refers to tools by
+//! the names this server exposes them under.
+
+use rmcp::model::{
+    GetPromptResult, JsonObject, Prompt, PromptArgument, PromptMessage, PromptMessageRole,
+};
+
+use crate::init::{
+    TEMPLATE_CMD_ARCHIVE_MD, TEMPLATE_CMD_IMPLEMENT_MD, TEMPLATE_CMD_PROPOSE_MD,
+    TEMPLATE_CMD_SETUP_MD,
+};
+
+/// Prefix of the tool names in the slash command templates.
+const CLAUDE_TOOL_PREFIX: &str = "mcp__spox__";
+
+/// Placeholder for the arguments in the slash command templates.
+const ARGUMENTS_PLACEHOLDER: &str = "$ARGUMENTS";
+
+/// A workflow prompt and the template it is rendered from.
+struct WorkflowPrompt {
+    /// The prompt name, also the slash command name.
+    name: &'static str,
+    /// The slash command template.
+    template: &'static str,
+    /// The argument of the prompt, with its description.
+    argument: Option<(&'static str, &'static str)>,
+}
+
+/// All workflow prompts, in lifecycle order.
+const PROMPTS: [WorkflowPrompt; 4] = [
+    WorkflowPrompt {
+        name: "setup",
+        template: TEMPLATE_CMD_SETUP_MD,
+        argument: None,
+    },
+    WorkflowPrompt {
+        name: "propose",
+        template: TEMPLATE_CMD_PROPOSE_MD,
+        argument: Some((
+            "request",
+            "What to change: a rough idea, or the path of a markdown file describing it",
+        )),
+    },
+    WorkflowPrompt {
+        name: "implement",
+        template: TEMPLATE_CMD_IMPLEMENT_MD,
+        argument: Some(("change_id", "ID of the approved change to implement")),
+    },
+    WorkflowPrompt {
+        name: "archive",
+        template: TEMPLATE_CMD_ARCHIVE_MD,
+        argument: Some(("change_id", "ID of the deployed change to archive")),
+    },
+];
+
+/// List the workflow prompts for `prompts/list`.
+pub fn list_prompts() -> Vec<Prompt> {
+    PROMPTS
+        .iter()
+        .map(|prompt| {
+            let (description, _) = split_frontmatter(prompt.template);
+            let arguments = prompt.argument.map(|(name, description)| {
+                vec![PromptArgument {
+                    name: name.to_string(),
+                    description: Some(description.to_string()),
+                    required: Some(false),
+                }]
+            });
+            Prompt::new(prompt.name, description, arguments)
+        })
+        .collect()
+}
+
+/// Render a workflow prompt for `prompts/get`.
+///
+/// # Arguments
+///
+/// * `name` - The prompt name
+/// * `arguments` - The prompt arguments; all are optional strings
+///
+/// # Errors
+///
+/// Returns an error if the prompt is unknown or an argument is not a string.
+pub fn get_prompt(name: &str, arguments: Option<&JsonObject>) -> Result<GetPromptResult, String> {
+    let prompt = PROMPTS
+        .iter()
+        .find(|prompt| prompt.name == name)
+        .ok_or_else(|| format!("Unknown prompt '{}'", name))?;
+
+    let value = match (prompt.argument, arguments) {
+        (Some((argument, _)), Some(arguments)) => match arguments.get(argument) {
+            Some(serde_json::Value::String(value)) => value.trim().to_string(),
+            Some(serde_json::Value::Null) | None => String::new(),
+            Some(_) => return Err(format!("Argument '{}' must be a string", argument)),
+        },
+        _ => String::new(),
+    };
+    let user_input = match prompt.argument {
+        Some(("change_id", _)) if !value.is_empty() => format!("Change ID: `{}`", value),
+        _ => value,
+    };
+
+    let (description, body) = split_frontmatter(prompt.template);
+    Ok(GetPromptResult {
+        description: description.map(str::to_string),
+        messages: vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            render(body, &user_input),
+        )],
+    })
+}
+
+/// Split a slash command template into its frontmatter description and body.
+fn split_frontmatter(template: &str) -> (Option<&str>, &str) {
+    let Some(rest) = template.strip_prefix("---\n") else {
+        return (None, template);
+    };
+    let Some((frontmatter, body)) = rest.split_once("\n---\n") else {
+        return (None, template);
+    };
+
+    let description = frontmatter
+        .lines()
+        .find_map(|line| line.strip_prefix("description:"))
+        .map(|description| description.trim().trim_matches('"'));
+    (description, body.trim_start())
+}
+
+/// Render a template body with the user input.
+///
+/// The input replaces the `$ARGUMENTS` placeholder, or is appended to
+/// templates without one. Tool names lose their Claude Code prefix and slash
+/// commands become references to the prompts.
+fn render(body: &str, user_input: &str) -> String {
+    let mut text = if body.contains(ARGUMENTS_PLACEHOLDER) {
+        if user_input.is_empty() {
+            body.replace(&format!("{}\n\n", ARGUMENTS_PLACEHOLDER), "")
+                .replace(ARGUMENTS_PLACEHOLDER, "")
+        } else {
+            body.replace(ARGUMENTS_PLACEHOLDER, user_input)
+        }
+    } else if user_input.is_empty() {
+        body.to_string()
+    } else {
+        format!("{}\n\n{}", body.trim_end(), user_input)
+    };
+
+    text = text.replace(CLAUDE_TOOL_PREFIX, "");
+    for prompt in &PROMPTS {
+        text = text.replace(
+            &format!("`/spox:{}`", prompt.name),
+            &format!("the `{}` prompt", prompt.name),
+        );
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::PromptMessageContent;
+    use serde_json::json;
+
+    fn text(result: &GetPromptResult) -> &str {
+        match &result.messages[0].content {
+            PromptMessageContent::Text { text } => text,
+            other => panic!("Expected text content, got {:?}", other),
+        }
+    }
+
+    fn arguments(value: serde_json::Value) -> JsonObject {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_list_prompts() {
+        let prompts = list_prompts();
+        let names: Vec<&str> = prompts.iter().map(|p| p.name.as_str()).collect();
+
+        assert_eq!(names, vec!["setup", "propose", "implement", "archive"]);
+        assert_eq!(
+            prompts[1].description.as_deref(),
+            Some("Create a change proposal that locks intent before implementation.")
+        );
+        assert!(prompts[0].arguments.is_none());
+        let arguments = prompts[2].arguments.as_ref().unwrap();
+        assert_eq!(arguments[0].name, "change_id");
+        assert_eq!(arguments[0].required, Some(false));
+    }
+
+    #[test]
+    fn test_get_prompt_strips_frontmatter_and_tool_prefix() {
+        let result = get_prompt("propose", None).unwrap();
+        let text = text(&result);
+
+        assert_eq!(result.messages[0].role, PromptMessageRole::User);
+        assert!(text.starts_with("## Goal"), "{}", text);
+        assert!(!text.contains("category: Spec Oxide"));
+        assert!(!text.contains(ARGUMENTS_PLACEHOLDER));
+        assert!(!text.contains(CLAUDE_TOOL_PREFIX));
+        assert!(text.contains("`search_specs`"));
+    }
+
+    #[test]
+    fn test_get_prompt_fills_in_arguments() {
+        let propose = get_prompt(
+            "propose",
+            Some(&arguments(json!({"request": "Add two-factor login"}))),
+        )
+        .unwrap();
+        let archive =
+            get_prompt("archive", Some(&arguments(json!({"change_id": "add-2fa"})))).unwrap();
+        let implement = get_prompt(
+            "implement",
+            Some(&arguments(json!({"change_id": "add-2fa"}))),
+        )
+        .unwrap();
+
+        assert!(text(&propose).contains("Add two-factor login\n\n## Guardrails"));
+        assert!(text(&archive).contains("Change ID: `add-2fa`\n\n## Guardrails"));
+        assert!(text(&implement).ends_with("Change ID: `add-2fa`"));
+    }
+
+    #[test]
+    fn test_get_setup_prompt_refers_to_propose_prompt() {
+        let result = get_prompt("setup", None).unwrap();
+
+        assert!(text(&result).contains("the `propose` prompt"));
+        assert!(!text(&result).contains("/spox:"));
+    }
+
+    #[test]
+    fn test_get_prompt_errors() {
+        let unknown = get_prompt("vibe", None).unwrap_err();
+        let not_a_string =
+            get_prompt("archive", Some(&arguments(json!({"change_id": 42})))).unwrap_err();
+
+        assert!(unknown.contains("vibe"), "{}", unknown);
+        assert!(not_a_string.contains("change_id"), "{}", not_a_string);
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::path::Path;
 
+use crate::cli::output::OutputFormat;
 use crate::config::Config;
 use crate::error::{Error, Result};
 
@@ -13,9 +14,16 @@ use crate::error::{Error, Result};
 /// # Errors
 ///
 /// Returns an error if:
+/// - `--format json` is given; stdout carries the MCP protocol
 /// - Configuration cannot be loaded
 /// - The MCP server fails to start
-pub fn serve() -> Result<()> {
+pub fn serve(format: OutputFormat) -> Result<()> {
+    if format.is_json() {
+        return Err(Error::Other(
+            "spox mcp serve cannot be combined with --format json".to_string(),
+        ));
+    }
+
     // Load configuration
     let config = Config::load(Path::new(".spox/config.toml"))?;
 
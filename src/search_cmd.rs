@@ -0,0 +1,192 @@
+//! Command handler for `spox search`.
+//!
+//! Searches specs and changes with `core::index`, building the search index
+//! first if it is missing and updating it if it is out of date.
+
+use std::path::Path;
+
+use crate::cli::output::{print_json, OutputFormat};
+use crate::cli::render::{cyan_bold, dim};
+use crate::config::Config;
+use crate::core::embedding::LazyEmbedder;
+use crate::core::index::{ensure_index, search, IndexFolders, SearchFilter, SearchMode};
+use crate::error::{Error, Result};
+use crate::mcp::{SearchResultItem, SearchSpecsResponse};
+
+/// Format search results for display.
+///
+/// # Arguments
+/// * `results` - The ranked search results
+///
+/// # Returns
+/// A formatted string ready for terminal display, colored when stdout is a TTY.
+///
+/// # Example Output
+/// ```text
+/// 1. config / Archive Folder  (1.00)
+///    The archive_folder setting SHALL name the folder for archived changes.
+/// 2. change add-archive-cmd  (0.42)
+///    Archiving is manual today...
+/// ```
+/// Or if empty: `No results found.`
+fn format_search_results(results: &[SearchResultItem]) -> String {
+    if results.is_empty() {
+        return "No results found.".to_string();
+    }
+
+    let mut output = String::new();
+    for (i, result) in results.iter().enumerate() {
+        output.push_str(&format!(
+            "{}. {}  {}\n   {}\n",
+            i + 1,
+            cyan_bold(&format_location(result)),
+            dim(&format!("({:.2})", result.score)),
+            result.snippet
+        ));
+    }
+
+    // Remove trailing newline
+    output.trim_end().to_string()
+}
+
+/// Format where a search result matched, e.g. `auth / Login / Success` for a
+/// scenario or `change add-2fa: auth / Login` for a delta requirement.
+fn format_location(result: &SearchResultItem) -> String {
+    let mut path = vec![result.spec_id.as_str()];
+    path.extend(result.requirement.as_deref());
+    path.extend(result.scenario.as_deref());
+    path.retain(|part| !part.is_empty());
+    let path = path.join(" / ");
+
+    match &result.change_id {
+        Some(change_id) if path.is_empty() => format!("{} {}", result.kind, change_id),
+        Some(change_id) => format!("{} {}: {}", result.kind, change_id, path),
+        None => path,
+    }
+}
+
+/// Run the `search` command.
+///
+/// With JSON output, prints a `SearchSpecsResponse`.
+///
+/// # Arguments
+/// * `query` - The search query
+/// * `top_k` - The maximum number of results
+/// * `spec_ids` - Only show results of these specs (all if empty)
+/// * `mode` - Rank by embeddings, keywords, or both
+/// * `format` - Output format
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be loaded, or the index cannot
+/// be built or searched.
+pub fn run(
+    query: &str,
+    top_k: usize,
+    spec_ids: Vec<String>,
+    mode: SearchMode,
+    format: OutputFormat,
+) -> Result<()> {
+    let config = Config::load(Path::new(".spox/config.toml"))?;
+    let project_root = Path::new(".");
+    let embedder = LazyEmbedder::from_config(&config, project_root);
+    let folders = IndexFolders::from_config(project_root, &config);
+    let filter = SearchFilter {
+        spec_ids,
+        ..Default::default()
+    };
+
+    let (index, freshness) = ensure_index(project_root, &folders, &embedder)
+        .map_err(|e| Error::Other(format!("Failed to ensure index: {}", e)))?;
+    let page = search(&index, query, 0, top_k, mode, &filter, &embedder)
+        .map_err(|e| Error::Other(format!("Search failed: {}", e)))?;
+    let response = SearchSpecsResponse::from_page(page, &freshness);
+
+    if format.is_json() {
+        return print_json(&response);
+    }
+
+    if response.index_built == Some(true) {
+        eprintln!("Built search index");
+    }
+    if response.index_updated == Some(true) {
+        eprintln!("Updated search index");
+    }
+    if let Some(warning) = &response.warning {
+        eprintln!("Warning: {}", warning);
+    }
+    println!("{}", format_search_results(&response.results));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(
+        spec_id: &str,
+        requirement: Option<&str>,
+        score: f32,
+        snippet: &str,
+    ) -> SearchResultItem {
+        SearchResultItem {
+            kind: if requirement.is_some() {
+                "requirement"
+            } else {
+                "spec"
+            }
+            .to_string(),
+            spec_id: spec_id.to_string(),
+            requirement: requirement.map(str::to_string),
+            scenario: None,
+            change_id: None,
+            score,
+            snippet: snippet.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_format_search_results_empty() {
+        assert_eq!(format_search_results(&[]), "No results found.");
+    }
+
+    #[test]
+    fn test_format_search_results_ranks_and_locates() {
+        let results = vec![
+            item(
+                "config",
+                Some("Archive Folder"),
+                1.0,
+                "The archive_folder setting.",
+            ),
+            item("config", None, 0.4231, "[Config] Config"),
+        ];
+
+        let output = format_search_results(&results);
+
+        assert_eq!(
+            output,
+            "1. config / Archive Folder  (1.00)\n   The archive_folder setting.\n\
+             2. config  (0.42)\n   [Config] Config"
+        );
+    }
+
+    #[test]
+    fn test_format_location_of_scenarios_and_changes() {
+        let mut scenario = item("auth", Some("Login"), 0.5, "WHEN ...");
+        scenario.kind = "scenario".to_string();
+        scenario.scenario = Some("Success".to_string());
+        assert_eq!(format_location(&scenario), "auth / Login / Success");
+
+        let mut proposal = item("", None, 0.5, "Why");
+        proposal.kind = "change".to_string();
+        proposal.change_id = Some("add-2fa".to_string());
+        assert_eq!(format_location(&proposal), "change add-2fa");
+
+        let mut delta = item("auth", Some("Login"), 0.5, "The system SHALL");
+        delta.kind = "change".to_string();
+        delta.change_id = Some("add-2fa".to_string());
+        assert_eq!(format_location(&delta), "change add-2fa: auth / Login");
+    }
+}
@@ -6,59 +6,37 @@
 use std::fs;
 use std::path::Path;
 
+pub use crate::core::markdown::DeltaOp;
+use crate::core::markdown::Document;
+use crate::mcp::{DeltaGroupMcp, DeltaItemMcp, GetChangeResponse, ProposalContent, TasksContent};
+
 use super::{blue, box_header, cyan_bold, dim, green, progress_bar, red, yellow};
 
 /// Default box width for headers.
 const BOX_WIDTH: usize = 61;
 
-/// Delta operation type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum DeltaOp {
-    Added,
-    Modified,
-    Removed,
-    Renamed,
-}
+/// Sections `get_change` can return on their own.
+const CHANGE_SECTIONS: [&str; 4] = ["proposal", "tasks", "design", "deltas"];
 
 impl DeltaOp {
-    /// Get the symbol for this operation.
-    pub fn symbol(&self) -> &'static str {
+    /// Format text with the color of this operation.
+    pub fn colored(&self, text: &str) -> String {
         match self {
-            DeltaOp::Added => "+",
-            DeltaOp::Modified => "~",
-            DeltaOp::Removed => "-",
-            DeltaOp::Renamed => ">",
-        }
-    }
-
-    /// Get the label for this operation.
-    pub fn label(&self) -> &'static str {
-        match self {
-            DeltaOp::Added => "ADDED",
-            DeltaOp::Modified => "MODIFIED",
-            DeltaOp::Removed => "REMOVED",
-            DeltaOp::Renamed => "RENAMED",
+            DeltaOp::Added => green(text),
+            DeltaOp::Modified => yellow(text),
+            DeltaOp::Removed => red(text),
+            DeltaOp::Renamed => blue(text),
         }
     }
 
     /// Format the symbol with appropriate color.
     pub fn colored_symbol(&self) -> String {
-        match self {
-            DeltaOp::Added => green(self.symbol()),
-            DeltaOp::Modified => yellow(self.symbol()),
-            DeltaOp::Removed => red(self.symbol()),
-            DeltaOp::Renamed => blue(self.symbol()),
-        }
+        self.colored(self.symbol())
     }
 
     /// Format the label with appropriate color.
     pub fn colored_label(&self) -> String {
-        match self {
-            DeltaOp::Added => green(self.label()),
-            DeltaOp::Modified => yellow(self.label()),
-            DeltaOp::Removed => red(self.label()),
-            DeltaOp::Renamed => blue(self.label()),
-        }
+        self.colored(self.label())
     }
 }
 
@@ -73,6 +51,8 @@ pub struct DeltaItem {
     pub text: String,
     /// List of scenario names.
     pub scenarios: Vec<String>,
+    /// The full requirement block as written in the delta spec (header included).
+    pub content: String,
 }
 
 /// A group of delta items for a single capability.
@@ -169,45 +149,13 @@ pub fn parse_change(change_dir: &Path) -> Result<ChangeInfo, String> {
 
 /// Parse proposal.md to extract Why and What Changes sections.
 fn parse_proposal(content: &str) -> (String, String) {
-    let why = extract_section(content, "Why");
-    let what_changes = extract_section(content, "What Changes");
-    (why, what_changes)
-}
-
-/// Extract a `## <header>` section from markdown content.
-fn extract_section(content: &str, header: &str) -> String {
-    let lines: Vec<&str> = content.lines().collect();
-    let target = format!("## {}", header);
-
-    let mut in_section = false;
-    let mut section_lines = Vec::new();
-
-    for line in lines {
-        let trimmed = line.trim();
-
-        if trimmed.eq_ignore_ascii_case(&target) {
-            in_section = true;
-            continue;
-        }
-
-        if in_section {
-            // Stop at next ## header (but not ### or deeper)
-            if trimmed.starts_with("## ") && !trimmed.starts_with("### ") {
-                break;
-            }
-            section_lines.push(line);
-        }
-    }
-
-    // Trim leading/trailing empty lines and join
-    let result: Vec<&str> = section_lines
-        .iter()
-        .copied()
-        .skip_while(|l| l.trim().is_empty())
-        .collect();
-
-    let result: String = result.join("\n");
-    result.trim_end().to_string()
+    let doc = Document::parse(content);
+    let section_text = |name: &str| {
+        doc.section_named(name)
+            .map(|section| section.text.clone())
+            .unwrap_or_default()
+    };
+    (section_text("Why"), section_text("What Changes"))
 }
 
 /// Parse tasks.md to count completed and total tasks.
@@ -235,7 +183,13 @@ fn parse_tasks(content: &str) -> (usize, usize) {
 }
 
 /// Parse delta specs from the specs/ subdirectory.
-fn parse_delta_specs(specs_dir: &Path) -> Result<Vec<DeltaGroup>, String> {
+///
+/// # Arguments
+/// * `specs_dir` - Path to the `specs/` directory of a change
+///
+/// # Returns
+/// Delta groups sorted by capability name, or an error message.
+pub fn parse_delta_specs(specs_dir: &Path) -> Result<Vec<DeltaGroup>, String> {
     let mut groups = Vec::new();
 
     let entries =
@@ -270,158 +224,28 @@ fn parse_delta_specs(specs_dir: &Path) -> Result<Vec<DeltaGroup>, String> {
 }
 
 /// Parse delta content from a spec.md file.
-fn parse_delta_content(content: &str) -> Vec<DeltaItem> {
-    let mut items = Vec::new();
-
-    // Find delta operation headers and their requirements
-    let delta_headers = [
-        ("## ADDED Requirements", DeltaOp::Added),
-        ("## MODIFIED Requirements", DeltaOp::Modified),
-        ("## REMOVED Requirements", DeltaOp::Removed),
-        ("## RENAMED Requirements", DeltaOp::Renamed),
-    ];
-
-    let lines: Vec<&str> = content.lines().collect();
-
-    for (header_text, op) in delta_headers {
-        // Find the header (case-insensitive)
-        let header_idx = lines
-            .iter()
-            .position(|line| line.trim().eq_ignore_ascii_case(header_text));
-
-        if let Some(start_idx) = header_idx {
-            // Extract section until next ## header
-            let section_content = extract_section_from_index(&lines, start_idx);
-            let requirements = parse_requirements(&section_content, op);
-            items.extend(requirements);
-        }
-    }
-
-    items
-}
-
-/// Extract section content from a line index until next ## header.
-fn extract_section_from_index(lines: &[&str], start_idx: usize) -> String {
-    let mut section_lines = Vec::new();
-
-    for line in lines.iter().skip(start_idx + 1) {
-        let trimmed = line.trim();
-        // Stop at next ## header (but not ### or deeper)
-        if trimmed.starts_with("## ") && !trimmed.starts_with("### ") {
-            break;
-        }
-        section_lines.push(*line);
-    }
-
-    section_lines.join("\n")
-}
-
-/// Parse requirements from a delta section.
-fn parse_requirements(content: &str, op: DeltaOp) -> Vec<DeltaItem> {
+///
+/// Items are grouped by operation (ADDED, MODIFIED, REMOVED, RENAMED), in
+/// document order within each operation.
+pub fn parse_delta_content(content: &str) -> Vec<DeltaItem> {
+    let doc = Document::parse(content);
     let mut items = Vec::new();
-    let lines: Vec<&str> = content.lines().collect();
-
-    let mut idx = 0;
-    while idx < lines.len() {
-        let line = lines[idx];
-        let trimmed = line.trim();
-
-        if trimmed.starts_with("### Requirement:") {
-            let name = trimmed
-                .strip_prefix("### Requirement:")
-                .unwrap_or("")
-                .trim()
-                .to_string();
-
-            // Collect requirement content until next ### or ## header
-            let mut req_lines = Vec::new();
-            idx += 1;
-
-            while idx < lines.len() {
-                let subsequent_line = lines[idx];
-                let subsequent_trimmed = subsequent_line.trim();
-
-                if subsequent_trimmed.starts_with("### ")
-                    || (subsequent_trimmed.starts_with("## ")
-                        && !subsequent_trimmed.starts_with("### "))
-                {
-                    break;
-                }
-                req_lines.push(subsequent_line);
-                idx += 1;
-            }
-
-            let req_content = req_lines.join("\n");
-
-            // Extract the requirement text (first non-empty paragraph)
-            let text = extract_requirement_text(&req_content);
-
-            // Extract scenario names
-            let scenarios = extract_scenario_names(&req_content);
 
-            items.push(DeltaItem {
+    for op in DeltaOp::ALL {
+        for (_, section) in doc.delta_sections().filter(|(o, _)| *o == op) {
+            items.extend(section.requirements.iter().map(|req| DeltaItem {
                 operation: op,
-                name,
-                text,
-                scenarios,
-            });
-        } else {
-            idx += 1;
+                name: req.name.clone(),
+                text: req.description.clone(),
+                scenarios: req.scenarios.iter().map(|s| s.name.clone()).collect(),
+                content: req.content.clone(),
+            }));
         }
     }
 
     items
 }
 
-/// Extract the main text of a requirement (first non-empty lines before scenarios).
-fn extract_requirement_text(content: &str) -> String {
-    let mut text_lines = Vec::new();
-
-    for line in content.lines() {
-        let trimmed = line.trim();
-
-        // Stop at scenario headers
-        if trimmed.starts_with("#### Scenario:") {
-            break;
-        }
-
-        // Skip empty lines at the start
-        if text_lines.is_empty() && trimmed.is_empty() {
-            continue;
-        }
-
-        // Stop at empty line after collecting text
-        if !text_lines.is_empty() && trimmed.is_empty() {
-            break;
-        }
-
-        text_lines.push(trimmed);
-    }
-
-    text_lines.join(" ")
-}
-
-/// Extract scenario names from requirement content.
-fn extract_scenario_names(content: &str) -> Vec<String> {
-    let mut scenarios = Vec::new();
-
-    for line in content.lines() {
-        let trimmed = line.trim();
-        if trimmed.starts_with("#### Scenario:") {
-            let name = trimmed
-                .strip_prefix("#### Scenario:")
-                .unwrap_or("")
-                .trim()
-                .to_string();
-            if !name.is_empty() {
-                scenarios.push(name);
-            }
-        }
-    }
-
-    scenarios
-}
-
 /// Format change info for full display.
 ///
 /// # Arguments
@@ -458,8 +282,7 @@ pub fn format_change(change: &ChangeInfo) -> String {
     output.push('\n');
 
     // Tasks progress
-    if change.tasks_total > 0 {
-        let percentage = (change.tasks_completed * 100) / change.tasks_total;
+    if let Some(percentage) = (change.tasks_completed * 100).checked_div(change.tasks_total) {
         let bar = progress_bar(change.tasks_completed, change.tasks_total);
         output.push_str(&format!(
             "Tasks: {} {}/{} ({}%)\n\n",
@@ -565,6 +388,101 @@ pub fn show_change_deltas_only(change_dir: &Path) -> Result<String, String> {
     Ok(format_deltas_only(&change))
 }
 
+/// Get a change, or one section of it, as returned by `get_change`.
+///
+/// # Arguments
+/// * `changes_path` - Path to the changes folder
+/// * `change_id` - The change ID (folder name)
+/// * `section` - `proposal`, `tasks`, `design` or `deltas`; `None` returns all of them
+///
+/// # Returns
+/// `Ok(GetChangeResponse)` with the requested sections, or `Err` with error message.
+pub fn get_change(
+    changes_path: &Path,
+    change_id: &str,
+    section: Option<&str>,
+) -> Result<GetChangeResponse, String> {
+    let change_path = changes_path.join(change_id);
+
+    if !change_path.exists() {
+        return Err(format!("Change '{}' not found", change_id));
+    }
+
+    if let Some(unknown) = section.filter(|s| !CHANGE_SECTIONS.contains(s)) {
+        return Err(format!(
+            "Unknown section '{}'. Valid sections: proposal, tasks, design, deltas",
+            unknown
+        ));
+    }
+    let wanted = |name: &str| section.is_none_or(|s| s == name);
+
+    let info = parse_change(&change_path)
+        .map_err(|e| format!("Failed to parse change '{}': {}", change_id, e))?;
+
+    let proposal = wanted("proposal").then_some(ProposalContent {
+        why: info.why,
+        what_changes: info.what_changes,
+    });
+
+    let tasks = if wanted("tasks") {
+        let tasks_path = change_path.join("tasks.md");
+        let content = if tasks_path.exists() {
+            fs::read_to_string(&tasks_path)
+                .map_err(|e| format!("Failed to read tasks.md: {}", e))?
+        } else {
+            String::new()
+        };
+        Some(TasksContent {
+            content,
+            completed: info.tasks_completed,
+            total: info.tasks_total,
+        })
+    } else {
+        None
+    };
+
+    let design_path = change_path.join("design.md");
+    let design = if wanted("design") && design_path.exists() {
+        Some(
+            fs::read_to_string(&design_path)
+                .map_err(|e| format!("Failed to read design.md: {}", e))?,
+        )
+    } else {
+        None
+    };
+
+    let deltas = wanted("deltas").then(|| {
+        info.deltas
+            .into_iter()
+            .map(|g| DeltaGroupMcp {
+                capability: g.capability,
+                items: g
+                    .items
+                    .into_iter()
+                    .map(|i| DeltaItemMcp {
+                        operation: match i.operation {
+                            DeltaOp::Added => "added".to_string(),
+                            DeltaOp::Modified => "modified".to_string(),
+                            DeltaOp::Removed => "removed".to_string(),
+                            DeltaOp::Renamed => "renamed".to_string(),
+                        },
+                        name: i.name,
+                        scenarios: i.scenarios,
+                    })
+                    .collect(),
+            })
+            .collect()
+    });
+
+    Ok(GetChangeResponse {
+        change_id: change_id.to_string(),
+        proposal,
+        tasks,
+        design,
+        deltas,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1003,6 +921,7 @@ The system SHALL support the new feature.
                     name: "New Feature".to_string(),
                     text: "Description".to_string(),
                     scenarios: vec!["Test".to_string()],
+                    content: String::new(),
                 }],
             }],
         };
@@ -1047,12 +966,14 @@ The system SHALL support the new feature.
                         name: "Two-Factor Auth".to_string(),
                         text: "The system SHALL support 2FA.".to_string(),
                         scenarios: vec!["TOTP setup".to_string(), "TOTP verify".to_string()],
+                        content: String::new(),
                     },
                     DeltaItem {
                         operation: DeltaOp::Modified,
                         name: "User Login".to_string(),
                         text: "The system SHALL require 2FA.".to_string(),
                         scenarios: vec!["Valid credentials with 2FA".to_string()],
+                        content: String::new(),
                     },
                 ],
             }],
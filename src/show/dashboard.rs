@@ -7,6 +7,10 @@ use std::fs;
 use std::path::Path;
 
 use crate::config::Config;
+use crate::core::approval::{approval_status, ApprovalStatus};
+use crate::core::markdown::{DeltaOp, Document};
+use crate::core::spec::parse_all_specs;
+use crate::mcp::{self, ChangeSummaryMcp, ListChangesResponse, ListSpecsResponse, TaskProgress};
 
 // Import color utilities from parent module
 use super::{centered_box_header, colored_progress_bar, dim, green, red, yellow, HEADER_WIDTH};
@@ -31,6 +35,8 @@ pub struct ChangeSummary {
     pub tasks_total: usize,
     /// Summary of deltas (e.g., "auth (+1, ~1), notifications (+1)").
     pub delta_summary: String,
+    /// Approval state of the change.
+    pub approval: ApprovalStatus,
 }
 
 /// Dashboard information containing specs and changes.
@@ -114,17 +120,18 @@ pub fn gather_specs(spec_folder: &str) -> Result<Vec<SpecSummary>, String> {
     Ok(specs)
 }
 
-/// Count requirements in a spec file by counting `### Requirement:` headers.
+/// Count the `### Requirement:` blocks in a spec file.
 fn count_requirements(spec_path: &Path) -> usize {
     let content = match fs::read_to_string(spec_path) {
         Ok(c) => c,
         Err(_) => return 0,
     };
 
-    content
-        .lines()
-        .filter(|line| line.trim().starts_with("### Requirement:"))
-        .count()
+    Document::parse(&content)
+        .sections
+        .iter()
+        .map(|section| section.requirements.len())
+        .sum()
 }
 
 /// Gather all active changes with task progress.
@@ -181,11 +188,14 @@ pub fn gather_changes(changes_folder: &str) -> Result<Vec<ChangeSummary>, String
             String::new()
         };
 
+        let approval = approval_status(&path);
+
         changes.push(ChangeSummary {
             name,
             tasks_completed,
             tasks_total,
             delta_summary,
+            approval,
         });
     }
 
@@ -230,6 +240,13 @@ struct DeltaCounts {
     added: usize,
     modified: usize,
     removed: usize,
+    renamed: usize,
+}
+
+impl DeltaCounts {
+    fn is_empty(&self) -> bool {
+        self.added + self.modified + self.removed + self.renamed == 0
+    }
 }
 
 /// Gather delta summary from specs directory.
@@ -253,7 +270,7 @@ fn gather_delta_summary(specs_path: &Path) -> String {
             let spec_file = path.join("spec.md");
             if spec_file.exists() {
                 let counts = count_delta_operations(&spec_file);
-                if counts.added > 0 || counts.modified > 0 || counts.removed > 0 {
+                if !counts.is_empty() {
                     capability_deltas.push((capability_name, counts));
                 }
             }
@@ -263,7 +280,7 @@ fn gather_delta_summary(specs_path: &Path) -> String {
     // Sort by capability name
     capability_deltas.sort_by(|a, b| a.0.cmp(&b.0));
 
-    // Format as "capability (+N, ~N, -N), ..."
+    // Format as "capability (+N, ~N, -N, >N), ..."
     let parts: Vec<String> = capability_deltas
         .iter()
         .map(|(name, counts)| format_delta_counts(name, counts))
@@ -280,34 +297,14 @@ fn count_delta_operations(spec_path: &Path) -> DeltaCounts {
     };
 
     let mut counts = DeltaCounts::default();
-    let mut current_section: Option<&str> = None;
 
-    for line in content.lines() {
-        let trimmed = line.trim();
-        let upper = trimmed.to_uppercase();
-
-        // Detect section headers
-        if upper == "## ADDED REQUIREMENTS" {
-            current_section = Some("added");
-        } else if upper == "## MODIFIED REQUIREMENTS" {
-            current_section = Some("modified");
-        } else if upper == "## REMOVED REQUIREMENTS" {
-            current_section = Some("removed");
-        } else if upper == "## RENAMED REQUIREMENTS" {
-            // RENAMED counts as modified for summary purposes
-            current_section = Some("renamed");
-        } else if trimmed.starts_with("## ") && !trimmed.starts_with("### ") {
-            current_section = None;
-        }
-
-        // Count requirements in current section
-        if trimmed.starts_with("### Requirement:") {
-            match current_section {
-                Some("added") => counts.added += 1,
-                Some("modified") | Some("renamed") => counts.modified += 1,
-                Some("removed") => counts.removed += 1,
-                _ => {}
-            }
+    for (op, section) in Document::parse(&content).delta_sections() {
+        let count = section.requirements.len();
+        match op {
+            DeltaOp::Added => counts.added += count,
+            DeltaOp::Modified => counts.modified += count,
+            DeltaOp::Removed => counts.removed += count,
+            DeltaOp::Renamed => counts.renamed += count,
         }
     }
 
@@ -316,17 +313,16 @@ fn count_delta_operations(spec_path: &Path) -> DeltaCounts {
 
 /// Format delta counts for a capability.
 fn format_delta_counts(name: &str, counts: &DeltaCounts) -> String {
-    let mut parts = Vec::new();
-
-    if counts.added > 0 {
-        parts.push(format!("+{}", counts.added));
-    }
-    if counts.modified > 0 {
-        parts.push(format!("~{}", counts.modified));
-    }
-    if counts.removed > 0 {
-        parts.push(format!("-{}", counts.removed));
-    }
+    let parts: Vec<String> = [
+        (DeltaOp::Added, counts.added),
+        (DeltaOp::Modified, counts.modified),
+        (DeltaOp::Removed, counts.removed),
+        (DeltaOp::Renamed, counts.renamed),
+    ]
+    .iter()
+    .filter(|(_, count)| *count > 0)
+    .map(|(op, count)| format!("{}{}", op.symbol(), count))
+    .collect();
 
     if parts.is_empty() {
         name.to_string()
@@ -387,11 +383,18 @@ pub fn format_dashboard(info: &DashboardInfo) -> String {
             let bar = colored_progress_bar(change.tasks_completed, change.tasks_total);
             let tasks_label = format!("{}/{} tasks", change.tasks_completed, change.tasks_total);
 
+            let approval = match change.approval {
+                ApprovalStatus::Unapproved => String::new(),
+                ApprovalStatus::Approved => format!("  {}", green(change.approval.label())),
+                ApprovalStatus::Stale => format!("  {}", red(change.approval.label())),
+            };
+
             output.push_str(&format!(
-                "  {:<22} {} {}\n",
+                "  {:<22} {} {}{}\n",
                 yellow(&change.name),
                 bar,
-                dim(&tasks_label)
+                dim(&tasks_label),
+                approval
             ));
 
             // Delta summary (if present)
@@ -405,47 +408,30 @@ pub fn format_dashboard(info: &DashboardInfo) -> String {
     output
 }
 
-/// Format delta summary with colors for +/~/-
+/// Format delta summary with colors for +/~/-/>
 fn format_colored_delta_summary(delta_summary: &str) -> String {
     let mut result = String::new();
     let mut chars = delta_summary.chars().peekable();
 
     while let Some(c) = chars.next() {
-        if c == '+' {
-            // Color the + and following digits green
-            let mut num = String::from("+");
-            while let Some(&next) = chars.peek() {
-                if next.is_ascii_digit() {
-                    num.push(chars.next().unwrap());
-                } else {
-                    break;
-                }
-            }
-            result.push_str(&green(&num));
-        } else if c == '~' {
-            // Color the ~ and following digits yellow
-            let mut num = String::from("~");
-            while let Some(&next) = chars.peek() {
-                if next.is_ascii_digit() {
-                    num.push(chars.next().unwrap());
-                } else {
-                    break;
+        let op = DeltaOp::ALL
+            .into_iter()
+            .find(|op| op.symbol().starts_with(c));
+
+        // Color an operation symbol and its count (only if followed by a digit)
+        match op {
+            Some(op) if chars.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                let mut num = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_ascii_digit() {
+                        num.push(chars.next().unwrap());
+                    } else {
+                        break;
+                    }
                 }
+                result.push_str(&op.colored(&num));
             }
-            result.push_str(&yellow(&num));
-        } else if c == '-' && chars.peek().is_some_and(|c| c.is_ascii_digit()) {
-            // Color the - and following digits red (only if followed by digit)
-            let mut num = String::from("-");
-            while let Some(&next) = chars.peek() {
-                if next.is_ascii_digit() {
-                    num.push(chars.next().unwrap());
-                } else {
-                    break;
-                }
-            }
-            result.push_str(&red(&num));
-        } else {
-            result.push(c);
+            _ => result.push(c),
         }
     }
 
@@ -464,6 +450,54 @@ pub fn show_dashboard(config: &Config) -> Result<String, String> {
     Ok(format_dashboard(&info))
 }
 
+/// List all specs with their titles and purposes, as returned by `list_specs`.
+///
+/// # Arguments
+/// * `specs_path` - Path to the specs folder
+///
+/// # Returns
+/// `Ok(ListSpecsResponse)` with one entry per spec, or `Err` with error message.
+pub fn list_specs(specs_path: &Path) -> Result<ListSpecsResponse, String> {
+    let specs = parse_all_specs(specs_path).map_err(|e| format!("Failed to parse specs: {}", e))?;
+
+    let summaries = specs
+        .into_iter()
+        .map(|s| mcp::SpecSummary {
+            id: s.id,
+            title: s.title,
+            purpose: s.purpose,
+        })
+        .collect();
+
+    Ok(ListSpecsResponse { specs: summaries })
+}
+
+/// List all active changes with their task progress, as returned by `list_changes`.
+///
+/// # Arguments
+/// * `changes_path` - Path to the changes folder
+///
+/// # Returns
+/// `Ok(ListChangesResponse)` with one entry per change, or `Err` with error message.
+pub fn list_changes(changes_path: &Path) -> Result<ListChangesResponse, String> {
+    let changes = gather_changes(changes_path.to_str().unwrap_or(""))
+        .map_err(|e| format!("Failed to list changes: {}", e))?;
+
+    let summaries = changes
+        .into_iter()
+        .map(|c| ChangeSummaryMcp {
+            id: c.name.clone(),
+            title: c.name.replace('-', " "),
+            task_progress: TaskProgress {
+                completed: c.tasks_completed,
+                total: c.tasks_total,
+            },
+        })
+        .collect();
+
+    Ok(ListChangesResponse { changes: summaries })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -476,6 +510,7 @@ mod tests {
             added: 2,
             modified: 1,
             removed: 1,
+            renamed: 0,
         };
         let result = format_delta_counts("auth", &counts);
         assert_eq!(result, "auth (+2, ~1, -1)");
@@ -487,6 +522,7 @@ mod tests {
             added: 1,
             modified: 0,
             removed: 0,
+            renamed: 0,
         };
         let result = format_delta_counts("notifications", &counts);
         assert_eq!(result, "notifications (+1)");
@@ -498,6 +534,7 @@ mod tests {
             added: 0,
             modified: 2,
             removed: 0,
+            renamed: 0,
         };
         let result = format_delta_counts("config", &counts);
         assert_eq!(result, "config (~2)");
@@ -509,6 +546,7 @@ mod tests {
             added: 0,
             modified: 0,
             removed: 3,
+            renamed: 0,
         };
         let result = format_delta_counts("legacy", &counts);
         assert_eq!(result, "legacy (-3)");
@@ -569,6 +607,7 @@ mod tests {
                 tasks_completed: 3,
                 tasks_total: 5,
                 delta_summary: "auth (+1, ~1)".to_string(),
+                approval: ApprovalStatus::Unapproved,
             }],
         };
 
@@ -605,12 +644,32 @@ mod tests {
                 tasks_completed: 0,
                 tasks_total: 0,
                 delta_summary: String::new(),
+                approval: ApprovalStatus::Unapproved,
             }],
         };
 
         let output = format_dashboard(&info);
         assert!(output.contains("empty-change"));
         assert!(output.contains("0/0 tasks"));
+        assert!(!output.contains("approved"));
+    }
+
+    #[test]
+    fn test_format_dashboard_shows_approval_state() {
+        let info = DashboardInfo {
+            specs: vec![],
+            changes: vec![ChangeSummary {
+                name: "add-2fa".to_string(),
+                tasks_completed: 1,
+                tasks_total: 2,
+                delta_summary: String::new(),
+                approval: ApprovalStatus::Approved,
+            }],
+        };
+
+        let output = format_dashboard(&info);
+        assert!(output.contains("1/2 tasks"));
+        assert!(output.contains("approved"));
     }
 
     #[test]
@@ -669,39 +728,30 @@ The system SHALL improve the existing feature.
 ### Requirement: Old Feature
 "#;
 
-        // Manual parsing for test
-        let mut added = 0;
-        let mut modified = 0;
-        let mut removed = 0;
-        let mut current_section: Option<&str> = None;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let spec_path = temp_dir.path().join("spec.md");
+        fs::write(&spec_path, content).unwrap();
 
-        for line in content.lines() {
-            let trimmed = line.trim();
-            let upper = trimmed.to_uppercase();
-
-            if upper == "## ADDED REQUIREMENTS" {
-                current_section = Some("added");
-            } else if upper == "## MODIFIED REQUIREMENTS" {
-                current_section = Some("modified");
-            } else if upper == "## REMOVED REQUIREMENTS" {
-                current_section = Some("removed");
-            } else if trimmed.starts_with("## ") && !trimmed.starts_with("### ") {
-                current_section = None;
-            }
+        let counts = count_delta_operations(&spec_path);
 
-            if trimmed.starts_with("### Requirement:") {
-                match current_section {
-                    Some("added") => added += 1,
-                    Some("modified") => modified += 1,
-                    Some("removed") => removed += 1,
-                    _ => {}
-                }
-            }
-        }
+        assert_eq!(counts.added, 2);
+        assert_eq!(counts.modified, 1);
+        assert_eq!(counts.removed, 1);
+        assert_eq!(counts.renamed, 0);
+    }
+
+    #[test]
+    fn test_count_delta_operations_counts_renamed_separately() {
+        let content = "## MODIFIED Requirements\n\n### Requirement: Login\nThe system SHALL log in.\n\n## RENAMED Requirements\n\n### Requirement: Logout -> Sign Out\n";
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let spec_path = temp_dir.path().join("spec.md");
+        fs::write(&spec_path, content).unwrap();
+
+        let counts = count_delta_operations(&spec_path);
 
-        assert_eq!(added, 2);
-        assert_eq!(modified, 1);
-        assert_eq!(removed, 1);
+        assert_eq!(counts.modified, 1);
+        assert_eq!(counts.renamed, 1);
+        assert_eq!(format_delta_counts("auth", &counts), "auth (~1, >1)");
     }
 
     #[test]
@@ -730,12 +780,14 @@ The system SHALL improve the existing feature.
             tasks_completed: 2,
             tasks_total: 5,
             delta_summary: "auth (+1)".to_string(),
+            approval: ApprovalStatus::Unapproved,
         };
         let c2 = ChangeSummary {
             name: "add-feature".to_string(),
             tasks_completed: 2,
             tasks_total: 5,
             delta_summary: "auth (+1)".to_string(),
+            approval: ApprovalStatus::Unapproved,
         };
 
         assert_eq!(c1, c2);
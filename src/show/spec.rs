@@ -5,6 +5,8 @@
 use std::fs;
 use std::path::Path;
 
+use crate::core::markdown::{Document, SectionKind};
+
 use super::{box_header, cyan_bold, dim, green, is_tty, yellow};
 
 /// Default box width for headers.
@@ -54,16 +56,35 @@ pub fn parse_spec(path: &Path) -> Result<SpecInfo, String> {
 /// # Returns
 /// A `SpecInfo` struct or an error string.
 pub fn parse_spec_content(content: &str) -> Result<SpecInfo, String> {
-    let lines: Vec<&str> = content.lines().collect();
+    let doc = Document::parse(content);
 
     // Extract spec name from header (# <name> Specification)
-    let name = extract_spec_name(&lines)?;
-
-    // Extract purpose section
-    let purpose = extract_purpose(&lines)?;
+    let header = doc
+        .title
+        .as_ref()
+        .map(|heading| heading.text.as_str())
+        .ok_or_else(|| "Missing spec header (expected '# <name> Specification')".to_string())?;
+    let name = header
+        .strip_suffix(" Specification")
+        .or_else(|| header.strip_suffix(" specification"))
+        .unwrap_or(header)
+        .trim()
+        .to_string();
 
-    // Extract requirements
-    let requirements = extract_requirements(&lines);
+    let purpose = doc
+        .section(SectionKind::Purpose)
+        .map(|section| section.paragraph())
+        .ok_or_else(|| "Missing Purpose section".to_string())?;
+
+    let requirements = doc
+        .spec_requirements()
+        .iter()
+        .map(|req| RequirementInfo {
+            name: req.name.clone(),
+            text: req.description.clone(),
+            scenarios: req.scenarios.iter().map(|s| s.name.clone()).collect(),
+        })
+        .collect();
 
     Ok(SpecInfo {
         name,
@@ -72,153 +93,6 @@ pub fn parse_spec_content(content: &str) -> Result<SpecInfo, String> {
     })
 }
 
-/// Extract the spec name from the first header line.
-fn extract_spec_name(lines: &[&str]) -> Result<String, String> {
-    for line in lines {
-        let trimmed = line.trim();
-        if trimmed.starts_with("# ") {
-            // Extract name from "# <name> Specification" or just "# <name>"
-            let header = trimmed.strip_prefix("# ").unwrap_or("");
-            let name = header
-                .strip_suffix(" Specification")
-                .or_else(|| header.strip_suffix(" specification"))
-                .unwrap_or(header)
-                .trim()
-                .to_string();
-            return Ok(name);
-        }
-    }
-    Err("Missing spec header (expected '# <name> Specification')".to_string())
-}
-
-/// Extract the purpose section content.
-fn extract_purpose(lines: &[&str]) -> Result<String, String> {
-    let mut in_purpose = false;
-    let mut purpose_lines = Vec::new();
-
-    for line in lines {
-        let trimmed = line.trim();
-
-        // Check for Purpose header (case-insensitive)
-        if trimmed.eq_ignore_ascii_case("## Purpose") {
-            in_purpose = true;
-            continue;
-        }
-
-        // Stop at next level-2 header
-        if in_purpose && trimmed.starts_with("## ") {
-            break;
-        }
-
-        if in_purpose {
-            purpose_lines.push(*line);
-        }
-    }
-
-    if purpose_lines.is_empty() {
-        return Err("Missing Purpose section".to_string());
-    }
-
-    // Trim and join purpose lines
-    let purpose = purpose_lines
-        .into_iter()
-        .map(|l| l.trim())
-        .collect::<Vec<_>>()
-        .join(" ")
-        .trim()
-        .to_string();
-
-    Ok(purpose)
-}
-
-/// Extract all requirements from the spec content.
-fn extract_requirements(lines: &[&str]) -> Vec<RequirementInfo> {
-    let mut requirements = Vec::new();
-    let mut current_req: Option<RequirementInfo> = None;
-    let mut in_requirements = false;
-    let mut in_scenario = false;
-
-    for line in lines {
-        let trimmed = line.trim();
-
-        // Check for Requirements section
-        if trimmed.eq_ignore_ascii_case("## Requirements") {
-            in_requirements = true;
-            continue;
-        }
-
-        // Stop at next level-2 header (outside Requirements)
-        if in_requirements
-            && trimmed.starts_with("## ")
-            && !trimmed.eq_ignore_ascii_case("## Requirements")
-        {
-            // Save current requirement if any
-            if let Some(req) = current_req.take() {
-                requirements.push(req);
-            }
-            break;
-        }
-
-        if !in_requirements {
-            continue;
-        }
-
-        // Check for requirement header (### Requirement: <name>)
-        if trimmed.starts_with("### Requirement:") {
-            // Save previous requirement
-            if let Some(req) = current_req.take() {
-                requirements.push(req);
-            }
-
-            let name = trimmed
-                .strip_prefix("### Requirement:")
-                .unwrap_or("")
-                .trim()
-                .to_string();
-
-            current_req = Some(RequirementInfo {
-                name,
-                text: String::new(),
-                scenarios: Vec::new(),
-            });
-            in_scenario = false;
-            continue;
-        }
-
-        // Check for scenario header (#### Scenario: <name>)
-        if trimmed.starts_with("#### Scenario:") {
-            let scenario_name = trimmed
-                .strip_prefix("#### Scenario:")
-                .unwrap_or("")
-                .trim()
-                .to_string();
-
-            if let Some(ref mut req) = current_req {
-                req.scenarios.push(scenario_name);
-            }
-            in_scenario = true;
-            continue;
-        }
-
-        // Accumulate requirement text (before scenarios)
-        if current_req.is_some() && !in_scenario && !trimmed.is_empty() {
-            if let Some(ref mut req) = current_req {
-                if !req.text.is_empty() {
-                    req.text.push(' ');
-                }
-                req.text.push_str(trimmed);
-            }
-        }
-    }
-
-    // Save last requirement
-    if let Some(req) = current_req.take() {
-        requirements.push(req);
-    }
-
-    requirements
-}
-
 /// Format spec info for display (with colors if TTY).
 ///
 /// # Arguments
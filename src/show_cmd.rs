@@ -6,47 +6,99 @@
 use std::env;
 use std::path::Path;
 
+use serde::Serialize;
+
+use crate::cli::output::{print_json, OutputFormat};
 use crate::config::Config;
+use crate::core::spec::get_spec_by_id;
 use crate::error::{Error, Result};
+use crate::mcp::{ChangeSummaryMcp, SpecSummary};
 use crate::show::{change, dashboard, project, spec};
+use crate::watch::{self, ProjectWatcher};
+
+/// JSON output of the `show` command.
+#[derive(Debug, Serialize)]
+struct DashboardResponse {
+    /// All specs, as returned by `list_specs`.
+    specs: Vec<SpecSummary>,
+    /// All active changes, as returned by `list_changes`.
+    changes: Vec<ChangeSummaryMcp>,
+}
 
 /// Run the top-level `show` command to display the project dashboard.
 ///
 /// Displays project info (root, versions, status) followed by the
 /// specs and changes dashboard.
 ///
+/// # Arguments
+/// * `format` - Output format; JSON prints all specs and changes.
+/// * `watch` - Keep running and redraw the dashboard when files change.
+///
 /// # Returns
 /// Returns `Ok(())` on success, or an error if config cannot be loaded
 /// or dashboard cannot be generated.
-pub fn run_dashboard() -> Result<()> {
+pub fn run_dashboard(format: OutputFormat, watch: bool) -> Result<()> {
     let config_path = Path::new(".spox/config.toml");
-    let config = Config::load(config_path)?;
+    let mut config = Config::load(config_path)?;
+
+    if format.is_json() {
+        if watch {
+            return Err(Error::Other(
+                "--watch cannot be combined with --format json".to_string(),
+            ));
+        }
+        let specs = dashboard::list_specs(Path::new(config.spec_folder()))
+            .map_err(Error::Other)?
+            .specs;
+        let changes = dashboard::list_changes(Path::new(config.changes_folder()))
+            .map_err(Error::Other)?
+            .changes;
+        return print_json(&DashboardResponse { specs, changes });
+    }
 
     // Get absolute project root path
     let project_root = env::current_dir()
         .map_err(|e| Error::Other(format!("Failed to get current directory: {}", e)))?;
 
-    // Gather and display project info
-    let project_info = project::gather_project_info(&project_root, &config);
+    if !watch {
+        println!("{}", render_dashboard(&project_root, &config)?);
+        return Ok(());
+    }
+
+    let mut watcher = ProjectWatcher::new(&config)?;
+    loop {
+        watch::clear_screen();
+        // A half-written file must not end watch mode
+        match render_dashboard(&project_root, &config) {
+            Ok(output) => println!("{}", output),
+            Err(e) => eprintln!("error: {}", e),
+        }
+        watch::print_footer();
+
+        watch::wait_for_changes(&mut watcher, &mut config)?;
+    }
+}
+
+/// Render the project info followed by the specs and changes dashboard.
+fn render_dashboard(project_root: &Path, config: &Config) -> Result<String> {
+    let project_info = project::gather_project_info(project_root, config);
     let project_output = project::format_project_info(&project_info);
 
-    // Get dashboard output
-    let dashboard_output = dashboard::show_dashboard(&config).map_err(Error::Other)?;
+    let dashboard_output = dashboard::show_dashboard(config).map_err(Error::Other)?;
 
-    println!("{}", project_output);
-    println!("{}", dashboard_output);
-    Ok(())
+    Ok(format!("{}\n{}", project_output, dashboard_output))
 }
 
 /// Run the `spec show` command.
 ///
 /// # Arguments
 /// * `id` - Optional spec ID to show. If None, returns an error.
+/// * `format` - Output format; JSON prints the parsed spec.
 ///
 /// # Returns
 /// Returns `Ok(())` on success, or an error if the spec is not found
 /// or cannot be parsed.
-pub fn run_spec_show(id: Option<String>) -> Result<()> {
+pub fn run_spec_show(id: Option<String>, format: OutputFormat) -> Result<()> {
     let config_path = Path::new(".spox/config.toml");
     let config = Config::load(config_path)?;
 
@@ -60,6 +112,12 @@ pub fn run_spec_show(id: Option<String>) -> Result<()> {
                 return Err(Error::Other(format!("Spec '{}' not found", spec_id)));
             }
 
+            if format.is_json() {
+                let parsed = get_spec_by_id(Path::new(config.spec_folder()), &spec_id)
+                    .map_err(|e| Error::Other(e.to_string()))?;
+                return print_json(&parsed);
+            }
+
             let output = spec::show_spec(&spec_path).map_err(Error::Other)?;
             println!("{}", output);
         }
@@ -77,11 +135,12 @@ pub fn run_spec_show(id: Option<String>) -> Result<()> {
 /// # Arguments
 /// * `id` - Optional change ID to show. If None, returns an error.
 /// * `deltas_only` - If true, only show delta requirements.
+/// * `format` - Output format; JSON prints a `GetChangeResponse`.
 ///
 /// # Returns
 /// Returns `Ok(())` on success, or an error if the change is not found
 /// or cannot be parsed.
-pub fn run_change_show(id: Option<String>, deltas_only: bool) -> Result<()> {
+pub fn run_change_show(id: Option<String>, deltas_only: bool, format: OutputFormat) -> Result<()> {
     let config_path = Path::new(".spox/config.toml");
     let config = Config::load(config_path)?;
 
@@ -93,6 +152,14 @@ pub fn run_change_show(id: Option<String>, deltas_only: bool) -> Result<()> {
                 return Err(Error::Other(format!("Change '{}' not found", change_id)));
             }
 
+            if format.is_json() {
+                let section = deltas_only.then_some("deltas");
+                let response =
+                    change::get_change(Path::new(config.changes_folder()), &change_id, section)
+                        .map_err(Error::Other)?;
+                return print_json(&response);
+            }
+
             let output = if deltas_only {
                 change::show_change_deltas_only(&change_path)
             } else {
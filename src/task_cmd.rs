@@ -0,0 +1,100 @@
+//! Command handler for `spox task`.
+//!
+//! Checks off tasks in the `tasks.md` of an active change, rewriting only the
+//! checkbox of the task.
+
+use std::path::Path;
+
+use crate::cli::output::{print_json, OutputFormat};
+use crate::config::Config;
+use crate::core::change::validate_name;
+use crate::error::{Error, Result};
+use crate::mcp::{SetTaskStatusResponse, TaskProgress};
+use crate::validate::tasks::set_task_status;
+
+/// Format the result of checking off a task.
+///
+/// # Example Output
+/// ```text
+/// Marked task 1.2 of add-2fa as done (2/3 tasks completed)
+/// ```
+fn format_task_status(response: &SetTaskStatusResponse) -> String {
+    format!(
+        "Marked task {} of {} as {} ({}/{} tasks completed)",
+        response.task,
+        response.change_id,
+        if response.done { "done" } else { "not done" },
+        response.task_progress.completed,
+        response.task_progress.total
+    )
+}
+
+/// Run the `task done` command.
+///
+/// With JSON output, prints a `SetTaskStatusResponse`.
+///
+/// # Arguments
+/// * `change_id` - The ID of the active change
+/// * `number` - The task number, e.g. `1.2.1`
+/// * `format` - Output format (text or JSON)
+///
+/// # Errors
+/// Returns an error if the configuration cannot be loaded, the change or its
+/// tasks.md doesn't exist, or no single task has the given number.
+pub fn run_done(change_id: &str, number: &str, format: OutputFormat) -> Result<()> {
+    let config = Config::load(Path::new(".spox/config.toml"))?;
+    validate_name("change ID", change_id).map_err(|e| Error::Other(e.to_string()))?;
+
+    let change_dir = Path::new(config.changes_folder()).join(change_id);
+    if !change_dir.is_dir() {
+        return Err(Error::Other(format!("Change '{}' not found", change_id)));
+    }
+    let tasks_path = change_dir.join("tasks.md");
+    if !tasks_path.is_file() {
+        return Err(Error::Other(format!(
+            "Change '{}' has no tasks.md",
+            change_id
+        )));
+    }
+
+    let stats = set_task_status(&tasks_path, number, true)?;
+    let response = SetTaskStatusResponse {
+        change_id: change_id.to_string(),
+        task: number.trim().to_string(),
+        done: true,
+        task_progress: TaskProgress {
+            completed: stats.completed,
+            total: stats.total,
+        },
+    };
+
+    if format.is_json() {
+        return print_json(&response);
+    }
+    println!("{}", format_task_status(&response));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_task_status() {
+        let response = SetTaskStatusResponse {
+            change_id: "add-2fa".to_string(),
+            task: "1.2".to_string(),
+            done: true,
+            task_progress: TaskProgress {
+                completed: 2,
+                total: 3,
+            },
+        };
+
+        assert_eq!(
+            format_task_status(&response),
+            "Marked task 1.2 of add-2fa as done (2/3 tasks completed)"
+        );
+    }
+}
@@ -6,34 +6,88 @@
 use std::fs;
 use std::path::Path;
 
-use super::parser::{extract_scenarios, has_then_clause, has_when_clause};
+use crate::core::approval::{changed_since_approval, load_approval, APPROVAL_FILE};
+use crate::core::archive::parse_rename;
+use crate::core::conflict::find_conflicts;
+use crate::core::markdown::{DeltaOp, Document, Requirement};
+
 use super::tasks::{validate_tasks, TaskStats};
 use super::ValidationReport;
 
 /// Minimum character length for Why section to avoid warnings.
 const MIN_WHY_LENGTH: usize = 50;
 
-/// Valid delta operation headers.
-const DELTA_HEADERS: &[&str] = &[
-    "## ADDED Requirements",
-    "## MODIFIED Requirements",
-    "## REMOVED Requirements",
-    "## RENAMED Requirements",
-];
-
 /// Result of change validation including task statistics.
 #[derive(Debug)]
 pub struct ChangeValidationResult {
     /// The validation report with all issues found.
     pub report: ValidationReport,
     /// Task statistics (if tasks.md was found and validated).
-    #[allow(dead_code)] // Reserved for future CLI display of task progress
     pub task_stats: Option<TaskStats>,
 }
 
-/// Validate a change directory at the given path.
+/// Validate a change directory and cross-check its deltas against the current specs.
+///
+/// Runs the structural checks of [`validate_change`], then reports an error when a
+/// delta does not fit the spec it targets:
+///
+/// - A MODIFIED or REMOVED requirement does not exist in the target spec
+/// - An ADDED requirement already exists
+/// - A RENAMED source does not exist or its new name is already taken
+/// - The target capability has no spec and is not declared under
+///   `New capabilities:` in the proposal's Impact section
+///
+/// It also warns when another active change in the same changes folder touches
+/// a requirement this change touches.
+///
+/// # Arguments
+/// * `change_dir` - Path to the change directory
+/// * `spec_folder` - Path to the specs folder holding `<capability>/spec.md`
+///
+/// # Returns
+/// A `ChangeValidationResult` containing the validation report and task statistics.
+pub fn validate_change_against_specs(
+    change_dir: &Path,
+    spec_folder: &Path,
+) -> ChangeValidationResult {
+    let mut result = validate_change(change_dir);
+
+    if change_dir.is_dir() {
+        validate_delta_targets(change_dir, spec_folder, &mut result.report);
+        validate_no_conflicts(change_dir, &mut result.report);
+    }
+
+    result
+}
+
+/// Validate a change against the specs without checking its approval record.
+///
+/// Runs the same checks as [`validate_change_against_specs`] except that
+/// `approval.toml` is ignored, so a change can be validated before it is
+/// (re-)approved.
+///
+/// # Arguments
+/// * `change_dir` - Path to the change directory
+/// * `spec_folder` - Path to the specs folder holding `<capability>/spec.md`
+///
+/// # Returns
+/// A `ChangeValidationResult` containing the validation report and task statistics.
+pub fn validate_change_content(change_dir: &Path, spec_folder: &Path) -> ChangeValidationResult {
+    let mut result = validate_change_structure(change_dir);
+
+    if change_dir.is_dir() {
+        validate_delta_targets(change_dir, spec_folder, &mut result.report);
+        validate_no_conflicts(change_dir, &mut result.report);
+    }
+
+    result
+}
+
+/// Validate the structure of a change directory at the given path.
 ///
 /// The path should be the change directory (e.g., specs/_changes/add-feature/).
+/// Deltas are not compared with the specs they target; use
+/// [`validate_change_against_specs`] for that.
 ///
 /// # Arguments
 /// * `change_dir` - Path to the change directory
@@ -41,6 +95,18 @@ pub struct ChangeValidationResult {
 /// # Returns
 /// A `ChangeValidationResult` containing the validation report and task statistics.
 pub fn validate_change(change_dir: &Path) -> ChangeValidationResult {
+    let mut result = validate_change_structure(change_dir);
+
+    // Approved files must not be edited after approval
+    if change_dir.is_dir() {
+        validate_approval(change_dir, &mut result.report);
+    }
+
+    result
+}
+
+/// Validate the proposal, tasks and delta specs of a change directory.
+fn validate_change_structure(change_dir: &Path) -> ChangeValidationResult {
     let mut report = ValidationReport::new();
     let dir_path = change_dir.to_string_lossy().to_string();
 
@@ -158,62 +224,355 @@ pub fn validate_change(change_dir: &Path) -> ChangeValidationResult {
     ChangeValidationResult { report, task_stats }
 }
 
-/// Validate proposal.md content.
-fn validate_proposal(content: &str, file_path: &str, report: &mut ValidationReport) {
-    let lines: Vec<&str> = content.lines().collect();
+/// Validate proposal.md content that is not (or not yet) saved to disk.
+///
+/// # Arguments
+/// * `content` - The content of the proposal.md file
+/// * `file_path` - The file path to use in validation messages
+///
+/// # Returns
+/// A `ValidationReport` containing all validation issues found.
+pub fn validate_proposal_content(content: &str, file_path: &str) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    validate_proposal(content, file_path, &mut report);
+    report
+}
 
-    // Check for Why section
-    if let Some((why_line, _)) = find_section(&lines, "Why") {
-        let why_text = extract_section_text(&lines, why_line);
-        if why_text.len() < MIN_WHY_LENGTH {
+/// Validate the content of a single delta spec of a change.
+///
+/// Runs the structural delta checks and cross-checks the delta against the spec
+/// of its capability, like [`validate_change_against_specs`] does for every delta
+/// spec on disk. New capabilities are read from the change's proposal.md.
+///
+/// # Arguments
+/// * `content` - The content of the delta spec
+/// * `capability` - The capability the delta spec targets
+/// * `change_dir` - Path to the change directory
+/// * `spec_folder` - Path to the specs folder holding `<capability>/spec.md`
+///
+/// # Returns
+/// A `ValidationReport` with issues reported on `specs/<capability>/spec.md`.
+pub fn validate_delta_content(
+    content: &str,
+    capability: &str,
+    change_dir: &Path,
+    spec_folder: &Path,
+) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    let file_path = format!("specs/{}/spec.md", capability);
+
+    check_delta_content(content, &file_path, &mut report);
+    check_delta_target(
+        content,
+        capability,
+        spec_folder,
+        &read_new_capabilities(change_dir),
+        &file_path,
+        &mut report,
+    );
+
+    report
+}
+
+/// Validate that approved files still match the approval record, if present.
+fn validate_approval(change_dir: &Path, report: &mut ValidationReport) {
+    let approval = match load_approval(change_dir) {
+        Ok(Some(approval)) => approval,
+        Ok(None) => return,
+        Err(e) => {
+            report.add_error(APPROVAL_FILE, None, &e.to_string());
+            return;
+        }
+    };
+
+    match changed_since_approval(change_dir, &approval) {
+        Ok(changed) => {
+            for file in changed {
+                report.add_error(
+                    &file,
+                    None,
+                    &format!(
+                        "Changed after approval by {} at {}; approve the change again",
+                        approval.approver, approval.approved_at
+                    ),
+                );
+            }
+        }
+        Err(e) => report.add_error(APPROVAL_FILE, None, &e.to_string()),
+    }
+}
+
+/// Check every delta spec of a change against the spec of its capability.
+fn validate_delta_targets(change_dir: &Path, spec_folder: &Path, report: &mut ValidationReport) {
+    let new_capabilities = read_new_capabilities(change_dir);
+
+    let Ok(entries) = fs::read_dir(change_dir.join("specs")) else {
+        return;
+    };
+    let mut capabilities: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().join("spec.md").is_file())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    capabilities.sort();
+
+    for capability in capabilities {
+        let file_path = format!("specs/{}/spec.md", capability);
+        let Ok(delta) = fs::read_to_string(change_dir.join(&file_path)) else {
+            continue; // Reported by the structural checks
+        };
+
+        check_delta_target(
+            &delta,
+            &capability,
+            spec_folder,
+            &new_capabilities,
+            &file_path,
+            report,
+        );
+    }
+}
+
+/// Check a single delta spec against the spec of its capability.
+fn check_delta_target(
+    delta: &str,
+    capability: &str,
+    spec_folder: &Path,
+    new_capabilities: &[String],
+    file_path: &str,
+    report: &mut ValidationReport,
+) {
+    let target_path = spec_folder.join(capability).join("spec.md");
+    let mut names: Vec<String> = if target_path.exists() {
+        match fs::read_to_string(&target_path) {
+            Ok(content) => Document::parse(&content)
+                .spec_requirements()
+                .iter()
+                .map(|r| r.name.clone())
+                .collect(),
+            Err(e) => {
+                report.add_error(
+                    file_path,
+                    None,
+                    &format!(
+                        "Failed to read target spec {}: {}",
+                        target_path.display(),
+                        e
+                    ),
+                );
+                return;
+            }
+        }
+    } else if new_capabilities.iter().any(|c| c == capability) {
+        Vec::new()
+    } else {
+        report.add_error(
+            file_path,
+            None,
+            &format!(
+                "Capability \"{}\" has no spec at {}; list it under \"New capabilities:\" in the proposal's Impact section if it is new",
+                capability,
+                target_path.display()
+            ),
+        );
+        return;
+    };
+
+    check_delta_against_target(delta, capability, &mut names, file_path, report);
+}
+
+/// Capabilities declared as new in the proposal of a change, if it can be read.
+fn read_new_capabilities(change_dir: &Path) -> Vec<String> {
+    fs::read_to_string(change_dir.join("proposal.md"))
+        .map(|content| declared_new_capabilities(&content))
+        .unwrap_or_default()
+}
+
+/// Warn about requirements that other active changes touch as well.
+fn validate_no_conflicts(change_dir: &Path, report: &mut ValidationReport) {
+    let (Some(changes_folder), Some(change_id)) = (change_dir.parent(), change_dir.file_name())
+    else {
+        return;
+    };
+    let change_id = change_id.to_string_lossy();
+
+    let conflicts = match find_conflicts(changes_folder) {
+        Ok(conflicts) => conflicts,
+        Err(e) => {
             report.add_warning(
+                "specs/",
+                None,
+                &format!("Failed to check for conflicts: {}", e),
+            );
+            return;
+        }
+    };
+
+    for conflict in conflicts.iter().filter(|c| c.involves(&change_id)) {
+        let others: Vec<String> = conflict
+            .touches
+            .iter()
+            .filter(|t| t.change_id != change_id)
+            .map(|t| format!("{} ({})", t.change_id, t.operation.label()))
+            .collect();
+        report.add_warning(
+            &format!("specs/{}/spec.md", conflict.capability),
+            None,
+            &format!(
+                "Requirement \"{}\" is also changed by {}; archiving both overwrites one of the changes",
+                conflict.requirement,
+                others.join(", ")
+            ),
+        );
+    }
+}
+
+/// Replay the operations of a delta on the requirement names of its target spec.
+///
+/// Operations are checked in the order the archive applies them (RENAMED,
+/// REMOVED, MODIFIED, ADDED), so a MODIFIED delta may refer to a new name.
+fn check_delta_against_target(
+    delta: &str,
+    capability: &str,
+    names: &mut Vec<String>,
+    file_path: &str,
+    report: &mut ValidationReport,
+) {
+    let doc = Document::parse(delta);
+    let items = |op: DeltaOp| -> Vec<&Requirement> {
+        doc.delta_sections()
+            .filter(|(o, _)| *o == op)
+            .flat_map(|(_, section)| &section.requirements)
+            .collect()
+    };
+    let exists = |names: &[String], name: &str| names.iter().any(|n| n == name.trim());
+
+    for req in items(DeltaOp::Renamed) {
+        let line = Some(req.span.start.line);
+        let Some((from, to)) = parse_rename(&req.name) else {
+            report.add_error(
                 file_path,
-                Some(why_line + 1),
+                line,
                 &format!(
-                    "Why section is too short ({} chars, minimum {} recommended)",
-                    why_text.len(),
-                    MIN_WHY_LENGTH
+                    "RENAMED requirement \"{}\" must use the form \"Old Name -> New Name\"",
+                    req.name
+                ),
+            );
+            continue;
+        };
+
+        if !exists(names, from) {
+            report.add_error(
+                file_path,
+                line,
+                &format!(
+                    "RENAMED requirement \"{}\" does not exist in spec \"{}\"",
+                    from, capability
+                ),
+            );
+        } else if exists(names, to) {
+            report.add_error(
+                file_path,
+                line,
+                &format!(
+                    "Cannot rename \"{}\": requirement \"{}\" already exists in spec \"{}\"",
+                    from, to, capability
                 ),
             );
+        } else {
+            names.retain(|n| n != from);
+            names.push(to.to_string());
         }
-    } else {
-        report.add_error(file_path, Some(1), "Missing Why section");
     }
 
-    // Check for What Changes section
-    if find_section(&lines, "What Changes").is_none() {
-        report.add_error(file_path, Some(1), "Missing What Changes section");
+    for op in [DeltaOp::Removed, DeltaOp::Modified] {
+        for req in items(op) {
+            if !exists(names, &req.name) {
+                report.add_error(
+                    file_path,
+                    Some(req.span.start.line),
+                    &format!(
+                        "{} requirement \"{}\" does not exist in spec \"{}\"",
+                        op.label(),
+                        req.name,
+                        capability
+                    ),
+                );
+            } else if op == DeltaOp::Removed {
+                names.retain(|n| n != req.name.trim());
+            }
+        }
     }
-}
 
-/// Find a `## <header>` section and return its line index (0-indexed) and content.
-fn find_section(lines: &[&str], header: &str) -> Option<(usize, String)> {
-    let target = format!("## {}", header);
-
-    for (i, line) in lines.iter().enumerate() {
-        let trimmed = line.trim();
-        if trimmed.eq_ignore_ascii_case(&target) || trimmed == target {
-            return Some((i, header.to_string()));
+    for req in items(DeltaOp::Added) {
+        if exists(names, &req.name) {
+            report.add_error(
+                file_path,
+                Some(req.span.start.line),
+                &format!(
+                    "ADDED requirement \"{}\" already exists in spec \"{}\"",
+                    req.name, capability
+                ),
+            );
+        } else {
+            names.push(req.name.trim().to_string());
         }
     }
-    None
 }
 
-/// Extract the text content of a section (from header to next same-level header).
-fn extract_section_text(lines: &[&str], start_line: usize) -> String {
-    let mut content = String::new();
+/// Capabilities declared as new in the proposal's Impact section.
+///
+/// Reads a `- New capabilities: a, b` line; backticks and bold markers are ignored.
+fn declared_new_capabilities(proposal: &str) -> Vec<String> {
+    let doc = Document::parse(proposal);
+    let Some(impact) = doc.section_named("Impact") else {
+        return Vec::new();
+    };
+
+    impact
+        .text
+        .lines()
+        .map(|line| line.trim().trim_start_matches(['-', '*']).trim())
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim_matches(|c: char| c == '*' || c.is_whitespace())
+                .eq_ignore_ascii_case("New capabilities")
+                .then_some(value)
+        })
+        .flat_map(|value| value.split(','))
+        .map(|name| {
+            name.trim_matches(|c: char| c == '`' || c == '*' || c.is_whitespace())
+                .to_string()
+        })
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Validate proposal.md content.
+fn validate_proposal(content: &str, file_path: &str, report: &mut ValidationReport) {
+    let doc = Document::parse(content);
 
-    for line in lines.iter().skip(start_line + 1) {
-        let trimmed = line.trim();
-        // Stop at next ## header (but not ### or deeper)
-        if trimmed.starts_with("## ") && !trimmed.starts_with("### ") {
-            break;
+    // Check for Why section
+    if let Some(why) = doc.section_named("Why") {
+        if why.text.len() < MIN_WHY_LENGTH {
+            report.add_warning(
+                file_path,
+                Some(why.heading.span.start.line),
+                &format!(
+                    "Why section is too short ({} chars, minimum {} recommended)",
+                    why.text.len(),
+                    MIN_WHY_LENGTH
+                ),
+            );
         }
-        content.push_str(trimmed);
-        content.push('\n');
+    } else {
+        report.add_error(file_path, Some(1), "Missing Why section");
     }
 
-    content.trim().to_string()
+    // Check for What Changes section
+    if doc.section_named("What Changes").is_none() {
+        report.add_error(file_path, Some(1), "Missing What Changes section");
+    }
 }
 
 /// Validate a delta spec file and return the count of delta operations found.
@@ -236,45 +595,34 @@ fn validate_delta_spec(spec_path: &Path, spec_dir: &Path, report: &mut Validatio
         }
     };
 
-    let lines: Vec<&str> = content.lines().collect();
-    let mut delta_count = 0;
+    check_delta_content(&content, &file_path, report)
+}
 
-    // Find all delta operation headers
-    for (line_idx, line) in lines.iter().enumerate() {
-        let trimmed = line.trim();
+/// Validate delta spec content and return the count of delta operations found.
+fn check_delta_content(content: &str, file_path: &str, report: &mut ValidationReport) -> usize {
+    let doc = Document::parse(content);
+    let mut delta_count = 0;
 
-        for delta_header in DELTA_HEADERS {
-            if trimmed.eq_ignore_ascii_case(delta_header) {
-                delta_count += 1;
+    for (op, section) in doc.delta_sections() {
+        delta_count += 1;
 
-                // For ADDED and MODIFIED requirements, validate they have proper structure
-                if trimmed.to_uppercase().contains("ADDED")
-                    || trimmed.to_uppercase().contains("MODIFIED")
-                {
-                    validate_delta_requirements(&lines, line_idx, trimmed, &file_path, report);
-                }
-            }
+        // For ADDED and MODIFIED requirements, validate they have proper structure
+        if matches!(op, DeltaOp::Added | DeltaOp::Modified) {
+            validate_delta_requirements(op, &section.requirements, file_path, report);
         }
     }
 
     // Check if there's at least one valid delta header
     if delta_count == 0 {
         // Check if there are any header-like patterns that might be malformed
-        let has_invalid_headers = lines.iter().any(|line| {
-            let upper = line.to_uppercase();
-            (upper.contains("ADDED")
-                || upper.contains("MODIFIED")
-                || upper.contains("REMOVED")
-                || upper.contains("RENAMED"))
-                && line.trim().starts_with("##")
-                && !DELTA_HEADERS
-                    .iter()
-                    .any(|h| line.trim().eq_ignore_ascii_case(h))
+        let has_invalid_headers = doc.headings.iter().any(|heading| {
+            let upper = heading.text.to_uppercase();
+            heading.level >= 2 && DeltaOp::ALL.iter().any(|op| upper.contains(op.label()))
         });
 
         if has_invalid_headers {
             report.add_error(
-                &file_path,
+                file_path,
                 None,
                 "Delta spec has invalid headers. Use: ## ADDED Requirements, ## MODIFIED Requirements, ## REMOVED Requirements, or ## RENAMED Requirements",
             );
@@ -286,150 +634,70 @@ fn validate_delta_spec(spec_path: &Path, spec_dir: &Path, report: &mut Validatio
 
 /// Validate requirements within a delta section (ADDED or MODIFIED).
 fn validate_delta_requirements(
-    lines: &[&str],
-    section_start: usize,
-    section_header: &str,
+    op: DeltaOp,
+    requirements: &[Requirement],
     file_path: &str,
     report: &mut ValidationReport,
 ) {
-    // Extract the section content
-    let section_content = extract_delta_section(lines, section_start);
-
-    // Find all requirements in this section
-    let requirements = find_delta_requirements(&section_content);
-
-    let is_added = section_header.to_uppercase().contains("ADDED");
-    let is_modified = section_header.to_uppercase().contains("MODIFIED");
-
-    for (req_line_offset, req_name, req_content) in requirements {
-        let absolute_line = section_start + req_line_offset + 1; // +1 for 1-indexed
+    for req in requirements {
+        let line = Some(req.span.start.line);
 
         // For ADDED requirements, check they have scenarios
-        if is_added {
-            let scenarios = extract_scenarios(&req_content);
-            if scenarios.is_empty() {
+        if op == DeltaOp::Added {
+            if req.scenarios.is_empty() {
                 report.add_warning(
                     file_path,
-                    Some(absolute_line),
+                    line,
                     &format!(
                         "ADDED requirement \"{}\" should have at least one scenario",
-                        req_name
+                        req.name
                     ),
                 );
-            } else {
-                // Validate each scenario has WHEN/THEN
-                for (scenario_offset, scenario_name, scenario_content) in &scenarios {
-                    let scenario_line = absolute_line + scenario_offset;
-
-                    if !has_when_clause(scenario_content) {
-                        report.add_error(
-                            file_path,
-                            Some(scenario_line),
-                            &format!(
-                                "Scenario \"{}\" in requirement \"{}\" is missing WHEN clause",
-                                scenario_name, req_name
-                            ),
-                        );
-                    }
-
-                    if !has_then_clause(scenario_content) {
-                        report.add_error(
-                            file_path,
-                            Some(scenario_line),
-                            &format!(
-                                "Scenario \"{}\" in requirement \"{}\" is missing THEN clause",
-                                scenario_name, req_name
-                            ),
-                        );
-                    }
-                }
             }
-        }
 
-        // For MODIFIED requirements, check they have full text
-        if is_modified {
-            // Check if requirement has substantive content (not just the header)
-            let content_lines: Vec<&str> = req_content
-                .lines()
-                .skip(1) // Skip the requirement header
-                .filter(|l| !l.trim().is_empty() && !l.trim().starts_with("####"))
-                .collect();
-
-            if content_lines.is_empty() {
-                report.add_warning(
-                    file_path,
-                    Some(absolute_line),
-                    &format!(
-                        "MODIFIED requirement \"{}\" should include the complete requirement text",
-                        req_name
-                    ),
-                );
-            }
-        }
-    }
-}
-
-/// Extract a delta section from start to next ## header.
-fn extract_delta_section(lines: &[&str], start_line: usize) -> String {
-    let mut section_lines = Vec::new();
-
-    for line in lines.iter().skip(start_line + 1) {
-        let trimmed = line.trim();
-        // Stop at next ## header (but not ### or deeper)
-        if trimmed.starts_with("## ") && !trimmed.starts_with("### ") {
-            break;
-        }
-        section_lines.push(*line);
-    }
-
-    section_lines.join("\n")
-}
+            // Validate each scenario has WHEN/THEN
+            for scenario in &req.scenarios {
+                let scenario_line = Some(scenario.span.start.line);
+
+                if !scenario.has_when() {
+                    report.add_error(
+                        file_path,
+                        scenario_line,
+                        &format!(
+                            "Scenario \"{}\" in requirement \"{}\" is missing WHEN clause",
+                            scenario.name, req.name
+                        ),
+                    );
+                }
 
-/// Find all `### Requirement:` blocks within delta section content.
-/// Returns: Vec<(line_offset, name, content)>
-fn find_delta_requirements(content: &str) -> Vec<(usize, String, String)> {
-    let lines: Vec<&str> = content.lines().collect();
-    let mut results = Vec::new();
-
-    let mut idx = 0;
-    while idx < lines.len() {
-        let line = lines[idx];
-        let trimmed = line.trim();
-
-        if trimmed.starts_with("### Requirement:") {
-            let line_offset = idx;
-            let name = trimmed
-                .strip_prefix("### Requirement:")
-                .unwrap_or("")
-                .trim()
-                .to_string();
-
-            // Collect content until next ### header or ## header
-            let mut section_lines = vec![line];
-            idx += 1;
-
-            while idx < lines.len() {
-                let subsequent_line = lines[idx];
-                let subsequent_trimmed = subsequent_line.trim();
-
-                // Stop at next ### or ## header
-                if subsequent_trimmed.starts_with("### ")
-                    || (subsequent_trimmed.starts_with("## ")
-                        && !subsequent_trimmed.starts_with("### "))
-                {
-                    break;
+                if !scenario.has_then() {
+                    report.add_error(
+                        file_path,
+                        scenario_line,
+                        &format!(
+                            "Scenario \"{}\" in requirement \"{}\" is missing THEN clause",
+                            scenario.name, req.name
+                        ),
+                    );
                 }
-                section_lines.push(subsequent_line);
-                idx += 1;
             }
+        }
 
-            results.push((line_offset, name, section_lines.join("\n")));
-        } else {
-            idx += 1;
+        // For MODIFIED requirements, check they have full text
+        if op == DeltaOp::Modified
+            && req.description.is_empty()
+            && req.scenarios.iter().all(|s| s.clauses.is_empty())
+        {
+            report.add_warning(
+                file_path,
+                line,
+                &format!(
+                    "MODIFIED requirement \"{}\" should include the complete requirement text",
+                    req.name
+                ),
+            );
         }
     }
-
-    results
 }
 
 #[cfg(test)]
@@ -485,6 +753,24 @@ mod tests {
         (temp_dir, change_dir)
     }
 
+    /// Helper to create `<spec_folder>/<capability>/spec.md` with the given requirements.
+    fn create_target_spec(spec_folder: &Path, capability: &str, requirements: &[&str]) {
+        let mut content = format!(
+            "# {} Specification\n\n## Purpose\nTarget spec.\n\n## Requirements\n",
+            capability
+        );
+        for name in requirements {
+            content.push_str(&format!(
+                "\n### Requirement: {}\nThe system SHALL work.\n",
+                name
+            ));
+        }
+
+        let dir = spec_folder.join(capability);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("spec.md"), content).unwrap();
+    }
+
     // ==================== Basic validation tests ====================
 
     #[test]
@@ -1238,4 +1524,274 @@ The system SHALL allow users to perform the new action.
             .iter()
             .any(|i| i.message.contains("Tasks: 2/4 completed")));
     }
+
+    // ==================== Approval tests ====================
+
+    #[test]
+    fn test_edit_after_approval_is_error() {
+        let proposal = r#"# Add Feature X
+
+## Why
+
+This feature is needed because it solves a critical user problem that has been
+requested by many users.
+
+## What Changes
+
+- Add new API endpoint
+"#;
+
+        let delta_spec = r#"## ADDED Requirements
+
+### Requirement: User can perform action
+
+The system SHALL allow users to perform the new action.
+
+#### Scenario: Successful action
+
+- **WHEN** user triggers the action
+- **THEN** the action completes successfully
+"#;
+
+        let (_temp_dir, change_dir) = create_temp_change_with_tasks(
+            proposal,
+            &[("feature-x", delta_spec)],
+            Some("# Tasks\n- [ ] 1.1 Task\n"),
+        );
+        let spec_folder = TempDir::new().unwrap();
+        create_target_spec(spec_folder.path(), "feature-x", &[]);
+        crate::core::approval::record_approval(
+            &change_dir,
+            spec_folder.path(),
+            "Jane",
+            "2025-01-15T10:30:00Z",
+        )
+        .unwrap();
+        assert!(validate_change(&change_dir).report.is_valid());
+
+        let spec_path = change_dir.join("specs/feature-x/spec.md");
+        fs::write(&spec_path, delta_spec.replace("new action", "other action")).unwrap();
+
+        let result = validate_change(&change_dir);
+        assert!(!result.report.is_valid());
+        assert!(result
+            .report
+            .issues
+            .iter()
+            .any(|i| i.severity == crate::validate::Severity::Error
+                && i.file == "specs/feature-x/spec.md"
+                && i.message.contains("Changed after approval by Jane")));
+    }
+
+    #[test]
+    fn test_invalid_approval_record_is_error() {
+        let (_temp_dir, change_dir) = create_temp_change("# Change\n", &[]);
+        fs::write(change_dir.join("approval.toml"), "approver = ").unwrap();
+
+        let result = validate_change(&change_dir);
+
+        assert!(result
+            .report
+            .issues
+            .iter()
+            .any(|i| i.file == "approval.toml"));
+    }
+
+    // ==================== Target spec cross-check tests ====================
+
+    const CROSS_CHECK_PROPOSAL: &str = r#"# Change Auth
+
+## Why
+
+Authentication needs to change to support a second factor for all users of the system.
+
+## What Changes
+
+- Update authentication requirements
+"#;
+
+    /// Validate a change with one `auth` delta against a spec folder.
+    fn cross_check(
+        proposal: &str,
+        delta: &str,
+        target: Option<&[&str]>,
+    ) -> (ChangeValidationResult, TempDir, TempDir) {
+        let (temp_dir, change_dir) = create_temp_change_with_tasks(
+            proposal,
+            &[("auth", delta)],
+            Some("# Tasks\n- [ ] 1.1 Task\n"),
+        );
+        let spec_folder = TempDir::new().unwrap();
+        if let Some(requirements) = target {
+            create_target_spec(spec_folder.path(), "auth", requirements);
+        }
+
+        let result = validate_change_against_specs(&change_dir, spec_folder.path());
+        (result, temp_dir, spec_folder)
+    }
+
+    fn has_error(result: &ChangeValidationResult, needle: &str) -> bool {
+        result.report.issues.iter().any(|i| {
+            i.severity == crate::validate::Severity::Error
+                && i.file == "specs/auth/spec.md"
+                && i.message.contains(needle)
+        })
+    }
+
+    #[test]
+    fn test_cross_check_accepts_matching_delta() {
+        let delta = "## MODIFIED Requirements\n\n### Requirement: Login\nThe system SHALL log in.\n\n## REMOVED Requirements\n\n### Requirement: Legacy\n**Reason**: Gone\n\n## ADDED Requirements\n\n### Requirement: OTP\nThe system SHALL ask for a code.\n\n#### Scenario: Code\n- **WHEN** user logs in\n- **THEN** a code is requested\n";
+
+        let (result, _c, _s) = cross_check(CROSS_CHECK_PROPOSAL, delta, Some(&["Login", "Legacy"]));
+
+        assert!(
+            result.report.is_valid(),
+            "Expected valid change, got errors: {:?}",
+            result.report.issues
+        );
+    }
+
+    #[test]
+    fn test_cross_check_modified_and_removed_must_exist() {
+        let delta = "## MODIFIED Requirements\n\n### Requirement: Missing\nThe system SHALL x.\n\n## REMOVED Requirements\n\n### Requirement: Also Missing\n**Reason**: Gone\n";
+
+        let (result, _c, _s) = cross_check(CROSS_CHECK_PROPOSAL, delta, Some(&["Login"]));
+
+        assert!(has_error(
+            &result,
+            "MODIFIED requirement \"Missing\" does not exist in spec \"auth\""
+        ));
+        assert!(has_error(
+            &result,
+            "REMOVED requirement \"Also Missing\" does not exist"
+        ));
+    }
+
+    #[test]
+    fn test_cross_check_added_must_not_exist() {
+        let delta = "## ADDED Requirements\n\n### Requirement: Login\nThe system SHALL log in.\n\n#### Scenario: Login\n- **WHEN** x\n- **THEN** y\n";
+
+        let (result, _c, _s) = cross_check(CROSS_CHECK_PROPOSAL, delta, Some(&["Login"]));
+
+        assert!(has_error(
+            &result,
+            "ADDED requirement \"Login\" already exists in spec \"auth\""
+        ));
+        let line = result
+            .report
+            .issues
+            .iter()
+            .find(|i| i.file == "specs/auth/spec.md");
+        assert_eq!(line.and_then(|i| i.line), Some(3));
+    }
+
+    #[test]
+    fn test_cross_check_renamed() {
+        let delta = "## RENAMED Requirements\n\n### Requirement: Missing -> Other\n\n### Requirement: Login -> Logout\n\n### Requirement: No Arrow\n";
+
+        let (result, _c, _s) = cross_check(CROSS_CHECK_PROPOSAL, delta, Some(&["Login", "Logout"]));
+
+        assert!(has_error(
+            &result,
+            "RENAMED requirement \"Missing\" does not exist"
+        ));
+        assert!(has_error(
+            &result,
+            "Cannot rename \"Login\": requirement \"Logout\" already exists"
+        ));
+        assert!(has_error(
+            &result,
+            "must use the form \"Old Name -> New Name\""
+        ));
+    }
+
+    #[test]
+    fn test_cross_check_modified_may_use_renamed_name() {
+        let delta = "## RENAMED Requirements\n\n### Requirement: Login -> Sign In\n\n## MODIFIED Requirements\n\n### Requirement: Sign In\nThe system SHALL sign in.\n";
+
+        let (result, _c, _s) = cross_check(CROSS_CHECK_PROPOSAL, delta, Some(&["Login"]));
+
+        assert!(
+            result.report.is_valid(),
+            "Expected valid change, got errors: {:?}",
+            result.report.issues
+        );
+    }
+
+    #[test]
+    fn test_cross_check_missing_capability_must_be_declared() {
+        let delta = "## ADDED Requirements\n\n### Requirement: OTP\nThe system SHALL ask for a code.\n\n#### Scenario: Code\n- **WHEN** user logs in\n- **THEN** a code is requested\n";
+
+        let (result, _c, _s) = cross_check(CROSS_CHECK_PROPOSAL, delta, None);
+        assert!(has_error(&result, "Capability \"auth\" has no spec"));
+
+        let declared = format!(
+            "{}\n## Impact\n- Affected specs: auth\n- New capabilities: `auth`\n",
+            CROSS_CHECK_PROPOSAL
+        );
+        let (result, _c, _s) = cross_check(&declared, delta, None);
+        assert!(
+            result.report.is_valid(),
+            "Expected valid change, got errors: {:?}",
+            result.report.issues
+        );
+    }
+
+    #[test]
+    fn test_cross_check_new_capability_cannot_modify() {
+        let proposal = format!(
+            "{}\n## Impact\n- **New capabilities**: auth\n",
+            CROSS_CHECK_PROPOSAL
+        );
+        let delta =
+            "## MODIFIED Requirements\n\n### Requirement: Login\nThe system SHALL log in.\n";
+
+        let (result, _c, _s) = cross_check(&proposal, delta, None);
+
+        assert!(has_error(
+            &result,
+            "MODIFIED requirement \"Login\" does not exist"
+        ));
+    }
+
+    #[test]
+    fn test_declared_new_capabilities() {
+        let proposal =
+            "## Impact\n- Affected specs: auth\n- New capabilities: `billing`, notifications\n";
+
+        assert_eq!(
+            declared_new_capabilities(proposal),
+            vec!["billing".to_string(), "notifications".to_string()]
+        );
+        assert!(declared_new_capabilities("## Why\n- New capabilities: x\n").is_empty());
+    }
+
+    // ==================== Conflict tests ====================
+
+    #[test]
+    fn test_conflicting_change_is_warning() {
+        let changes = TempDir::new().unwrap();
+        let spec_folder = TempDir::new().unwrap();
+        create_target_spec(spec_folder.path(), "auth", &["Login"]);
+
+        let delta =
+            "## MODIFIED Requirements\n\n### Requirement: Login\nThe system SHALL log in.\n";
+        for id in ["a", "b"] {
+            let dir = changes.path().join(id);
+            fs::create_dir_all(dir.join("specs/auth")).unwrap();
+            fs::write(dir.join("proposal.md"), CROSS_CHECK_PROPOSAL).unwrap();
+            fs::write(dir.join("tasks.md"), "# Tasks\n- [ ] 1.1 Task\n").unwrap();
+            fs::write(dir.join("specs/auth/spec.md"), delta).unwrap();
+        }
+
+        let result = validate_change_against_specs(&changes.path().join("a"), spec_folder.path());
+
+        assert!(result.report.is_valid());
+        assert!(result.report.issues.iter().any(|i| {
+            i.severity == crate::validate::Severity::Warning
+                && i.file == "specs/auth/spec.md"
+                && i.message
+                    .contains("\"Login\" is also changed by b (MODIFIED)")
+        }));
+    }
 }
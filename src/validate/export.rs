@@ -0,0 +1,315 @@
+//! Machine-readable exports of validation reports.
+//!
+//! Serialises validation reports into SARIF 2.1.0 (for inline code review
+//! annotations) and JUnit XML (for CI test result panels). Info-level issues
+//! are not exported.
+
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use super::{Severity, ValidationReport};
+
+/// SARIF schema location written into exported logs.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// What kind of item a report belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportKind {
+    /// A spec in the spec folder.
+    Spec,
+    /// A change in the changes folder.
+    Change,
+}
+
+impl ReportKind {
+    /// Label used for JUnit class names and suite names.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReportKind::Spec => "spec",
+            ReportKind::Change => "change",
+        }
+    }
+
+    /// SARIF rule ID for issues of this kind.
+    fn rule_id(&self) -> &'static str {
+        match self {
+            ReportKind::Spec => "spox/spec-validation",
+            ReportKind::Change => "spox/change-validation",
+        }
+    }
+}
+
+/// The validation report of a single spec or change.
+#[derive(Debug, Clone)]
+pub struct ReportEntry {
+    /// The spec or change ID.
+    pub name: String,
+    /// Directory that relative issue paths are resolved against (empty for paths
+    /// that are already relative to the project root).
+    pub base: PathBuf,
+    /// The validation report.
+    pub report: ValidationReport,
+}
+
+impl ReportEntry {
+    /// Whether this entry fails validation, treating warnings as failures in strict mode.
+    pub fn failed(&self, strict: bool) -> bool {
+        if strict {
+            !self.report.is_valid_strict()
+        } else {
+            !self.report.is_valid()
+        }
+    }
+
+    /// Path of an issue's file relative to the project root, with `/` separators.
+    fn issue_path(&self, file: &str) -> String {
+        let path = self.base.join(file);
+        path.to_string_lossy().replace('\\', "/")
+    }
+}
+
+/// Serialise validation reports as a SARIF 2.1.0 log with a single run.
+///
+/// Errors map to level `error` and warnings to level `warning`.
+pub fn to_sarif(kind: ReportKind, entries: &[ReportEntry]) -> String {
+    let results: Vec<Value> = entries
+        .iter()
+        .flat_map(|entry| {
+            entry.report.issues.iter().filter_map(move |issue| {
+                let level = match issue.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Info => return None,
+                };
+
+                let mut location = json!({
+                    "artifactLocation": { "uri": entry.issue_path(&issue.file) }
+                });
+                if let Some(line) = issue.line {
+                    location["region"] = json!({ "startLine": line });
+                }
+
+                Some(json!({
+                    "ruleId": kind.rule_id(),
+                    "level": level,
+                    "message": { "text": issue.message },
+                    "locations": [{ "physicalLocation": location }],
+                }))
+            })
+        })
+        .collect();
+
+    let log = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "spox",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/marconae/spec-oxide",
+                    "rules": [{
+                        "id": kind.rule_id(),
+                        "shortDescription": {
+                            "text": format!("{} validation", kind.label())
+                        },
+                    }],
+                }
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&log).unwrap_or_default()
+}
+
+/// Serialise validation reports as JUnit XML with one testcase per spec or change.
+///
+/// A testcase fails if it has errors, or warnings in strict mode. Its issues are
+/// listed in the failure body; warnings of passing testcases go to `system-out`.
+pub fn to_junit(kind: ReportKind, entries: &[ReportEntry], strict: bool) -> String {
+    let failures = entries.iter().filter(|e| e.failed(strict)).count();
+    let suite = format!("spox {} validate", kind.label());
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"spox\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+        entries.len(),
+        failures
+    ));
+    xml.push_str(&format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\">\n",
+        escape_xml(&suite),
+        entries.len(),
+        failures
+    ));
+
+    for entry in entries {
+        let open = format!(
+            "    <testcase classname=\"{}\" name=\"{}\"",
+            kind.label(),
+            escape_xml(&entry.name)
+        );
+        let issues = format_issues(entry);
+
+        if entry.failed(strict) {
+            let message = format!(
+                "{} error(s), {} warning(s)",
+                entry.report.errors, entry.report.warnings
+            );
+            xml.push_str(&format!(
+                "{}>\n      <failure message=\"{}\" type=\"validation\">{}</failure>\n    </testcase>\n",
+                open,
+                escape_xml(&message),
+                escape_xml(&issues)
+            ));
+        } else if !issues.is_empty() {
+            xml.push_str(&format!(
+                "{}>\n      <system-out>{}</system-out>\n    </testcase>\n",
+                open,
+                escape_xml(&issues)
+            ));
+        } else {
+            xml.push_str(&format!("{}/>\n", open));
+        }
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// One line per error or warning: `ERROR path:line message`.
+fn format_issues(entry: &ReportEntry) -> String {
+    entry
+        .report
+        .issues
+        .iter()
+        .filter(|i| i.severity != Severity::Info)
+        .map(|issue| {
+            let path = entry.issue_path(&issue.file);
+            let location = match issue.line {
+                Some(line) => format!("{}:{}", path, line),
+                None => path,
+            };
+            format!("{} {} {}\n", issue.severity, location, issue.message)
+        })
+        .collect()
+}
+
+/// Escape text for use in XML attributes and element content.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, base: &str, report: ValidationReport) -> ReportEntry {
+        ReportEntry {
+            name: name.to_string(),
+            base: PathBuf::from(base),
+            report,
+        }
+    }
+
+    fn failing_change() -> ReportEntry {
+        let mut report = ValidationReport::new();
+        report.add_error("proposal.md", Some(1), "Missing Why section");
+        report.add_warning("specs/auth/spec.md", None, "Requirement <x> & \"y\"");
+        report.add_info("tasks.md", None, "Tasks: 1/2 completed");
+        entry("add-2fa", "specs/_changes/add-2fa", report)
+    }
+
+    fn warning_only_spec() -> ReportEntry {
+        let mut report = ValidationReport::new();
+        report.add_warning("specs/auth/spec.md", Some(4), "Purpose is short");
+        entry("auth", "", report)
+    }
+
+    // ==================== to_sarif tests ====================
+
+    #[test]
+    fn test_sarif_contains_results_with_locations() {
+        let sarif = to_sarif(ReportKind::Change, &[failing_change()]);
+        let log: Value = serde_json::from_str(&sarif).unwrap();
+
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "spox");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2, "info issues are not exported");
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[0]["ruleId"], "spox/change-validation");
+        assert_eq!(results[0]["message"]["text"], "Missing Why section");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"]["uri"],
+            "specs/_changes/add-2fa/proposal.md"
+        );
+        assert_eq!(location["region"]["startLine"], 1);
+        assert_eq!(results[1]["level"], "warning");
+        assert!(results[1]["locations"][0]["physicalLocation"]
+            .get("region")
+            .is_none());
+    }
+
+    #[test]
+    fn test_sarif_without_issues_has_empty_results() {
+        let sarif = to_sarif(
+            ReportKind::Spec,
+            &[entry("auth", "", ValidationReport::new())],
+        );
+        let log: Value = serde_json::from_str(&sarif).unwrap();
+
+        assert!(log["runs"][0]["results"].as_array().unwrap().is_empty());
+    }
+
+    // ==================== to_junit tests ====================
+
+    #[test]
+    fn test_junit_one_testcase_per_entry() {
+        let junit = to_junit(
+            ReportKind::Change,
+            &[failing_change(), entry("fix", "x", ValidationReport::new())],
+            false,
+        );
+
+        assert!(junit.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(
+            junit.contains("<testsuite name=\"spox change validate\" tests=\"2\" failures=\"1\"")
+        );
+        assert!(junit.contains("<testcase classname=\"change\" name=\"fix\"/>"));
+        assert!(
+            junit.contains("<failure message=\"1 error(s), 1 warning(s)\" type=\"validation\">")
+        );
+        assert!(junit.contains("ERROR specs/_changes/add-2fa/proposal.md:1 Missing Why section"));
+        assert!(junit.contains("Requirement &lt;x&gt; &amp; &quot;y&quot;"));
+        assert!(!junit.contains("Tasks: 1/2"));
+    }
+
+    #[test]
+    fn test_junit_warnings_fail_only_in_strict_mode() {
+        let normal = to_junit(ReportKind::Spec, &[warning_only_spec()], false);
+        assert!(normal.contains("failures=\"0\""));
+        assert!(normal
+            .contains("<system-out>WARN specs/auth/spec.md:4 Purpose is short\n</system-out>"));
+
+        let strict = to_junit(ReportKind::Spec, &[warning_only_spec()], true);
+        assert!(strict.contains("failures=\"1\""));
+        assert!(strict.contains("<failure message=\"0 error(s), 1 warning(s)\""));
+    }
+}
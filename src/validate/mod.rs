@@ -4,7 +4,8 @@
 //! and change proposals against formatting rules and business constraints.
 
 pub mod change;
-pub mod parser;
+pub mod export;
+pub mod response;
 pub mod spec;
 pub mod tasks;
 
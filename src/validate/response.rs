@@ -0,0 +1,328 @@
+//! Validation responses shared by the MCP tools and the CLI's JSON output.
+//!
+//! Converts validation reports of specs and changes into `ValidationResponse`s,
+//! the shape returned by the `validate_spec` and `validate_change` tools.
+
+use std::fs;
+use std::path::Path;
+
+use crate::mcp::{ValidationError, ValidationResponse, ValidationWarning};
+
+use super::change::{validate_change_against_specs, ChangeValidationResult};
+use super::spec::validate_spec;
+use super::{Severity, ValidationReport};
+
+/// Validate one spec, or all specs, as returned by `validate_spec`.
+///
+/// # Arguments
+/// * `specs_path` - Path to the specs folder
+/// * `spec_id` - The spec to validate; `None` validates every spec
+///
+/// # Returns
+/// `Ok(ValidationResponse)` with all errors and warnings, or `Err` with error message.
+pub fn spec_validation_response(
+    specs_path: &Path,
+    spec_id: Option<&str>,
+) -> Result<ValidationResponse, String> {
+    match spec_id {
+        Some(id) => {
+            // Validate single spec
+            let spec_path = specs_path.join(id).join("spec.md");
+
+            if !spec_path.exists() {
+                return Err(format!("spec not found: '{}'", id));
+            }
+
+            let report = validate_spec(&spec_path);
+            Ok(report_response(&report, |_| None))
+        }
+        None => {
+            // Validate all specs
+            if !specs_path.exists() {
+                return Err(format!(
+                    "Spec folder '{}' does not exist",
+                    specs_path.display()
+                ));
+            }
+
+            let mut total_errors = 0;
+            let mut total_warnings = 0;
+            let mut all_errors = Vec::new();
+            let mut all_warnings = Vec::new();
+
+            let entries: Vec<_> = fs::read_dir(specs_path)
+                .map_err(|e| format!("Failed to read spec folder: {}", e))?
+                .filter_map(|e| e.ok())
+                .collect();
+
+            // Sort entries and filter to spec directories
+            let mut sorted_entries: Vec<_> = entries
+                .iter()
+                .filter(|e| e.path().is_dir())
+                .filter(|e| {
+                    // Skip directories starting with _ (like _changes, _archive)
+                    let name = e.file_name().to_string_lossy().to_string();
+                    !name.starts_with('_')
+                })
+                .collect();
+            sorted_entries.sort_by_key(|e| e.file_name());
+
+            for entry in sorted_entries {
+                let spec_md_path = entry.path().join("spec.md");
+                if !spec_md_path.exists() {
+                    continue;
+                }
+
+                let report = validate_spec(&spec_md_path);
+                total_errors += report.errors;
+                total_warnings += report.warnings;
+
+                // Collect errors and warnings
+                collect_issues(
+                    &report,
+                    |file| file.to_string(),
+                    |_| None,
+                    &mut all_errors,
+                    &mut all_warnings,
+                );
+            }
+
+            Ok(bulk_response(
+                "specs",
+                total_errors,
+                total_warnings,
+                all_errors,
+                all_warnings,
+            ))
+        }
+    }
+}
+
+/// Validate one change, or all changes, as returned by `validate_change`.
+///
+/// Changes are validated against the specs they target, see
+/// [`validate_change_against_specs`].
+///
+/// # Arguments
+/// * `changes_path` - Path to the changes folder
+/// * `specs_path` - Path to the specs folder
+/// * `change_id` - The change to validate; `None` validates every change
+///
+/// # Returns
+/// `Ok(ValidationResponse)` with all errors and warnings, or `Err` with error message.
+pub fn change_validation_response(
+    changes_path: &Path,
+    specs_path: &Path,
+    change_id: Option<&str>,
+) -> Result<ValidationResponse, String> {
+    match change_id {
+        Some(id) => {
+            // Validate single change
+            let change_path = changes_path.join(id);
+
+            if !change_path.exists() {
+                return Err(format!("change not found: '{}'", id));
+            }
+
+            let result = validate_change_against_specs(&change_path, specs_path);
+            Ok(change_result_response(&result))
+        }
+        None => {
+            // Validate all changes
+            if !changes_path.exists() {
+                return Err(format!(
+                    "Changes folder '{}' does not exist",
+                    changes_path.display()
+                ));
+            }
+
+            let mut total_errors = 0;
+            let mut total_warnings = 0;
+            let mut all_errors = Vec::new();
+            let mut all_warnings = Vec::new();
+
+            let entries: Vec<_> = fs::read_dir(changes_path)
+                .map_err(|e| format!("Failed to read changes folder: {}", e))?
+                .filter_map(|e| e.ok())
+                .collect();
+
+            // Sort entries and filter to change directories
+            let mut sorted_entries: Vec<_> = entries.iter().filter(|e| e.path().is_dir()).collect();
+            sorted_entries.sort_by_key(|e| e.file_name());
+
+            for entry in sorted_entries {
+                let result = validate_change_against_specs(&entry.path(), specs_path);
+                total_errors += result.report.errors;
+                total_warnings += result.report.warnings;
+
+                let change_id = entry.file_name().to_string_lossy().to_string();
+
+                // Collect errors and warnings with change context
+                collect_issues(
+                    &result.report,
+                    |file| format!("{}/{}", change_id, file),
+                    section_from_file,
+                    &mut all_errors,
+                    &mut all_warnings,
+                );
+            }
+
+            Ok(bulk_response(
+                "changes",
+                total_errors,
+                total_warnings,
+                all_errors,
+                all_warnings,
+            ))
+        }
+    }
+}
+
+/// Convert the validation result of a single change to a `ValidationResponse`.
+fn change_result_response(result: &ChangeValidationResult) -> ValidationResponse {
+    report_response(&result.report, section_from_file)
+}
+
+/// Convert a single validation report to a `ValidationResponse`.
+fn report_response(
+    report: &ValidationReport,
+    section: fn(&str) -> Option<String>,
+) -> ValidationResponse {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+    collect_issues(
+        report,
+        |file| file.to_string(),
+        section,
+        &mut errors,
+        &mut warnings,
+    );
+
+    let summary = if report.valid {
+        format!("Valid ({} warnings)", report.warnings)
+    } else {
+        format!(
+            "Validation failed: {} errors, {} warnings",
+            report.errors, report.warnings
+        )
+    };
+
+    ValidationResponse {
+        valid: report.valid,
+        errors,
+        warnings,
+        summary,
+    }
+}
+
+/// Build the response of a validation over every spec or change.
+fn bulk_response(
+    kind: &str,
+    total_errors: usize,
+    total_warnings: usize,
+    errors: Vec<ValidationError>,
+    warnings: Vec<ValidationWarning>,
+) -> ValidationResponse {
+    let valid = total_errors == 0;
+    let summary = if valid {
+        format!("All {} valid ({} warnings)", kind, total_warnings)
+    } else {
+        format!(
+            "Validation failed: {} errors, {} warnings",
+            total_errors, total_warnings
+        )
+    };
+
+    ValidationResponse {
+        valid,
+        errors,
+        warnings,
+        summary,
+    }
+}
+
+/// Collect the errors and warnings of a report; info messages are skipped.
+fn collect_issues(
+    report: &ValidationReport,
+    file: impl Fn(&str) -> String,
+    section: fn(&str) -> Option<String>,
+    errors: &mut Vec<ValidationError>,
+    warnings: &mut Vec<ValidationWarning>,
+) {
+    for issue in &report.issues {
+        match issue.severity {
+            Severity::Error => errors.push(ValidationError {
+                file: file(&issue.file),
+                line: issue.line,
+                description: issue.message.clone(),
+                section: section(&issue.file),
+            }),
+            Severity::Warning => warnings.push(ValidationWarning {
+                file: file(&issue.file),
+                line: issue.line,
+                description: issue.message.clone(),
+                section: section(&issue.file),
+            }),
+            Severity::Info => {} // Skip info messages
+        }
+    }
+}
+
+/// Extract section name from file path for change validation context.
+fn section_from_file(file: &str) -> Option<String> {
+    if file.contains("proposal.md") {
+        Some("proposal".to_string())
+    } else if file.contains("tasks.md") {
+        Some("tasks".to_string())
+    } else if file.contains("design.md") {
+        Some("design".to_string())
+    } else if file.contains("specs/") || file.contains("spec.md") {
+        Some("deltas".to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_section_from_file() {
+        assert_eq!(
+            section_from_file("proposal.md"),
+            Some("proposal".to_string())
+        );
+        assert_eq!(section_from_file("tasks.md"), Some("tasks".to_string()));
+        assert_eq!(section_from_file("design.md"), Some("design".to_string()));
+        assert_eq!(
+            section_from_file("specs/auth/spec.md"),
+            Some("deltas".to_string())
+        );
+        assert_eq!(section_from_file("approval.toml"), None);
+    }
+
+    #[test]
+    fn test_all_changes_prefix_files_with_change_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let changes_path = temp_dir.path().join("_changes");
+        fs::create_dir_all(changes_path.join("empty-change")).unwrap();
+
+        let response = change_validation_response(&changes_path, temp_dir.path(), None).unwrap();
+
+        assert!(!response.valid);
+        assert_eq!(response.errors[0].file, "empty-change/proposal.md");
+        assert_eq!(response.errors[0].section, Some("proposal".to_string()));
+        assert!(response.summary.starts_with("Validation failed"));
+    }
+
+    #[test]
+    fn test_missing_spec_is_an_error() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let result = spec_validation_response(temp_dir.path(), Some("nope"));
+
+        assert_eq!(result.unwrap_err(), "spec not found: 'nope'");
+    }
+}
@@ -6,6 +6,7 @@ use std::fs;
 use std::path::Path;
 
 use super::ValidationReport;
+use crate::core::markdown::{Document, Requirement, Scenario, SectionKind};
 
 /// Minimum character length for Purpose section to avoid warnings.
 const MIN_PURPOSE_LENGTH: usize = 50;
@@ -30,24 +31,37 @@ pub fn validate_spec(path: &Path) -> ValidationReport {
         }
     };
 
-    validate_spec_content(&content, &file_path, &mut report);
+    check_spec_content(&content, &file_path, &mut report);
+    report
+}
+
+/// Validate spec content that is not (or not yet) saved to disk.
+///
+/// # Arguments
+/// * `content` - The content of the spec.md file
+/// * `file_path` - The file path to use in validation messages
+///
+/// # Returns
+/// A `ValidationReport` containing all validation issues found.
+pub fn validate_spec_content(content: &str, file_path: &str) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    check_spec_content(content, file_path, &mut report);
     report
 }
 
 /// Validate spec content (used for both file-based and content-based validation).
-fn validate_spec_content(content: &str, file_path: &str, report: &mut ValidationReport) {
-    let lines: Vec<&str> = content.lines().collect();
+fn check_spec_content(content: &str, file_path: &str, report: &mut ValidationReport) {
+    let doc = Document::parse(content);
 
     // Check for Purpose section
-    if let Some((purpose_line, _)) = find_section(&lines, "## Purpose") {
-        let purpose_text = extract_section_text(&lines, purpose_line);
-        if purpose_text.len() < MIN_PURPOSE_LENGTH {
+    if let Some(purpose) = doc.section(SectionKind::Purpose) {
+        if purpose.text.len() < MIN_PURPOSE_LENGTH {
             report.add_warning(
                 file_path,
-                Some(purpose_line + 1),
+                Some(purpose.heading.span.start.line),
                 &format!(
                     "Purpose section is too short ({} chars, minimum {} recommended)",
-                    purpose_text.len(),
+                    purpose.text.len(),
                     MIN_PURPOSE_LENGTH
                 ),
             );
@@ -57,227 +71,45 @@ fn validate_spec_content(content: &str, file_path: &str, report: &mut Validation
     }
 
     // Check for Requirements section
-    let requirements_line = match find_section(&lines, "## Requirements") {
-        Some((line, _)) => line,
+    let requirements = match doc.section(SectionKind::Requirements) {
+        Some(section) => section,
         None => {
             report.add_error(file_path, Some(1), "Missing Requirements section");
             return; // Cannot validate requirements if section is missing
         }
     };
 
-    // Parse and validate each requirement block
-    let requirements = parse_requirements(&lines, requirements_line);
-
-    if requirements.is_empty() {
+    if requirements.requirements.is_empty() {
         report.add_warning(
             file_path,
-            Some(requirements_line + 1),
+            Some(requirements.heading.span.start.line),
             "Requirements section has no requirement blocks",
         );
     }
 
-    for req in requirements {
-        validate_requirement(&req, file_path, report);
+    for req in &requirements.requirements {
+        validate_requirement(req, file_path, report);
     }
 }
 
-/// Find a section header in the lines and return its line index and content.
-fn find_section(lines: &[&str], header: &str) -> Option<(usize, String)> {
-    for (i, line) in lines.iter().enumerate() {
-        if line.trim().eq_ignore_ascii_case(header) {
-            return Some((i, header.to_string()));
-        }
-    }
-    None
-}
-
-/// Extract the text content of a section (from header to next same-level header).
-fn extract_section_text(lines: &[&str], start_line: usize) -> String {
-    let mut content = String::new();
-    let header_prefix = "## ";
-
-    for line in lines.iter().skip(start_line + 1) {
-        // Stop at next level-2 header
-        if line.starts_with(header_prefix) {
-            break;
-        }
-        content.push_str(line);
-        content.push('\n');
-    }
-
-    content.trim().to_string()
-}
-
-/// Represents a parsed requirement block.
-#[derive(Debug)]
-struct Requirement {
-    /// Line number where the requirement starts (0-indexed).
-    line: usize,
-    /// The name/title of the requirement.
-    name: String,
-    /// The descriptive text of the requirement.
-    text: String,
-    /// List of scenarios in this requirement.
-    scenarios: Vec<Scenario>,
-}
-
-/// Represents a parsed scenario block.
-#[derive(Debug)]
-struct Scenario {
-    /// Line number where the scenario starts (0-indexed).
-    line: usize,
-    /// The name/title of the scenario.
-    name: String,
-    /// Whether the scenario has a WHEN clause.
-    has_when: bool,
-    /// Whether the scenario has a THEN clause.
-    has_then: bool,
-}
-
-/// Parse all requirement blocks from the lines starting after requirements_line.
-fn parse_requirements(lines: &[&str], requirements_line: usize) -> Vec<Requirement> {
-    let mut requirements = Vec::new();
-    let mut current_req: Option<Requirement> = None;
-    let mut current_scenario: Option<Scenario> = None;
-    let mut in_requirements_section = false;
-
-    for (i, line) in lines.iter().enumerate().skip(requirements_line) {
-        let trimmed = line.trim();
-
-        // Check if we've entered a new level-2 section (end of Requirements)
-        if i > requirements_line && trimmed.starts_with("## ") {
-            // Save any pending scenario
-            if let (Some(ref mut req), Some(scenario)) = (&mut current_req, current_scenario.take())
-            {
-                req.scenarios.push(scenario);
-            }
-            // Save any pending requirement
-            if let Some(req) = current_req.take() {
-                requirements.push(req);
-            }
-            break;
-        }
-
-        // Mark that we're in the requirements section
-        if trimmed.eq_ignore_ascii_case("## Requirements") {
-            in_requirements_section = true;
-            continue;
-        }
-
-        if !in_requirements_section {
-            continue;
-        }
-
-        // Check for requirement header (### Requirement: Name)
-        if trimmed.starts_with("### Requirement:") {
-            // Save any pending scenario to previous requirement
-            if let (Some(ref mut req), Some(scenario)) = (&mut current_req, current_scenario.take())
-            {
-                req.scenarios.push(scenario);
-            }
-            // Save previous requirement
-            if let Some(req) = current_req.take() {
-                requirements.push(req);
-            }
-
-            let name = trimmed
-                .strip_prefix("### Requirement:")
-                .unwrap_or("")
-                .trim()
-                .to_string();
-
-            current_req = Some(Requirement {
-                line: i,
-                name,
-                text: String::new(),
-                scenarios: Vec::new(),
-            });
-            continue;
-        }
-
-        // Check for scenario header (#### Scenario: Name)
-        if trimmed.starts_with("#### Scenario:") {
-            // Save any pending scenario
-            if let (Some(ref mut req), Some(scenario)) = (&mut current_req, current_scenario.take())
-            {
-                req.scenarios.push(scenario);
-            }
-
-            let name = trimmed
-                .strip_prefix("#### Scenario:")
-                .unwrap_or("")
-                .trim()
-                .to_string();
-
-            current_scenario = Some(Scenario {
-                line: i,
-                name,
-                has_when: false,
-                has_then: false,
-            });
-            continue;
-        }
-
-        // Check for WHEN/THEN in scenario content
-        if let Some(ref mut scenario) = current_scenario {
-            let upper = trimmed.to_uppercase();
-            if upper.contains("**WHEN**")
-                || upper.starts_with("WHEN ")
-                || upper.starts_with("- WHEN")
-                || upper.contains("- **WHEN**")
-            {
-                scenario.has_when = true;
-            }
-            if upper.contains("**THEN**")
-                || upper.starts_with("THEN ")
-                || upper.starts_with("- THEN")
-                || upper.contains("- **THEN**")
-            {
-                scenario.has_then = true;
-            }
-        }
-
-        // Accumulate requirement text (before scenarios)
-        if current_req.is_some() && current_scenario.is_none() {
-            if let Some(ref mut req) = current_req {
-                // Skip empty lines at the start
-                if !req.text.is_empty() || !trimmed.is_empty() {
-                    if !req.text.is_empty() {
-                        req.text.push(' ');
-                    }
-                    req.text.push_str(trimmed);
-                }
-            }
-        }
-    }
-
-    // Save any pending scenario and requirement at end of file
-    if let (Some(ref mut req), Some(scenario)) = (&mut current_req, current_scenario.take()) {
-        req.scenarios.push(scenario);
-    }
-    if let Some(req) = current_req.take() {
-        requirements.push(req);
-    }
-
-    requirements
-}
-
 /// Validate a single requirement block.
 fn validate_requirement(req: &Requirement, file_path: &str, report: &mut ValidationReport) {
+    let line = Some(req.span.start.line);
+
     // Check requirement has text
-    if req.text.trim().is_empty() {
+    if req.description.is_empty() {
         report.add_error(
             file_path,
-            Some(req.line + 1),
+            line,
             &format!("Requirement \"{}\" has no description text", req.name),
         );
     } else {
         // Check for normative language (SHALL/MUST)
-        let upper_text = req.text.to_uppercase();
+        let upper_text = req.description.to_uppercase();
         if !upper_text.contains("SHALL") && !upper_text.contains("MUST") {
             report.add_warning(
                 file_path,
-                Some(req.line + 1),
+                line,
                 &format!(
                     "Requirement \"{}\" does not use normative language (SHALL/MUST)",
                     req.name
@@ -290,7 +122,7 @@ fn validate_requirement(req: &Requirement, file_path: &str, report: &mut Validat
     if req.scenarios.is_empty() {
         report.add_warning(
             file_path,
-            Some(req.line + 1),
+            line,
             &format!("Requirement \"{}\" has no scenarios", req.name),
         );
     }
@@ -308,10 +140,10 @@ fn validate_scenario(
     file_path: &str,
     report: &mut ValidationReport,
 ) {
-    if !scenario.has_when {
+    if !scenario.has_when() {
         report.add_error(
             file_path,
-            Some(scenario.line + 1),
+            Some(scenario.span.start.line),
             &format!(
                 "Scenario \"{}\" in requirement \"{}\" is missing WHEN clause",
                 scenario.name, req_name
@@ -319,10 +151,10 @@ fn validate_scenario(
         );
     }
 
-    if !scenario.has_then {
+    if !scenario.has_then() {
         report.add_error(
             file_path,
-            Some(scenario.line + 1),
+            Some(scenario.span.start.line),
             &format!(
                 "Scenario \"{}\" in requirement \"{}\" is missing THEN clause",
                 scenario.name, req_name
@@ -7,6 +7,7 @@ use std::fs;
 use std::path::Path;
 
 use super::ValidationReport;
+use crate::error::{Error, Result};
 
 /// Statistics about task completion.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -160,6 +161,95 @@ pub fn validate_tasks_content(content: &str, file_name: &str) -> (ValidationRepo
     (report, stats)
 }
 
+/// Check off or uncheck a task in a tasks.md file.
+///
+/// # Arguments
+/// * `path` - Path to the tasks.md file
+/// * `number` - The numbered prefix of the task, e.g. `1.2.1`
+/// * `done` - Whether the task is completed
+///
+/// # Returns
+/// The task statistics of the updated file.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or written, or if no single task
+/// has the given number.
+pub fn set_task_status(path: &Path, number: &str, done: bool) -> Result<TaskStats> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| Error::Other(format!("Failed to read {}: {}", path.display(), e)))?;
+    let (updated, stats) = set_task_status_content(&content, number, done)?;
+    if updated != content {
+        fs::write(path, updated)
+            .map_err(|e| Error::Other(format!("Failed to write {}: {}", path.display(), e)))?;
+    }
+    Ok(stats)
+}
+
+/// Check off or uncheck a task in tasks.md content.
+///
+/// Only the checkbox of the task is rewritten; every other byte, including
+/// line endings, is kept.
+///
+/// # Arguments
+/// * `content` - The content of the tasks.md file
+/// * `number` - The numbered prefix of the task, e.g. `1.2.1`
+/// * `done` - Whether the task is completed
+///
+/// # Returns
+/// The updated content and its task statistics.
+///
+/// # Errors
+/// Returns an error if no task, or more than one task, has the given number.
+pub fn set_task_status_content(
+    content: &str,
+    number: &str,
+    done: bool,
+) -> Result<(String, TaskStats)> {
+    let number = number.trim();
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+
+    let matches: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| {
+            parse_checkbox_item(line)
+                .is_some_and(|item| task_number(&item.description) == Some(number))
+        })
+        .map(|(idx, _)| idx)
+        .collect();
+
+    let idx = match matches.as_slice() {
+        [idx] => *idx,
+        [] => return Err(Error::Other(format!("Task '{}' not found", number))),
+        _ => {
+            return Err(Error::Other(format!(
+                "Task number '{}' is used by {} tasks",
+                number,
+                matches.len()
+            )))
+        }
+    };
+
+    // The status character follows the leading whitespace and `- [`
+    let line = lines[idx];
+    let status = line.len() - line.trim_start().len() + 3;
+    let mark = if done { "x" } else { " " };
+
+    let mut updated = String::with_capacity(content.len());
+    for (i, line) in lines.iter().enumerate() {
+        if i == idx {
+            updated.push_str(&line[..status]);
+            updated.push_str(mark);
+            updated.push_str(&line[status + 1..]);
+        } else {
+            updated.push_str(line);
+        }
+    }
+
+    let (_, stats) = validate_tasks_content(&updated, "tasks.md");
+    Ok((updated, stats))
+}
+
 /// A parsed checkbox task item.
 #[derive(Debug)]
 #[allow(dead_code)] // Fields reserved for future validation features
@@ -230,6 +320,14 @@ fn parse_checkbox_item(line: &str) -> Option<CheckboxItem> {
     })
 }
 
+/// Get the numbered prefix of a task description, e.g. `1.2` of `1.2 Add login`.
+fn task_number(description: &str) -> Option<&str> {
+    if !has_numbered_prefix(description) {
+        return None;
+    }
+    description.split_whitespace().next()
+}
+
 /// Check if a description starts with a numbered prefix like 1.1, 2.3.1, etc.
 fn has_numbered_prefix(description: &str) -> bool {
     let trimmed = description.trim();
@@ -783,4 +881,70 @@ No tasks here yet.
         assert_eq!(stats.completed, 7);
         assert_eq!(stats.percentage(), 41);
     }
+
+    // ==================== set_task_status tests ====================
+
+    #[test]
+    fn test_set_task_status_checks_only_that_task() {
+        let content = "## 1. Setup\r\n- [ ] 1.1 Add config\r\n  - [ ] 1.1.1 Add field\r\n- [ ] 1.2 Add command\r\n";
+
+        let (updated, stats) = set_task_status_content(content, "1.1.1", true).unwrap();
+
+        assert_eq!(
+            updated,
+            "## 1. Setup\r\n- [ ] 1.1 Add config\r\n  - [x] 1.1.1 Add field\r\n- [ ] 1.2 Add command\r\n"
+        );
+        assert_eq!(
+            stats,
+            TaskStats {
+                total: 3,
+                completed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_task_status_unchecks_task() {
+        let content = "- [X] 1.1 Add config\n* [x] 1.2 Add command";
+
+        let (updated, stats) = set_task_status_content(content, " 1.2 ", false).unwrap();
+
+        assert_eq!(updated, "- [X] 1.1 Add config\n* [ ] 1.2 Add command");
+        assert_eq!(stats.completed, 1);
+    }
+
+    #[test]
+    fn test_set_task_status_errors() {
+        let content = "- [ ] 1.1 Add config\n- [ ] 1.1 Add config again\n- [ ] Write docs 1.2\n";
+
+        let missing = set_task_status_content(content, "1.2", true).unwrap_err();
+        let ambiguous = set_task_status_content(content, "1.1", true).unwrap_err();
+
+        assert_eq!(missing.to_string(), "Task '1.2' not found");
+        assert_eq!(
+            ambiguous.to_string(),
+            "Task number '1.1' is used by 2 tasks"
+        );
+    }
+
+    #[test]
+    fn test_set_task_status_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("tasks.md");
+        fs::write(&path, "- [ ] 1.1 Add config\n- [x] 1.2 Add command\n").unwrap();
+
+        let stats = set_task_status(&path, "1.1", true).unwrap();
+
+        assert_eq!(
+            stats,
+            TaskStats {
+                total: 2,
+                completed: 2
+            }
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "- [x] 1.1 Add config\n- [x] 1.2 Add command\n"
+        );
+    }
 }
@@ -3,12 +3,21 @@
 //! Provides `run_spec_validate` and `run_change_validate` functions
 //! that handle validation of specs and changes respectively.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::cli::output::{print_json, OutputFormat, ReportOutput};
 use crate::config::Config;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::mcp::ValidationResponse;
+use crate::validate::export::{to_junit, to_sarif, ReportEntry, ReportKind};
+use crate::validate::response::{change_validation_response, spec_validation_response};
 use crate::validate::{change, spec, Severity, ValidationReport};
+use crate::watch::{self, ProjectWatcher, Touched};
+
+/// Path of the config file, relative to the project root.
+const CONFIG_PATH: &str = ".spox/config.toml";
 
 /// Run the `spec validate` command.
 ///
@@ -18,13 +27,38 @@ use crate::validate::{change, spec, Severity, ValidationReport};
 /// # Arguments
 /// * `id` - Optional spec ID to validate
 /// * `strict` - If true, warnings also cause validation failure
+/// * `format` - Output format; JSON prints a `ValidationResponse`
+/// * `output` - Print a SARIF or JUnit XML report instead (takes precedence over `format`)
+/// * `watch` - Keep running and re-validate the specs whose files change
 ///
 /// # Returns
 /// Returns `Ok(())` if validation passes, exits with code 1 if validation fails.
-pub fn run_spec_validate(id: Option<String>, strict: bool) -> Result<()> {
-    let config_path = Path::new(".spox/config.toml");
+pub fn run_spec_validate(
+    id: Option<String>,
+    strict: bool,
+    format: OutputFormat,
+    output: Option<ReportOutput>,
+    watch: bool,
+) -> Result<()> {
+    let config_path = Path::new(CONFIG_PATH);
     let config = Config::load(config_path)?;
 
+    if watch {
+        check_watch_output(format, output)?;
+        return watch_spec_validation(config, id, strict);
+    }
+
+    if let Some(output) = output {
+        let entries = collect_spec_reports(&config, id);
+        return print_export(ReportKind::Spec, &entries, output, strict);
+    }
+
+    if format.is_json() {
+        let response = spec_validation_response(Path::new(config.spec_folder()), id.as_deref())
+            .map_err(Error::Other)?;
+        return print_json_report(&response, strict);
+    }
+
     if let Some(spec_id) = id {
         // Validate single spec
         let spec_path = Path::new(config.spec_folder())
@@ -55,13 +89,42 @@ pub fn run_spec_validate(id: Option<String>, strict: bool) -> Result<()> {
 /// # Arguments
 /// * `id` - Optional change ID to validate
 /// * `strict` - If true, warnings also cause validation failure
+/// * `format` - Output format; JSON prints a `ValidationResponse`
+/// * `output` - Print a SARIF or JUnit XML report instead (takes precedence over `format`)
+/// * `watch` - Keep running and re-validate the changes affected by file changes
 ///
 /// # Returns
 /// Returns `Ok(())` if validation passes, exits with code 1 if validation fails.
-pub fn run_change_validate(id: Option<String>, strict: bool) -> Result<()> {
-    let config_path = Path::new(".spox/config.toml");
+pub fn run_change_validate(
+    id: Option<String>,
+    strict: bool,
+    format: OutputFormat,
+    output: Option<ReportOutput>,
+    watch: bool,
+) -> Result<()> {
+    let config_path = Path::new(CONFIG_PATH);
     let config = Config::load(config_path)?;
 
+    if watch {
+        check_watch_output(format, output)?;
+        return watch_change_validation(config, id, strict);
+    }
+
+    if let Some(output) = output {
+        let entries = collect_change_reports(&config, id);
+        return print_export(ReportKind::Change, &entries, output, strict);
+    }
+
+    if format.is_json() {
+        let response = change_validation_response(
+            Path::new(config.changes_folder()),
+            Path::new(config.spec_folder()),
+            id.as_deref(),
+        )
+        .map_err(Error::Other)?;
+        return print_json_report(&response, strict);
+    }
+
     if let Some(change_id) = id {
         // Validate single change
         let change_path = Path::new(config.changes_folder()).join(&change_id);
@@ -75,7 +138,8 @@ pub fn run_change_validate(id: Option<String>, strict: bool) -> Result<()> {
             std::process::exit(1);
         }
 
-        let result = change::validate_change(&change_path);
+        let result =
+            change::validate_change_against_specs(&change_path, Path::new(config.spec_folder()));
         print_change_report(&change_id, &result.report, strict);
         exit_on_failure(&result.report, strict);
 
@@ -85,7 +149,7 @@ pub fn run_change_validate(id: Option<String>, strict: bool) -> Result<()> {
         }
     } else {
         // Validate all changes
-        run_bulk_change_validation(config.changes_folder(), strict)?;
+        run_bulk_change_validation(config.changes_folder(), config.spec_folder(), strict)?;
     }
 
     Ok(())
@@ -100,49 +164,64 @@ fn run_bulk_spec_validation(spec_folder: &str, strict: bool) -> Result<()> {
         std::process::exit(1);
     }
 
-    println!("Validating specs...");
+    let results: BTreeMap<String, ValidationReport> = list_specs(spec_path)
+        .into_iter()
+        .map(|(spec_id, spec_md_path)| (spec_id, spec::validate_spec(&spec_md_path)))
+        .collect();
 
-    let mut total_validated = 0;
-    let mut total_errors = 0;
-    let mut total_warnings = 0;
-    let mut any_failed = false;
+    if !print_bulk_results("specs", &results, strict) {
+        std::process::exit(1);
+    }
 
-    // Read all subdirectories in spec folder
-    let entries: Vec<_> = match fs::read_dir(spec_path) {
-        Ok(entries) => entries.filter_map(|e| e.ok()).collect(),
-        Err(e) => {
-            eprintln!("Failed to read spec folder: {}", e);
-            std::process::exit(1);
-        }
-    };
+    Ok(())
+}
 
-    // Sort entries for consistent output
-    let mut sorted_entries: Vec<_> = entries
-        .iter()
-        .filter(|e| e.path().is_dir())
-        .filter(|e| {
-            // Skip directories starting with _ (like _changes, _archive)
-            let name = e.file_name().to_string_lossy().to_string();
-            !name.starts_with('_')
+/// Run bulk validation for all changes in the changes folder.
+fn run_bulk_change_validation(changes_folder: &str, spec_folder: &str, strict: bool) -> Result<()> {
+    let changes_path = Path::new(changes_folder);
+
+    if !changes_path.exists() {
+        eprintln!("Changes folder '{}' does not exist", changes_folder);
+        std::process::exit(1);
+    }
+
+    let results: BTreeMap<String, ValidationReport> = list_changes(changes_path)
+        .into_iter()
+        .map(|(change_id, change_path)| {
+            let result =
+                change::validate_change_against_specs(&change_path, Path::new(spec_folder));
+            (change_id, result.report)
         })
         .collect();
-    sorted_entries.sort_by_key(|e| e.file_name());
 
-    for entry in sorted_entries {
-        let spec_id = entry.file_name().to_string_lossy().to_string();
-        let spec_md_path = entry.path().join("spec.md");
+    if !print_bulk_results("changes", &results, strict) {
+        std::process::exit(1);
+    }
 
-        if !spec_md_path.exists() {
-            continue; // Skip directories without spec.md
-        }
+    Ok(())
+}
 
-        let report = spec::validate_spec(&spec_md_path);
-        total_validated += 1;
+/// Print the status line of every validated item and the totals.
+///
+/// # Returns
+/// `true` if every item passes, treating warnings as failures in strict mode.
+fn print_bulk_results(
+    label: &str,
+    results: &BTreeMap<String, ValidationReport>,
+    strict: bool,
+) -> bool {
+    println!("Validating {}...", label);
+
+    let mut total_errors = 0;
+    let mut total_warnings = 0;
+    let mut any_failed = false;
+
+    for (id, report) in results {
         total_errors += report.errors;
         total_warnings += report.warnings;
 
-        let status = format_bulk_status(&report, strict);
-        println!("  {:<20} {}", spec_id, status);
+        let status = format_bulk_status(report, strict);
+        println!("  {:<20} {}", id, status);
 
         if !report.is_valid() || (strict && !report.is_valid_strict()) {
             any_failed = true;
@@ -151,83 +230,344 @@ fn run_bulk_spec_validation(spec_folder: &str, strict: bool) -> Result<()> {
 
     println!();
     println!(
-        "{} specs validated, {} errors, {} warnings",
-        total_validated, total_errors, total_warnings
+        "{} {} validated, {} errors, {} warnings",
+        results.len(),
+        label,
+        total_errors,
+        total_warnings
     );
 
-    if any_failed {
-        std::process::exit(1);
-    }
+    !any_failed
+}
 
+// =============================================================================
+// Watch mode
+// =============================================================================
+
+/// Reject output formats that watch mode cannot redraw.
+fn check_watch_output(format: OutputFormat, output: Option<ReportOutput>) -> Result<()> {
+    if format.is_json() || output.is_some() {
+        return Err(Error::Other(
+            "--watch cannot be combined with --format json or --output".to_string(),
+        ));
+    }
     Ok(())
 }
 
-/// Run bulk validation for all changes in the changes folder.
-fn run_bulk_change_validation(changes_folder: &str, strict: bool) -> Result<()> {
-    let changes_path = Path::new(changes_folder);
+/// Validate specs, then re-validate the specs whose files change until interrupted.
+fn watch_spec_validation(mut config: Config, id: Option<String>, strict: bool) -> Result<()> {
+    let mut results = validate_specs(&config, id.as_deref());
+    if let Some(spec_id) = id.as_deref().filter(|i| !results.contains_key(*i)) {
+        eprintln!("Spec '{}' not found in {}", spec_id, config.spec_folder());
+        std::process::exit(1);
+    }
+    let mut watcher = ProjectWatcher::new(&config)?;
+
+    loop {
+        watch::clear_screen();
+        match id.as_deref() {
+            Some(spec_id) => match results.get(spec_id) {
+                Some(report) => print_spec_report(spec_id, report, strict),
+                None => println!("Spec '{}' not found", spec_id),
+            },
+            None => {
+                print_bulk_results("specs", &results, strict);
+            }
+        }
+        watch::print_footer();
 
-    if !changes_path.exists() {
-        eprintln!("Changes folder '{}' does not exist", changes_folder);
+        let Some(touched) = watch::wait_for_changes(&mut watcher, &mut config)? else {
+            results = validate_specs(&config, id.as_deref());
+            continue;
+        };
+
+        for spec_id in &touched.specs {
+            if id.as_ref().is_some_and(|i| i != spec_id) {
+                continue;
+            }
+            let spec_path = Path::new(config.spec_folder())
+                .join(spec_id)
+                .join("spec.md");
+            if spec_path.exists() {
+                results.insert(spec_id.clone(), spec::validate_spec(&spec_path));
+            } else {
+                results.remove(spec_id);
+            }
+        }
+    }
+}
+
+/// Validate changes, then re-validate the changes affected by file changes until interrupted.
+///
+/// A change is affected when its own files change, or when the spec or another
+/// change's delta spec of a capability it has a delta for changes.
+fn watch_change_validation(mut config: Config, id: Option<String>, strict: bool) -> Result<()> {
+    let mut results = validate_changes(&config, id.as_deref());
+    if let Some(change_id) = id.as_deref().filter(|i| !results.contains_key(*i)) {
+        eprintln!(
+            "Change '{}' not found in {}",
+            change_id,
+            config.changes_folder()
+        );
         std::process::exit(1);
     }
+    let mut watcher = ProjectWatcher::new(&config)?;
+
+    loop {
+        watch::clear_screen();
+        match id.as_deref() {
+            Some(change_id) => match results.get(change_id) {
+                Some(report) => {
+                    print_change_report(change_id, report, strict);
+                    if report.is_valid() && (!strict || report.is_valid_strict()) {
+                        println!("\nChange '{}' is valid", change_id);
+                    }
+                }
+                None => println!("Change '{}' not found", change_id),
+            },
+            None => {
+                print_bulk_results("changes", &results, strict);
+            }
+        }
+        watch::print_footer();
 
-    println!("Validating changes...");
+        let Some(touched) = watch::wait_for_changes(&mut watcher, &mut config)? else {
+            results = validate_changes(&config, id.as_deref());
+            continue;
+        };
 
-    let mut total_validated = 0;
-    let mut total_errors = 0;
-    let mut total_warnings = 0;
-    let mut any_failed = false;
+        let changes_path = Path::new(config.changes_folder());
+        for change_id in affected_changes(&touched, changes_path) {
+            if id.as_ref().is_some_and(|i| *i != change_id) {
+                continue;
+            }
+            let change_path = changes_path.join(&change_id);
+            let exists = if id.is_some() {
+                change_path.is_dir()
+            } else {
+                change_path.join("proposal.md").exists()
+            };
+            if exists {
+                let result = change::validate_change_against_specs(
+                    &change_path,
+                    Path::new(config.spec_folder()),
+                );
+                results.insert(change_id, result.report);
+            } else {
+                results.remove(&change_id);
+            }
+        }
+    }
+}
+
+/// Validate one spec, or every spec in the spec folder.
+fn validate_specs(config: &Config, id: Option<&str>) -> BTreeMap<String, ValidationReport> {
+    let spec_folder = Path::new(config.spec_folder());
+    let specs = match id {
+        Some(spec_id) => vec![(
+            spec_id.to_string(),
+            spec_folder.join(spec_id).join("spec.md"),
+        )],
+        None if spec_folder.is_dir() => list_specs(spec_folder),
+        None => Vec::new(),
+    };
 
-    // Read all subdirectories in changes folder
-    let entries: Vec<_> = match fs::read_dir(changes_path) {
-        Ok(entries) => entries.filter_map(|e| e.ok()).collect(),
+    specs
+        .into_iter()
+        .filter(|(_, path)| path.exists())
+        .map(|(spec_id, path)| (spec_id, spec::validate_spec(&path)))
+        .collect()
+}
+
+/// Validate one change, or every change in the changes folder.
+fn validate_changes(config: &Config, id: Option<&str>) -> BTreeMap<String, ValidationReport> {
+    let changes_folder = Path::new(config.changes_folder());
+    let changes = match id {
+        Some(change_id) => vec![(change_id.to_string(), changes_folder.join(change_id))],
+        None if changes_folder.is_dir() => list_changes(changes_folder),
+        None => Vec::new(),
+    };
+
+    changes
+        .into_iter()
+        .filter(|(_, path)| path.is_dir())
+        .map(|(change_id, path)| {
+            let result =
+                change::validate_change_against_specs(&path, Path::new(config.spec_folder()));
+            (change_id, result.report)
+        })
+        .collect()
+}
+
+/// Changes whose validation result may differ after the touched files changed.
+fn affected_changes(touched: &Touched, changes_path: &Path) -> BTreeSet<String> {
+    let mut affected = touched.changes.clone();
+    if !changes_path.is_dir() {
+        return affected;
+    }
+
+    for (change_id, change_path) in list_changes(changes_path) {
+        if delta_capabilities(&change_path)
+            .iter()
+            .any(|c| touched.capabilities.contains(c))
+        {
+            affected.insert(change_id);
+        }
+    }
+
+    affected
+}
+
+/// Capabilities a change has delta specs for.
+fn delta_capabilities(change_path: &Path) -> BTreeSet<String> {
+    let Ok(entries) = fs::read_dir(change_path.join("specs")) else {
+        return BTreeSet::new();
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().join("spec.md").is_file())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect()
+}
+
+/// List the specs in the spec folder as sorted `(id, path to spec.md)` pairs.
+///
+/// Skips `_`-prefixed folders (like `_changes` and `_archive`) and folders
+/// without a spec.md. Exits with code 1 if the folder cannot be read.
+fn list_specs(spec_path: &Path) -> Vec<(String, PathBuf)> {
+    list_subdirectories(spec_path, "spec folder")
+        .into_iter()
+        .filter(|(name, _)| !name.starts_with('_'))
+        .map(|(name, path)| (name, path.join("spec.md")))
+        .filter(|(_, path)| path.exists())
+        .collect()
+}
+
+/// List the changes in the changes folder as sorted `(id, path)` pairs.
+///
+/// Only folders with a proposal.md count as changes. Exits with code 1 if the
+/// folder cannot be read.
+fn list_changes(changes_path: &Path) -> Vec<(String, PathBuf)> {
+    list_subdirectories(changes_path, "changes folder")
+        .into_iter()
+        .filter(|(name, _)| name != "archive")
+        .filter(|(_, path)| path.join("proposal.md").exists())
+        .collect()
+}
+
+/// Sorted `(name, path)` pairs of the subdirectories of a folder.
+fn list_subdirectories(folder: &Path, description: &str) -> Vec<(String, PathBuf)> {
+    let entries = match fs::read_dir(folder) {
+        Ok(entries) => entries,
         Err(e) => {
-            eprintln!("Failed to read changes folder: {}", e);
+            eprintln!("Failed to read {}: {}", description, e);
             std::process::exit(1);
         }
     };
 
-    // Sort entries for consistent output
-    let mut sorted_entries: Vec<_> = entries
-        .iter()
+    let mut dirs: Vec<(String, PathBuf)> = entries
+        .filter_map(|e| e.ok())
         .filter(|e| e.path().is_dir())
-        .filter(|e| {
-            // Skip archive directory
-            let name = e.file_name().to_string_lossy().to_string();
-            name != "archive"
-        })
+        .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
         .collect();
-    sorted_entries.sort_by_key(|e| e.file_name());
-
-    for entry in sorted_entries {
-        let change_id = entry.file_name().to_string_lossy().to_string();
-        let change_path = entry.path();
+    dirs.sort();
+    dirs
+}
 
-        // Check if it looks like a change directory (has proposal.md)
-        if !change_path.join("proposal.md").exists() {
-            continue;
+/// Validate the requested spec (or all specs) for a SARIF or JUnit export.
+///
+/// Exits with code 1 if the spec or the spec folder does not exist.
+fn collect_spec_reports(config: &Config, id: Option<String>) -> Vec<ReportEntry> {
+    let spec_folder = Path::new(config.spec_folder());
+    let specs = match id {
+        Some(spec_id) => {
+            let spec_path = spec_folder.join(&spec_id).join("spec.md");
+            if !spec_path.exists() {
+                eprintln!("Spec '{}' not found at {}", spec_id, spec_path.display());
+                std::process::exit(1);
+            }
+            vec![(spec_id, spec_path)]
         }
+        None => {
+            if !spec_folder.exists() {
+                eprintln!("Spec folder '{}' does not exist", config.spec_folder());
+                std::process::exit(1);
+            }
+            list_specs(spec_folder)
+        }
+    };
 
-        let result = change::validate_change(&change_path);
-        total_validated += 1;
-        total_errors += result.report.errors;
-        total_warnings += result.report.warnings;
-
-        let status = format_bulk_status(&result.report, strict);
-        println!("  {:<20} {}", change_id, status);
+    specs
+        .into_iter()
+        .map(|(name, path)| ReportEntry {
+            name,
+            base: PathBuf::new(),
+            report: spec::validate_spec(&path),
+        })
+        .collect()
+}
 
-        if !result.report.is_valid() || (strict && !result.report.is_valid_strict()) {
-            any_failed = true;
+/// Validate the requested change (or all changes) for a SARIF or JUnit export.
+///
+/// Exits with code 1 if the change or the changes folder does not exist.
+fn collect_change_reports(config: &Config, id: Option<String>) -> Vec<ReportEntry> {
+    let changes_folder = Path::new(config.changes_folder());
+    let changes = match id {
+        Some(change_id) => {
+            let change_path = changes_folder.join(&change_id);
+            if !change_path.exists() {
+                eprintln!(
+                    "Change '{}' not found at {}",
+                    change_id,
+                    change_path.display()
+                );
+                std::process::exit(1);
+            }
+            vec![(change_id, change_path)]
         }
-    }
+        None => {
+            if !changes_folder.exists() {
+                eprintln!(
+                    "Changes folder '{}' does not exist",
+                    config.changes_folder()
+                );
+                std::process::exit(1);
+            }
+            list_changes(changes_folder)
+        }
+    };
 
-    println!();
-    println!(
-        "{} changes validated, {} errors, {} warnings",
-        total_validated, total_errors, total_warnings
-    );
+    changes
+        .into_iter()
+        .map(|(name, path)| {
+            let result =
+                change::validate_change_against_specs(&path, Path::new(config.spec_folder()));
+            ReportEntry {
+                name,
+                base: path,
+                report: result.report,
+            }
+        })
+        .collect()
+}
+
+/// Print a SARIF or JUnit XML report and exit with code 1 if any entry failed.
+fn print_export(
+    kind: ReportKind,
+    entries: &[ReportEntry],
+    output: ReportOutput,
+    strict: bool,
+) -> Result<()> {
+    let report = match output {
+        ReportOutput::Sarif => to_sarif(kind, entries),
+        ReportOutput::Junit => to_junit(kind, entries, strict),
+    };
+    print!("{}", report);
+    if !report.ends_with('\n') {
+        println!();
+    }
 
-    if any_failed {
+    if entries.iter().any(|e| e.failed(strict)) {
         std::process::exit(1);
     }
 
@@ -307,6 +647,22 @@ fn print_report_issues(report: &ValidationReport) {
     }
 }
 
+/// Print a validation response as JSON and exit with code 1 if it indicates failure.
+fn print_json_report(response: &ValidationResponse, strict: bool) -> Result<()> {
+    print_json(response)?;
+
+    if !json_report_passes(response, strict) {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Whether a validation response passes, treating warnings as failures in strict mode.
+fn json_report_passes(response: &ValidationResponse, strict: bool) -> bool {
+    response.valid && (!strict || response.warnings.is_empty())
+}
+
 /// Exit with code 1 if the report indicates failure.
 fn exit_on_failure(report: &ValidationReport, strict: bool) {
     let failed = if strict {
@@ -373,4 +729,65 @@ mod tests {
         assert_eq!(format_bulk_status(&report, false), "FAIL (2 errors)");
         assert_eq!(format_bulk_status(&report, true), "FAIL (2 errors)");
     }
+
+    // ==================== Unit Tests for json_report_passes ====================
+
+    fn json_response(valid: bool, warnings: usize) -> ValidationResponse {
+        ValidationResponse {
+            valid,
+            errors: vec![],
+            warnings: (0..warnings)
+                .map(|_| crate::mcp::ValidationWarning {
+                    file: "spec.md".to_string(),
+                    line: None,
+                    description: "warning".to_string(),
+                    section: None,
+                })
+                .collect(),
+            summary: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_json_report_passes_valid() {
+        assert!(json_report_passes(&json_response(true, 0), true));
+        assert!(json_report_passes(&json_response(true, 1), false));
+    }
+
+    #[test]
+    fn test_json_report_fails_on_warnings_in_strict_mode() {
+        assert!(!json_report_passes(&json_response(true, 1), true));
+    }
+
+    #[test]
+    fn test_json_report_fails_when_invalid() {
+        assert!(!json_report_passes(&json_response(false, 0), false));
+    }
+
+    // ==================== Unit Tests for affected_changes ====================
+
+    #[test]
+    fn test_affected_changes_include_changes_with_touched_capability() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let changes = temp_dir.path();
+        for (change_id, capability) in [("add-2fa", "auth"), ("add-invoices", "billing")] {
+            let delta_dir = changes.join(change_id).join("specs").join(capability);
+            fs::create_dir_all(&delta_dir).unwrap();
+            fs::write(changes.join(change_id).join("proposal.md"), "# Change").unwrap();
+            fs::write(delta_dir.join("spec.md"), "## ADDED Requirements").unwrap();
+        }
+
+        let touched = Touched {
+            config: false,
+            specs: BTreeSet::from(["auth".to_string()]),
+            changes: BTreeSet::from(["fix-typo".to_string()]),
+            capabilities: BTreeSet::from(["auth".to_string()]),
+        };
+
+        let affected = affected_changes(&touched, changes);
+        assert_eq!(
+            affected,
+            BTreeSet::from(["add-2fa".to_string(), "fix-typo".to_string()])
+        );
+    }
 }
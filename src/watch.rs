@@ -0,0 +1,331 @@
+//! File watching for the `--watch` mode of the validate and show commands.
+//!
+//! Watches the spec folder, the changes folder and `.spox/config.toml`, waits
+//! for a burst of file changes to settle and reports which specs and changes
+//! the changed files belong to, so callers only redo the affected work.
+
+use std::collections::BTreeSet;
+use std::env;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::config::Config;
+use crate::error::{Error, Result};
+
+/// Path of the config file, relative to the project root.
+const CONFIG_PATH: &str = ".spox/config.toml";
+
+/// Time without further events before a burst of changes is reported.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// What a batch of changed files touched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Touched {
+    /// Whether `.spox/config.toml` changed.
+    pub config: bool,
+    /// Spec IDs with changed files in the spec folder.
+    pub specs: BTreeSet<String>,
+    /// Change IDs with changed files in the changes folder.
+    pub changes: BTreeSet<String>,
+    /// Capabilities whose spec or delta spec (in any change) changed.
+    pub capabilities: BTreeSet<String>,
+}
+
+impl Touched {
+    /// Classify changed paths (relative to the project root) by spec and change.
+    ///
+    /// Paths outside the spec folder, the changes folder and the config file,
+    /// as well as `_`-prefixed folders in the spec folder (like the archive),
+    /// are ignored.
+    pub fn from_paths(paths: &[PathBuf], config: &Config) -> Self {
+        let mut touched = Touched::default();
+
+        for path in paths {
+            if path == Path::new(CONFIG_PATH) {
+                touched.config = true;
+            } else if let Ok(rest) = path.strip_prefix(config.changes_folder()) {
+                match normal_components(rest).as_slice() {
+                    [change_id, "specs", capability, ..] => {
+                        touched.changes.insert(change_id.to_string());
+                        touched.capabilities.insert(capability.to_string());
+                    }
+                    [change_id, ..] => {
+                        touched.changes.insert(change_id.to_string());
+                    }
+                    [] => {}
+                }
+            } else if let Ok(rest) = path.strip_prefix(config.spec_folder()) {
+                if let Some(spec_id) = normal_components(rest).first() {
+                    if !spec_id.starts_with('_') {
+                        touched.specs.insert(spec_id.to_string());
+                        touched.capabilities.insert(spec_id.to_string());
+                    }
+                }
+            }
+        }
+
+        touched
+    }
+
+    /// Whether nothing relevant changed.
+    pub fn is_empty(&self) -> bool {
+        !self.config && self.specs.is_empty() && self.changes.is_empty()
+    }
+}
+
+/// Watches the files of a Spec Oxide project.
+pub struct ProjectWatcher {
+    /// Keeps the OS watches alive.
+    _watcher: RecommendedWatcher,
+    /// File system events.
+    events: Receiver<notify::Result<Event>>,
+    /// Project root that reported paths are made relative to.
+    root: PathBuf,
+}
+
+impl ProjectWatcher {
+    /// Start watching the spec folder, the changes folder and the config file.
+    ///
+    /// The current directory is the project root. Folders that do not exist
+    /// are not watched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the watcher cannot be created or a folder cannot be watched.
+    pub fn new(config: &Config) -> Result<Self> {
+        let root = env::current_dir()
+            .map_err(|e| Error::Other(format!("Failed to get current directory: {}", e)))?;
+        let (tx, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)
+            .map_err(|e| Error::Other(format!("Failed to start file watcher: {}", e)))?;
+
+        let mut watched: Vec<PathBuf> = Vec::new();
+        for folder in [config.spec_folder(), config.changes_folder()] {
+            let path = root.join(folder);
+            // The changes folder usually lives inside the spec folder
+            if !path.is_dir() || watched.iter().any(|w| path.starts_with(w)) {
+                continue;
+            }
+            watch_path(&mut watcher, &path, RecursiveMode::Recursive)?;
+            watched.push(path);
+        }
+
+        // Watch the folder, not the file: editors often save by replacing the file
+        let config_dir = root.join(CONFIG_PATH);
+        if let Some(dir) = config_dir.parent().filter(|d| d.is_dir()) {
+            watch_path(&mut watcher, dir, RecursiveMode::NonRecursive)?;
+        }
+
+        Ok(Self {
+            _watcher: watcher,
+            events,
+            root,
+        })
+    }
+
+    /// Block until files change and return what they touched.
+    ///
+    /// Bursts of events (an editor save, a `git checkout`) are collected into a
+    /// single batch. Batches that touch nothing relevant are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the watcher stops.
+    pub fn wait(&self, config: &Config) -> Result<Touched> {
+        loop {
+            let mut paths = Vec::new();
+
+            let first = self
+                .events
+                .recv()
+                .map_err(|_| Error::Other("File watcher stopped".to_string()))?;
+            self.collect(first, &mut paths);
+
+            loop {
+                match self.events.recv_timeout(DEBOUNCE) {
+                    Ok(event) => self.collect(event, &mut paths),
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        return Err(Error::Other("File watcher stopped".to_string()))
+                    }
+                }
+            }
+
+            paths.sort();
+            paths.dedup();
+            let touched = Touched::from_paths(&paths, config);
+            if !touched.is_empty() {
+                return Ok(touched);
+            }
+        }
+    }
+
+    /// Add the paths of a content-changing event, relative to the project root.
+    fn collect(&self, event: notify::Result<Event>, paths: &mut Vec<PathBuf>) {
+        let Ok(event) = event else {
+            return;
+        };
+        // Reading files during validation must not trigger another run
+        let relevant = match event.kind {
+            EventKind::Modify(ModifyKind::Metadata(_)) => false,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) => true,
+            _ => false,
+        };
+        if !relevant {
+            return;
+        }
+
+        for path in event.paths {
+            let relative = path.strip_prefix(&self.root).unwrap_or(&path);
+            paths.push(relative.to_path_buf());
+        }
+    }
+}
+
+/// Wait for relevant file changes, following config edits.
+///
+/// When the config file changed and still loads, `config` and `watcher` are
+/// replaced and `None` is returned: everything has to be redone. An invalid
+/// config is reported and the old one is kept.
+///
+/// # Errors
+///
+/// Returns an error if the watcher stops or cannot be recreated.
+pub fn wait_for_changes(
+    watcher: &mut ProjectWatcher,
+    config: &mut Config,
+) -> Result<Option<Touched>> {
+    loop {
+        let touched = watcher.wait(config)?;
+        if !touched.config {
+            return Ok(Some(touched));
+        }
+
+        match Config::load(Path::new(CONFIG_PATH)) {
+            Ok(reloaded) => {
+                *config = reloaded;
+                *watcher = ProjectWatcher::new(config)?;
+                return Ok(None);
+            }
+            Err(e) => eprintln!("error: {}", e),
+        }
+    }
+}
+
+/// Clear the terminal before redrawing watch output.
+pub fn clear_screen() {
+    print!("\x1B[2J\x1B[1;1H");
+}
+
+/// Print the watch mode footer and flush stdout.
+pub fn print_footer() {
+    println!();
+    println!("Watching for changes (Ctrl+C to stop)...");
+    let _ = std::io::stdout().flush();
+}
+
+/// Watch a path, mapping errors to `Error::Other`.
+fn watch_path(watcher: &mut RecommendedWatcher, path: &Path, mode: RecursiveMode) -> Result<()> {
+    watcher
+        .watch(path, mode)
+        .map_err(|e| Error::Other(format!("Failed to watch {}: {}", path.display(), e)))
+}
+
+/// The normal components of a relative path as strings.
+fn normal_components(path: &Path) -> Vec<&str> {
+    path.components()
+        .filter_map(|c| match c {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> Config {
+        toml::from_str(
+            r#"
+[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#,
+        )
+        .unwrap()
+    }
+
+    fn paths(paths: &[&str]) -> Vec<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    fn set(items: &[&str]) -> BTreeSet<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    // ==================== Touched::from_paths tests ====================
+
+    #[test]
+    fn test_spec_file_touches_spec_and_capability() {
+        let touched = Touched::from_paths(&paths(&["specs/auth/spec.md"]), &test_config());
+
+        assert_eq!(touched.specs, set(&["auth"]));
+        assert_eq!(touched.capabilities, set(&["auth"]));
+        assert!(touched.changes.is_empty());
+        assert!(!touched.config);
+    }
+
+    #[test]
+    fn test_change_files_touch_change() {
+        let touched = Touched::from_paths(
+            &paths(&[
+                "specs/_changes/add-2fa/proposal.md",
+                "specs/_changes/add-2fa/specs/auth/spec.md",
+                "specs/_changes/fix-login",
+            ]),
+            &test_config(),
+        );
+
+        assert_eq!(touched.changes, set(&["add-2fa", "fix-login"]));
+        assert_eq!(touched.capabilities, set(&["auth"]));
+        assert!(touched.specs.is_empty());
+    }
+
+    #[test]
+    fn test_config_and_irrelevant_paths() {
+        let touched = Touched::from_paths(
+            &paths(&[
+                ".spox/config.toml",
+                ".spox/search_index.bin",
+                "specs/_archive/2025-01-01-x/proposal.md",
+                "src/main.rs",
+            ]),
+            &test_config(),
+        );
+
+        assert!(touched.config);
+        assert!(touched.specs.is_empty());
+        assert!(touched.changes.is_empty());
+        assert!(!touched.is_empty());
+    }
+
+    #[test]
+    fn test_irrelevant_paths_are_empty() {
+        let touched = Touched::from_paths(
+            &paths(&[".spox/search_index.bin", "specs/_archive/x/tasks.md"]),
+            &test_config(),
+        );
+
+        assert!(touched.is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+//! Integration tests for `spox change approve` command.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a minimal .spox/config.toml
+fn create_config(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+
+    let config = r#"[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#;
+    fs::write(spox_dir.join("config.toml"), config).unwrap();
+}
+
+/// Helper to create a valid change with one delta spec
+fn create_change(root: &std::path::Path, name: &str) -> std::path::PathBuf {
+    let change_dir = root.join("specs/_changes").join(name);
+    fs::create_dir_all(change_dir.join("specs/auth")).unwrap();
+
+    let proposal = r#"# Change: Add 2FA
+
+## Why
+
+Users need a second authentication factor to protect accounts from credential theft.
+
+## What Changes
+
+- Add two-factor authentication
+
+## Impact
+
+- New capabilities: auth
+"#;
+    fs::write(change_dir.join("proposal.md"), proposal).unwrap();
+    fs::write(
+        change_dir.join("tasks.md"),
+        "# Tasks\n\n- [ ] 1.1 Implement\n",
+    )
+    .unwrap();
+
+    let delta = r#"## ADDED Requirements
+
+### Requirement: Two-Factor Auth
+
+The system SHALL require a one-time code after password login.
+
+#### Scenario: Code accepted
+
+- **WHEN** user enters a valid code
+- **THEN** user is authenticated
+"#;
+    fs::write(change_dir.join("specs/auth/spec.md"), delta).unwrap();
+
+    change_dir
+}
+
+// =============================================================================
+// Test: spox change approve records the approval
+// =============================================================================
+
+#[test]
+fn test_change_approve_writes_approval_record() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+    let change_dir = create_change(root, "add-2fa");
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "approve", "add-2fa", "--by", "Jane Doe"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Approved add-2fa by Jane Doe"));
+
+    let record = fs::read_to_string(change_dir.join("approval.toml")).unwrap();
+    assert!(record.contains("approver = \"Jane Doe\""));
+    assert!(record.contains("content_hash"));
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("approved"));
+}
+
+// =============================================================================
+// Test: editing an approved file fails validation
+// =============================================================================
+
+#[test]
+fn test_change_validate_fails_after_approved_file_edit() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+    let change_dir = create_change(root, "add-2fa");
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "approve", "add-2fa", "--by", "Jane"])
+        .assert()
+        .success();
+
+    let proposal_path = change_dir.join("proposal.md");
+    let proposal = fs::read_to_string(&proposal_path).unwrap();
+    fs::write(&proposal_path, proposal.replace("2FA", "MFA")).unwrap();
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "validate", "add-2fa"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Changed after approval"));
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("approval stale"));
+}
+
+// =============================================================================
+// Test: spox change approve with unknown change
+// =============================================================================
+
+#[test]
+fn test_change_approve_not_found() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "approve", "missing", "--by", "Jane"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found"));
+}
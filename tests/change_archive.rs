@@ -0,0 +1,208 @@
+//! Integration tests for `spox change archive` command.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a minimal .spox/config.toml
+fn create_config(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+
+    let config = r#"[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#;
+    fs::write(spox_dir.join("config.toml"), config).unwrap();
+}
+
+/// Helper to create the auth spec that the change modifies
+fn create_spec(root: &std::path::Path) {
+    let spec_dir = root.join("specs/auth");
+    fs::create_dir_all(&spec_dir).unwrap();
+
+    let content = r#"# auth Specification
+
+## Purpose
+
+This spec defines authentication requirements for the system.
+
+## Requirements
+
+### Requirement: User Login
+
+The system SHALL allow users to login with email and password.
+
+#### Scenario: Successful login
+
+- **WHEN** user provides valid credentials
+- **THEN** user is authenticated
+
+### Requirement: Legacy Tokens
+
+The system SHALL accept legacy API tokens.
+
+#### Scenario: Legacy token accepted
+
+- **WHEN** a client presents a legacy token
+- **THEN** the request is authenticated
+"#;
+    fs::write(spec_dir.join("spec.md"), content).unwrap();
+}
+
+/// Helper to create a change with a delta spec for auth
+fn create_change(root: &std::path::Path, name: &str, tasks: &str) {
+    let change_dir = root.join("specs/_changes").join(name);
+    fs::create_dir_all(change_dir.join("specs/auth")).unwrap();
+
+    let proposal = r#"# Change: Add 2FA
+
+## Why
+
+Users need a second authentication factor to protect accounts from credential theft.
+
+## What Changes
+
+- Add two-factor authentication
+- Remove legacy tokens
+"#;
+    fs::write(change_dir.join("proposal.md"), proposal).unwrap();
+    fs::write(change_dir.join("tasks.md"), tasks).unwrap();
+
+    let delta = r#"## ADDED Requirements
+
+### Requirement: Two-Factor Auth
+
+The system SHALL require a one-time code after password login.
+
+#### Scenario: Code accepted
+
+- **WHEN** user enters a valid code
+- **THEN** user is authenticated
+
+## MODIFIED Requirements
+
+### Requirement: User Login
+
+The system SHALL allow users to login with email or username.
+
+#### Scenario: Username login
+
+- **WHEN** user provides a valid username and password
+- **THEN** user is authenticated
+
+## REMOVED Requirements
+
+### Requirement: Legacy Tokens
+
+**Reason**: Replaced by two-factor authentication
+"#;
+    fs::write(change_dir.join("specs/auth/spec.md"), delta).unwrap();
+}
+
+// =============================================================================
+// Test: spox change archive applies deltas and moves the change
+// =============================================================================
+
+#[test]
+fn test_change_archive_applies_deltas() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    create_config(root);
+    create_spec(root);
+    create_change(root, "add-2fa", "# Tasks\n\n- [x] 1.1 Implement 2FA\n");
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "archive", "add-2fa"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Archived add-2fa"))
+        .stdout(predicate::str::contains("- auth  +1 ~1 -1"));
+
+    let spec = fs::read_to_string(root.join("specs/auth/spec.md")).unwrap();
+    assert!(spec.contains("### Requirement: Two-Factor Auth"));
+    assert!(spec.contains("email or username"));
+    assert!(!spec.contains("Legacy Tokens"));
+
+    assert!(!root.join("specs/_changes/add-2fa").exists());
+    let archived: Vec<String> = fs::read_dir(root.join("specs/_archive"))
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(archived.len(), 1);
+    assert!(archived[0].ends_with("-add-2fa"));
+}
+
+// =============================================================================
+// Test: spox change archive refuses incomplete changes unless forced
+// =============================================================================
+
+#[test]
+fn test_change_archive_refuses_incomplete_tasks() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    create_config(root);
+    create_spec(root);
+    create_change(root, "add-2fa", "# Tasks\n\n- [ ] 1.1 Implement 2FA\n");
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "archive", "add-2fa"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("incomplete tasks"));
+
+    assert!(root.join("specs/_changes/add-2fa").exists());
+}
+
+#[test]
+fn test_change_archive_force_archives_incomplete_change() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    create_config(root);
+    create_spec(root);
+    create_change(root, "add-2fa", "# Tasks\n\n- [ ] 1.1 Implement 2FA\n");
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "archive", "add-2fa", "--force"])
+        .assert()
+        .success();
+
+    assert!(!root.join("specs/_changes/add-2fa").exists());
+}
+
+// =============================================================================
+// Test: spox change archive with unknown change
+// =============================================================================
+
+#[test]
+fn test_change_archive_not_found() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    create_config(root);
+    create_spec(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "archive", "nonexistent"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found"));
+}
@@ -0,0 +1,98 @@
+//! Integration tests for `spox change conflicts` command.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a minimal .spox/config.toml
+fn create_config(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+
+    let config = r#"[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#;
+    fs::write(spox_dir.join("config.toml"), config).unwrap();
+}
+
+/// Helper to create a change with a single delta spec for the auth capability
+fn create_change(root: &std::path::Path, name: &str, delta: &str) {
+    let change_dir = root.join("specs/_changes").join(name);
+    fs::create_dir_all(change_dir.join("specs/auth")).unwrap();
+    fs::write(change_dir.join("proposal.md"), "# Change\n").unwrap();
+    fs::write(change_dir.join("specs/auth/spec.md"), delta).unwrap();
+}
+
+const MODIFY_LOGIN: &str =
+    "## MODIFIED Requirements\n\n### Requirement: User Login\nThe system SHALL log in.\n";
+
+const REMOVE_LOGIN: &str =
+    "## REMOVED Requirements\n\n### Requirement: User Login\n**Reason**: Replaced by SSO\n";
+
+// =============================================================================
+// Test: spox change conflicts
+// =============================================================================
+
+#[test]
+fn test_change_conflicts_none() {
+    let temp_dir = TempDir::new().unwrap();
+    create_config(temp_dir.path());
+    create_change(temp_dir.path(), "add-2fa", MODIFY_LOGIN);
+
+    spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["change", "conflicts"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "No conflicts between active changes.",
+        ));
+}
+
+#[test]
+fn test_change_conflicts_reports_overlap() {
+    let temp_dir = TempDir::new().unwrap();
+    create_config(temp_dir.path());
+    create_change(temp_dir.path(), "add-2fa", MODIFY_LOGIN);
+    create_change(temp_dir.path(), "sso", REMOVE_LOGIN);
+
+    spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["change", "conflicts"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("- auth: User Login"))
+        .stdout(predicate::str::contains("add-2fa  MODIFIED"))
+        .stdout(predicate::str::contains("sso      REMOVED"));
+}
+
+#[test]
+fn test_change_conflicts_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_config(temp_dir.path());
+    create_change(temp_dir.path(), "add-2fa", MODIFY_LOGIN);
+    create_change(temp_dir.path(), "sso", REMOVE_LOGIN);
+
+    let output = spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["change", "conflicts", "--format", "json"])
+        .output()
+        .unwrap();
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["conflicts"][0]["requirement"], "User Login");
+    assert_eq!(json["conflicts"][0]["changes"][1]["change_id"], "sso");
+    assert_eq!(json["conflicts"][0]["changes"][1]["operation"], "removed");
+}
@@ -0,0 +1,120 @@
+//! Integration tests for `spox change init` command.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to initialize a project (installs config and change templates)
+fn init_project(root: &std::path::Path) {
+    spox_cmd().current_dir(root).arg("init").assert().success();
+}
+
+// =============================================================================
+// Test: spox change init scaffolds a change from the templates
+// =============================================================================
+
+#[test]
+fn test_change_init_creates_change_from_templates() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    init_project(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "init", "add-2fa", "--capability", "auth"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created change add-2fa"))
+        .stdout(predicate::str::contains("- specs/auth/spec.md"));
+
+    let change_dir = root.join("specs/_changes/add-2fa");
+    let tasks = fs::read_to_string(change_dir.join("tasks.md")).unwrap();
+    assert!(tasks.starts_with("# Tasks add-2fa"));
+    assert!(change_dir.join("proposal.md").exists());
+    assert!(!change_dir.join("design.md").exists());
+
+    let delta = fs::read_to_string(change_dir.join("specs/auth/spec.md")).unwrap();
+    assert!(delta.contains("## ADDED Requirements"));
+}
+
+#[test]
+fn test_change_init_with_design_and_multiple_capabilities() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    init_project(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args([
+            "change",
+            "init",
+            "add-2fa-notify",
+            "--design",
+            "--capability",
+            "auth",
+            "--capability",
+            "notifications",
+        ])
+        .assert()
+        .success();
+
+    let change_dir = root.join("specs/_changes/add-2fa-notify");
+    assert!(change_dir.join("design.md").exists());
+    assert!(change_dir.join("specs/auth/spec.md").exists());
+    assert!(change_dir.join("specs/notifications/spec.md").exists());
+}
+
+// =============================================================================
+// Test: spox change init rejects existing IDs
+// =============================================================================
+
+#[test]
+fn test_change_init_rejects_existing_change() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    init_project(root);
+    fs::create_dir_all(root.join("specs/_changes/add-2fa")).unwrap();
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "init", "add-2fa"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+}
+
+#[test]
+fn test_change_init_rejects_archived_change() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    init_project(root);
+    fs::create_dir_all(root.join("specs/_archive/2025-01-15-add-2fa")).unwrap();
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["change", "init", "add-2fa"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("archive"));
+
+    assert!(!root.join("specs/_changes/add-2fa").exists());
+}
+
+#[test]
+fn test_change_init_without_initialization() {
+    let temp_dir = TempDir::new().unwrap();
+
+    spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["change", "init", "add-2fa"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("config not found"));
+}
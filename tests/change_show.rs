@@ -367,8 +367,7 @@ mod common {
         }
         output.push('\n');
 
-        if change.tasks_total > 0 {
-            let percentage = (change.tasks_completed * 100) / change.tasks_total;
+        if let Some(percentage) = (change.tasks_completed * 100).checked_div(change.tasks_total) {
             output.push_str(&format!(
                 "Tasks: {}/{} ({}%)\n\n",
                 change.tasks_completed, change.tasks_total, percentage
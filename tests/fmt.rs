@@ -0,0 +1,135 @@
+//! Integration tests for `spox fmt` command.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a minimal .spox/config.toml
+fn create_config(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+
+    let config = r#"[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#;
+    fs::write(spox_dir.join("config.toml"), config).unwrap();
+}
+
+/// A spec that is already in template layout
+const FORMATTED_SPEC: &str = r#"# auth Specification
+
+## Purpose
+This spec defines authentication requirements for the system.
+
+## Requirements
+
+### Requirement: User Login
+The system SHALL allow users to login.
+
+#### Scenario: Successful login
+- **WHEN** user provides valid credentials
+- **THEN** user is authenticated
+"#;
+
+/// The same spec with loose blank lines, plain keywords and a wrong heading level
+const MESSY_SPEC: &str = r#"# auth Specification
+
+
+## purpose
+
+This spec defines authentication requirements for the system.
+
+## Requirements
+### Requirement: User Login
+
+The system SHALL allow users to login.
+
+### Scenario: Successful login
+
+* WHEN user provides valid credentials
+* THEN user is authenticated
+
+
+"#;
+
+fn create_spec(root: &std::path::Path, content: &str) {
+    let spec_dir = root.join("specs/auth");
+    fs::create_dir_all(&spec_dir).unwrap();
+    fs::write(spec_dir.join("spec.md"), content).unwrap();
+}
+
+// =============================================================================
+// Test: spox fmt rewrites unformatted specs
+// =============================================================================
+
+#[test]
+fn test_fmt_rewrites_spec() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    create_config(root);
+    create_spec(root, MESSY_SPEC);
+
+    spox_cmd()
+        .current_dir(root)
+        .arg("fmt")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Reformatted specs/auth/spec.md"));
+
+    let spec = fs::read_to_string(root.join("specs/auth/spec.md")).unwrap();
+    assert_eq!(spec, FORMATTED_SPEC);
+}
+
+// =============================================================================
+// Test: spox fmt --check reports without writing
+// =============================================================================
+
+#[test]
+fn test_fmt_check_fails_on_unformatted_spec() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    create_config(root);
+    create_spec(root, MESSY_SPEC);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["fmt", "--check"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Would reformat specs/auth/spec.md",
+        ));
+
+    let spec = fs::read_to_string(root.join("specs/auth/spec.md")).unwrap();
+    assert_eq!(spec, MESSY_SPEC);
+}
+
+#[test]
+fn test_fmt_check_passes_on_formatted_spec() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    create_config(root);
+    create_spec(root, FORMATTED_SPEC);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["fmt", "--check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("All spec files are formatted"));
+}
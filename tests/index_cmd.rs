@@ -184,6 +184,173 @@ fn test_index_with_no_specs_shows_warning() {
     );
 }
 
+// =============================================================================
+// Test: spox index with the offline hashing backend
+// =============================================================================
+
+/// Select the hashing embedding backend in the config.
+fn use_hashing_backend(root: &std::path::Path) {
+    let config_path = root.join(".spox/config.toml");
+    let mut config = fs::read_to_string(&config_path).unwrap();
+    config.push_str("\n[search]\nbackend = \"hashing\"\n");
+    fs::write(config_path, config).unwrap();
+}
+
+#[test]
+fn test_index_with_hashing_backend_works_offline() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let specs_dir = root.join("specs");
+    fs::create_dir_all(&specs_dir).unwrap();
+    create_config(root, "specs", "specs/_changes");
+    use_hashing_backend(root);
+    create_spec(&specs_dir, "auth");
+    create_spec(&specs_dir, "payments");
+
+    spox_cmd()
+        .current_dir(root)
+        .arg("index")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("hashing embeddings"))
+        .stderr(predicate::str::contains(
+            "Index built successfully with 2 specs and 0 changes (6 added, 0 updated, 0 removed)",
+        ));
+
+    assert!(root.join(".spox/search_index.bin").exists());
+}
+
+#[test]
+fn test_index_rebuild_only_reports_changes() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let specs_dir = root.join("specs");
+    fs::create_dir_all(&specs_dir).unwrap();
+    create_config(root, "specs", "specs/_changes");
+    use_hashing_backend(root);
+    create_spec(&specs_dir, "auth");
+    create_spec(&specs_dir, "payments");
+
+    spox_cmd().current_dir(root).arg("index").assert().success();
+
+    fs::remove_dir_all(specs_dir.join("payments")).unwrap();
+    let auth_spec = specs_dir.join("auth/spec.md");
+    let content = fs::read_to_string(&auth_spec)
+        .unwrap()
+        .replace("provide basic auth features", "provide passkey login");
+    fs::write(&auth_spec, content).unwrap();
+
+    spox_cmd()
+        .current_dir(root)
+        .arg("index")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Index built successfully with 1 specs and 0 changes (0 added, 1 updated, 3 removed)",
+        ));
+}
+
+#[test]
+fn test_index_counts_active_and_archived_changes() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let specs_dir = root.join("specs");
+    fs::create_dir_all(&specs_dir).unwrap();
+    create_config(root, "specs", "specs/_changes");
+    use_hashing_backend(root);
+    create_spec(&specs_dir, "auth");
+    for change_dir in ["specs/_changes/add-2fa", "specs/_changes_archive/add-login"] {
+        let change_dir = root.join(change_dir);
+        fs::create_dir_all(&change_dir).unwrap();
+        fs::write(
+            change_dir.join("proposal.md"),
+            "## Why\n\nLogins are weak.\n\n## What Changes\n\n- Add a passcode\n",
+        )
+        .unwrap();
+    }
+
+    spox_cmd()
+        .current_dir(root)
+        .arg("index")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Index built successfully with 1 specs and 2 changes (5 added, 0 updated, 0 removed)",
+        ));
+}
+
+// =============================================================================
+// Test: spox index --check
+// =============================================================================
+
+#[test]
+fn test_index_check_detects_missing_and_stale_index() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let specs_dir = root.join("specs");
+    fs::create_dir_all(&specs_dir).unwrap();
+    create_config(root, "specs", "specs/_changes");
+    use_hashing_backend(root);
+    create_spec(&specs_dir, "auth");
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["index", "--check"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("Search index is missing"));
+    assert!(!root.join(".spox/search_index.bin").exists());
+
+    spox_cmd().current_dir(root).arg("index").assert().success();
+    spox_cmd()
+        .current_dir(root)
+        .args(["index", "--check"])
+        .assert()
+        .success()
+        .stdout("Search index is up to date\n");
+
+    let auth_spec = specs_dir.join("auth/spec.md");
+    let content = fs::read_to_string(&auth_spec)
+        .unwrap()
+        .replace("provide basic auth features", "provide passkey login");
+    fs::write(&auth_spec, content).unwrap();
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["index", "--check"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("Search index is stale"))
+        .stdout(predicate::str::contains("Run 'spox index' to rebuild it."));
+}
+
+#[test]
+fn test_index_check_rejects_old_index_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let specs_dir = root.join("specs");
+    fs::create_dir_all(&specs_dir).unwrap();
+    create_config(root, "specs", "specs/_changes");
+    use_hashing_backend(root);
+    create_spec(&specs_dir, "auth");
+    fs::write(root.join(".spox/search_index.bin"), b"not an index").unwrap();
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["index", "--check"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("unknown format"));
+
+    // A rebuild replaces the unreadable index
+    spox_cmd()
+        .current_dir(root)
+        .arg("index")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("(3 added, 0 updated, 0 removed)"));
+}
+
 // =============================================================================
 // Test: spox index without initialization (should fail)
 // =============================================================================
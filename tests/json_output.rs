@@ -0,0 +1,343 @@
+//! Integration tests for the global `--format json` option.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a minimal .spox/config.toml
+fn create_config(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+
+    let config = r#"[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#;
+    fs::write(spox_dir.join("config.toml"), config).unwrap();
+}
+
+const AUTH_SPEC: &str = r#"# auth Specification
+
+## Purpose
+This spec defines authentication requirements for the system.
+
+## Requirements
+
+### Requirement: User Login
+The system SHALL allow users to login.
+
+#### Scenario: Successful login
+- **WHEN** user provides valid credentials
+- **THEN** user is authenticated
+- **AND** a session is created
+"#;
+
+/// Create a project with one spec and one change.
+fn create_project(root: &std::path::Path) {
+    create_config(root);
+
+    let spec_dir = root.join("specs/auth");
+    fs::create_dir_all(&spec_dir).unwrap();
+    fs::write(spec_dir.join("spec.md"), AUTH_SPEC).unwrap();
+
+    let change_dir = root.join("specs/_changes/add-2fa");
+    fs::create_dir_all(change_dir.join("specs/auth")).unwrap();
+    fs::write(
+        change_dir.join("proposal.md"),
+        "# Change: Add 2FA\n\n## Why\nAccounts need a second factor.\n\n## What Changes\n- Add OTP check\n",
+    )
+    .unwrap();
+    fs::write(
+        change_dir.join("tasks.md"),
+        "## 1. Implementation\n- [x] 1.1 Add OTP\n- [ ] 1.2 Add tests\n",
+    )
+    .unwrap();
+    fs::write(
+        change_dir.join("specs/auth/spec.md"),
+        "## ADDED Requirements\n\n### Requirement: Two-Factor\nThe system SHALL ask for a code.\n\n#### Scenario: Code prompt\n- **WHEN** user logs in\n- **THEN** a code is requested\n",
+    )
+    .unwrap();
+}
+
+/// Run spox in the given directory and parse stdout as JSON.
+fn run_json(root: &std::path::Path, args: &[&str]) -> Value {
+    let output = spox_cmd()
+        .current_dir(root)
+        .args(args)
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+
+    serde_json::from_slice(&output.stdout).expect("stdout is valid JSON")
+}
+
+// =============================================================================
+// Test: list commands
+// =============================================================================
+
+#[test]
+fn test_spec_list_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(temp_dir.path(), &["spec", "list"]);
+
+    assert_eq!(json["specs"][0]["id"], "auth");
+    assert_eq!(json["specs"][0]["title"], "auth Specification");
+    assert_eq!(
+        json["specs"][0]["purpose"],
+        "This spec defines authentication requirements for the system."
+    );
+}
+
+#[test]
+fn test_change_list_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(temp_dir.path(), &["change", "list"]);
+
+    assert_eq!(json["changes"][0]["id"], "add-2fa");
+    assert_eq!(json["changes"][0]["task_progress"]["completed"], 1);
+    assert_eq!(json["changes"][0]["task_progress"]["total"], 2);
+}
+
+// =============================================================================
+// Test: show commands
+// =============================================================================
+
+#[test]
+fn test_spec_show_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(temp_dir.path(), &["spec", "show", "auth"]);
+
+    assert_eq!(json["id"], "auth");
+    let scenario = &json["requirements"][0]["scenarios"][0];
+    assert_eq!(scenario["name"], "Successful login");
+    assert_eq!(scenario["when_clause"], "user provides valid credentials");
+    assert_eq!(scenario["then_clauses"][1], "a session is created");
+}
+
+#[test]
+fn test_change_show_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(temp_dir.path(), &["change", "show", "add-2fa"]);
+
+    assert_eq!(json["change_id"], "add-2fa");
+    assert_eq!(json["proposal"]["why"], "Accounts need a second factor.");
+    assert_eq!(json["deltas"][0]["capability"], "auth");
+    assert_eq!(json["deltas"][0]["items"][0]["operation"], "added");
+}
+
+#[test]
+fn test_change_show_deltas_only_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(
+        temp_dir.path(),
+        &["change", "show", "add-2fa", "--deltas-only"],
+    );
+
+    assert!(json.get("proposal").is_none_or(Value::is_null));
+    assert_eq!(json["deltas"][0]["items"][0]["name"], "Two-Factor");
+}
+
+#[test]
+fn test_dashboard_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(temp_dir.path(), &["show"]);
+
+    assert_eq!(json["specs"][0]["id"], "auth");
+    assert_eq!(json["changes"][0]["id"], "add-2fa");
+}
+
+#[test]
+fn test_config_show_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(temp_dir.path(), &["config", "show"]);
+    assert_eq!(json["paths"]["spec_folder"], "specs");
+    assert_eq!(json["rules"]["system"][0], "mcp");
+
+    let json = run_json(temp_dir.path(), &["config", "show", "--paths"]);
+    assert_eq!(json["changes_folder"], "specs/_changes");
+}
+
+// =============================================================================
+// Test: validate commands
+// =============================================================================
+
+#[test]
+fn test_spec_validate_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(temp_dir.path(), &["spec", "validate", "auth"]);
+
+    assert_eq!(json["valid"], true);
+    assert!(json["errors"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_change_validate_json_failure_exits_non_zero() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+    fs::remove_file(temp_dir.path().join("specs/_changes/add-2fa/proposal.md")).unwrap();
+
+    let output = spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["--format", "json", "change", "validate", "add-2fa"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["valid"], false);
+    assert!(!json["errors"].as_array().unwrap().is_empty());
+}
+
+// =============================================================================
+// Test: commands that write files
+// =============================================================================
+
+#[test]
+fn test_init_json() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let json = run_json(temp_dir.path(), &["init"]);
+
+    assert_eq!(json["path"], ".");
+    assert_eq!(json["updated"], false);
+    assert!(temp_dir.path().join(".spox/config.toml").exists());
+}
+
+#[test]
+fn test_change_init_json() {
+    let temp_dir = TempDir::new().unwrap();
+    run_json(temp_dir.path(), &["init"]);
+
+    let json = run_json(
+        temp_dir.path(),
+        &["change", "init", "add-sso", "--capability", "auth"],
+    );
+
+    assert_eq!(json["change_id"], "add-sso");
+    let files: Vec<&str> = json["files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f.as_str().unwrap())
+        .collect();
+    assert!(files.contains(&"proposal.md"), "{:?}", files);
+    assert!(files.contains(&"specs/auth/spec.md"), "{:?}", files);
+}
+
+#[test]
+fn test_change_approve_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(
+        temp_dir.path(),
+        &["change", "approve", "add-2fa", "--by", "Alice"],
+    );
+
+    assert_eq!(json["change_id"], "add-2fa");
+    assert_eq!(json["approver"], "Alice");
+    assert!(!json["content_hash"].as_str().unwrap().is_empty());
+    assert!(json["files"]["proposal.md"].is_string());
+}
+
+#[test]
+fn test_change_archive_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(
+        temp_dir.path(),
+        &["change", "archive", "add-2fa", "--force"],
+    );
+
+    assert_eq!(json["change_id"], "add-2fa");
+    assert!(json["archive_path"].as_str().unwrap().ends_with("-add-2fa"));
+    assert_eq!(json["updates"][0]["capability"], "auth");
+    assert_eq!(json["updates"][0]["created"], false);
+    assert_eq!(json["updates"][0]["added"], 1);
+}
+
+#[test]
+fn test_fmt_check_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(temp_dir.path(), &["fmt", "--check"]);
+
+    assert_eq!(json["check"], true);
+    assert!(json["files"].is_array());
+}
+
+#[test]
+fn test_index_check_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let json = run_json(temp_dir.path(), &["index", "--check"]);
+
+    assert_eq!(json["status"], "missing");
+    assert_eq!(json["current"], false);
+}
+
+#[test]
+fn test_protocol_servers_reject_json() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    for args in [&["mcp", "serve"][..], &["lsp"][..]] {
+        spox_cmd()
+            .current_dir(temp_dir.path())
+            .args(args)
+            .args(["--format", "json"])
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(
+                "cannot be combined with --format json",
+            ));
+    }
+}
+
+// =============================================================================
+// Test: text remains the default
+// =============================================================================
+
+#[test]
+fn test_text_format_is_default() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["spec", "list"])
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with("Specs:"));
+}
@@ -0,0 +1,166 @@
+//! Integration tests for `spox lsp` command.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::{json, Value};
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{ChildStdin, ChildStdout, Stdio};
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a minimal .spox/config.toml
+fn create_config(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+
+    let config = r#"[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#;
+    fs::write(spox_dir.join("config.toml"), config).unwrap();
+}
+
+/// Write a JSON-RPC message with a Content-Length header.
+fn send(stdin: &mut ChildStdin, message: Value) {
+    let body = message.to_string();
+    write!(stdin, "Content-Length: {}\r\n\r\n{}", body.len(), body).unwrap();
+    stdin.flush().unwrap();
+}
+
+/// Read the next JSON-RPC message.
+fn receive(stdout: &mut BufReader<ChildStdout>) -> Value {
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        stdout.read_line(&mut header).unwrap();
+        let header = header.trim();
+        if header.is_empty() {
+            break;
+        }
+        if let Some(value) = header.strip_prefix("Content-Length:") {
+            length = value.trim().parse().unwrap();
+        }
+    }
+
+    let mut body = vec![0; length];
+    stdout.read_exact(&mut body).unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+/// Read messages until one with the given method arrives.
+fn receive_notification(stdout: &mut BufReader<ChildStdout>, method: &str) -> Value {
+    loop {
+        let message = receive(stdout);
+        if message["method"] == method {
+            return message;
+        }
+    }
+}
+
+// =============================================================================
+// Test: spox lsp help
+// =============================================================================
+
+#[test]
+fn test_main_help_shows_lsp_command() {
+    spox_cmd()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("lsp"));
+}
+
+#[test]
+fn test_lsp_without_initialization_fails() {
+    let temp_dir = TempDir::new().unwrap();
+
+    spox_cmd()
+        .current_dir(temp_dir.path())
+        .arg("lsp")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("config").or(predicate::str::contains("Config")));
+}
+
+// =============================================================================
+// Test: diagnostics over stdio
+// =============================================================================
+
+#[test]
+fn test_lsp_publishes_diagnostics_on_open() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+    let spec_path = root.join("specs/auth/spec.md");
+    fs::create_dir_all(spec_path.parent().unwrap()).unwrap();
+
+    let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin!("spox"))
+        .current_dir(root)
+        .arg("lsp")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+
+    send(
+        &mut stdin,
+        json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {"capabilities": {}}}),
+    );
+    let response = receive(&mut stdout);
+    assert_eq!(response["result"]["serverInfo"]["name"], "spox");
+    assert_eq!(
+        response["result"]["capabilities"]["definitionProvider"],
+        true
+    );
+
+    send(
+        &mut stdin,
+        json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+    );
+    let uri = format!("file://{}", spec_path.display());
+    send(
+        &mut stdin,
+        json!({"jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {
+            "textDocument": {
+                "uri": uri,
+                "languageId": "markdown",
+                "version": 1,
+                "text": "# auth Specification\n\n## Purpose\nThis spec defines authentication requirements for the system.\n"
+            }
+        }}),
+    );
+
+    let notification = receive_notification(&mut stdout, "textDocument/publishDiagnostics");
+    assert_eq!(notification["params"]["uri"], uri);
+    let diagnostics = notification["params"]["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["message"], "Missing Requirements section");
+    assert_eq!(diagnostics[0]["severity"], 1);
+
+    send(
+        &mut stdin,
+        json!({"jsonrpc": "2.0", "id": 2, "method": "shutdown"}),
+    );
+    loop {
+        if receive(&mut stdout)["id"] == 2 {
+            break;
+        }
+    }
+    send(&mut stdin, json!({"jsonrpc": "2.0", "method": "exit"}));
+    drop(stdin);
+
+    assert!(child.wait().unwrap().success());
+}
@@ -0,0 +1,203 @@
+//! Integration tests for `spox search` command.
+//!
+//! All tests use the offline hashing backend, so no embedding model is needed.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a project with the hashing backend and two specs.
+fn create_project(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+    fs::write(
+        spox_dir.join("config.toml"),
+        r#"[paths]
+spec_folder = "specs/"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+
+[search]
+backend = "hashing"
+"#,
+    )
+    .unwrap();
+
+    create_spec(
+        root,
+        "auth",
+        "Login",
+        "The system SHALL start a session on login.",
+    );
+    create_spec(
+        root,
+        "cli",
+        "Logout",
+        "The system SHALL end the session on logout.",
+    );
+}
+
+/// Helper to create a spec with one requirement
+fn create_spec(root: &std::path::Path, name: &str, requirement: &str, description: &str) {
+    let spec_dir = root.join("specs").join(name);
+    fs::create_dir_all(&spec_dir).unwrap();
+
+    let content = format!(
+        r#"# {} Specification
+
+## Purpose
+
+This spec defines {} functionality.
+
+## Requirements
+
+### Requirement: {}
+
+{}
+
+#### Scenario: Basic operation
+
+- **WHEN** user requests operation
+- **THEN** operation completes
+"#,
+        name, name, requirement, description
+    );
+    fs::write(spec_dir.join("spec.md"), content).unwrap();
+}
+
+// =============================================================================
+// Test: spox search text output
+// =============================================================================
+
+#[test]
+fn test_search_prints_ranked_results() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_project(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["search", "logout", "--mode", "keyword"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Built search index"))
+        .stdout(predicate::str::starts_with("1. cli / Logout  ("))
+        .stdout(predicate::str::contains(
+            "The system SHALL end the session on logout.",
+        ));
+}
+
+#[test]
+fn test_search_without_matches() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_project(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["search", "payments", "--mode", "keyword"])
+        .assert()
+        .success()
+        .stdout("No results found.\n");
+}
+
+#[test]
+fn test_search_updates_index_after_spec_edit() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_project(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["search", "passkey", "--mode", "keyword"])
+        .assert()
+        .success()
+        .stdout("No results found.\n");
+
+    create_spec(
+        root,
+        "auth",
+        "Login",
+        "The system SHALL start a session on passkey login.",
+    );
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["search", "passkey", "--mode", "keyword"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Updated search index"))
+        .stdout(predicate::str::starts_with("1. auth / Login  ("));
+}
+
+// =============================================================================
+// Test: spox search --spec
+// =============================================================================
+
+#[test]
+fn test_search_restricted_to_spec() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_project(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["search", "session", "--mode", "keyword", "--spec", "auth"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("auth / Login"))
+        .stdout(predicate::str::contains("cli").not());
+}
+
+// =============================================================================
+// Test: spox search --json
+// =============================================================================
+
+#[test]
+fn test_search_json() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_project(root);
+
+    let output = spox_cmd()
+        .current_dir(root)
+        .args([
+            "search", "session", "--mode", "keyword", "--top-k", "1", "--json",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let results = json["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["kind"], "requirement");
+    assert!(results[0]["score"].as_f64().unwrap() > 0.0);
+    assert!(json["total_matches"].as_u64().unwrap() >= 2);
+    assert_eq!(json["index_built"], true);
+}
+
+#[test]
+fn test_search_unknown_mode_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_project(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["search", "session", "--mode", "fuzzy"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("fuzzy"));
+}
@@ -0,0 +1,147 @@
+//! Integration tests for `spox task done` command.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a minimal .spox/config.toml
+fn create_config(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+
+    let config = r#"[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#;
+    fs::write(spox_dir.join("config.toml"), config).unwrap();
+}
+
+const TASKS: &str = r#"# Tasks
+
+## 1. Implementation
+- [x] 1.1 Add config
+- [ ] 1.2 Add command
+  - [ ] 1.2.1 Parse arguments
+"#;
+
+/// Helper to create a change with a tasks.md
+fn create_change(root: &std::path::Path, name: &str) -> std::path::PathBuf {
+    let change_dir = root.join("specs/_changes").join(name);
+    fs::create_dir_all(&change_dir).unwrap();
+    fs::write(change_dir.join("proposal.md"), "# Change: Add 2FA\n").unwrap();
+    fs::write(change_dir.join("tasks.md"), TASKS).unwrap();
+    change_dir
+}
+
+// =============================================================================
+// Test: spox task done checks off the task
+// =============================================================================
+
+#[test]
+fn test_task_done_checks_off_task() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+    let change_dir = create_change(root, "add-2fa");
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["task", "done", "add-2fa", "1.2.1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Marked task 1.2.1 of add-2fa as done (2/3 tasks completed)",
+        ));
+
+    assert_eq!(
+        fs::read_to_string(change_dir.join("tasks.md")).unwrap(),
+        TASKS.replace("- [ ] 1.2.1", "- [x] 1.2.1")
+    );
+}
+
+#[test]
+fn test_task_done_json() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+    create_change(root, "add-2fa");
+
+    let output = spox_cmd()
+        .current_dir(root)
+        .args(["--format", "json", "task", "done", "add-2fa", "1.2"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["task"], "1.2");
+    assert_eq!(json["done"], true);
+    assert_eq!(json["task_progress"]["completed"], 2);
+    assert_eq!(json["task_progress"]["total"], 3);
+}
+
+// =============================================================================
+// Test: spox task done fails for unknown tasks and changes
+// =============================================================================
+
+#[test]
+fn test_task_done_unknown_task() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+    let change_dir = create_change(root, "add-2fa");
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["task", "done", "add-2fa", "2.1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Task '2.1' not found"));
+
+    assert_eq!(
+        fs::read_to_string(change_dir.join("tasks.md")).unwrap(),
+        TASKS
+    );
+}
+
+#[test]
+fn test_task_done_unknown_change() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["task", "done", "add-2fa", "1.1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Change 'add-2fa' not found"));
+}
+
+#[test]
+fn test_task_done_change_without_tasks() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+    let change_dir = create_change(root, "add-2fa");
+    fs::remove_file(change_dir.join("tasks.md")).unwrap();
+
+    spox_cmd()
+        .current_dir(root)
+        .args(["task", "done", "add-2fa", "1.1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Change 'add-2fa' has no tasks.md"));
+}
@@ -0,0 +1,177 @@
+//! Integration tests for SARIF and JUnit XML output of the validate commands.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a minimal .spox/config.toml
+fn create_config(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+
+    let config = r#"[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#;
+    fs::write(spox_dir.join("config.toml"), config).unwrap();
+}
+
+const AUTH_SPEC: &str = r#"# auth Specification
+
+## Purpose
+This spec defines authentication requirements for the system.
+
+## Requirements
+
+### Requirement: User Login
+The system SHALL allow users to login.
+
+#### Scenario: Successful login
+- **WHEN** user provides valid credentials
+- **THEN** user is authenticated
+"#;
+
+/// A spec without a Requirements section.
+const BROKEN_SPEC: &str = r#"# billing Specification
+
+## Purpose
+This spec defines billing requirements for the system.
+"#;
+
+/// Create a project with a valid spec, an invalid spec and one valid change.
+fn create_project(root: &std::path::Path) {
+    create_config(root);
+
+    let auth_dir = root.join("specs/auth");
+    fs::create_dir_all(&auth_dir).unwrap();
+    fs::write(auth_dir.join("spec.md"), AUTH_SPEC).unwrap();
+
+    let billing_dir = root.join("specs/billing");
+    fs::create_dir_all(&billing_dir).unwrap();
+    fs::write(billing_dir.join("spec.md"), BROKEN_SPEC).unwrap();
+
+    let change_dir = root.join("specs/_changes/add-2fa");
+    fs::create_dir_all(change_dir.join("specs/auth")).unwrap();
+    fs::write(
+        change_dir.join("proposal.md"),
+        "# Change: Add 2FA\n\n## Why\nAccounts need a second factor.\n\n## What Changes\n- Add OTP check\n",
+    )
+    .unwrap();
+    fs::write(
+        change_dir.join("tasks.md"),
+        "## 1. Implementation\n- [ ] 1.1 Add OTP\n",
+    )
+    .unwrap();
+    fs::write(
+        change_dir.join("specs/auth/spec.md"),
+        "## ADDED Requirements\n\n### Requirement: Two-Factor\nThe system SHALL ask for a code.\n\n#### Scenario: Code prompt\n- **WHEN** user logs in\n- **THEN** a code is requested\n",
+    )
+    .unwrap();
+}
+
+// =============================================================================
+// Test: SARIF output
+// =============================================================================
+
+#[test]
+fn test_spec_validate_sarif() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let output = spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["spec", "validate", "--output", "sarif"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let log: Value = serde_json::from_slice(&output.stdout).expect("stdout is valid JSON");
+    assert_eq!(log["version"], "2.1.0");
+
+    let results = log["runs"][0]["results"].as_array().unwrap();
+    assert!(!results.is_empty());
+    assert!(results.iter().all(|r| {
+        r["locations"][0]["physicalLocation"]["artifactLocation"]["uri"] == "specs/billing/spec.md"
+    }));
+    assert!(results.iter().any(|r| r["level"] == "error"));
+}
+
+#[test]
+fn test_single_spec_sarif_passes() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let output = spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["spec", "validate", "auth", "--output", "sarif"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let log: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(log["runs"][0]["results"].as_array().unwrap().is_empty());
+}
+
+// =============================================================================
+// Test: JUnit output
+// =============================================================================
+
+#[test]
+fn test_spec_validate_junit_one_testcase_per_spec() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let output = spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["spec", "validate", "--output", "junit"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let xml = String::from_utf8(output.stdout).unwrap();
+    assert!(xml.contains("tests=\"2\" failures=\"1\""));
+    assert!(xml.contains("<testcase classname=\"spec\" name=\"auth\"/>"));
+    assert!(xml.contains("<testcase classname=\"spec\" name=\"billing\">"));
+    assert!(xml.contains("ERROR specs/billing/spec.md"));
+}
+
+#[test]
+fn test_change_validate_junit() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    let output = spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["change", "validate", "--output", "junit"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let xml = String::from_utf8(output.stdout).unwrap();
+    assert!(xml.contains("<testsuite name=\"spox change validate\" tests=\"1\" failures=\"0\""));
+    assert!(xml.contains("name=\"add-2fa\""));
+}
+
+#[test]
+fn test_change_validate_missing_change_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    create_project(temp_dir.path());
+
+    spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["change", "validate", "missing", "--output", "sarif"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("not found"));
+}
@@ -0,0 +1,176 @@
+//! Integration tests for the `--watch` mode of the validate and show commands.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+/// How long to wait for watch output before failing.
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Helper function to create a Command for the spox binary.
+fn spox_cmd() -> Command {
+    cargo_bin_cmd!("spox")
+}
+
+/// Helper to create a minimal .spox/config.toml
+fn create_config(root: &std::path::Path) {
+    let spox_dir = root.join(".spox");
+    fs::create_dir_all(&spox_dir).unwrap();
+
+    let config = r#"[paths]
+spec_folder = "specs"
+changes_folder = "specs/_changes"
+archive_folder = "specs/_archive"
+
+[rules]
+system = ["mcp"]
+"#;
+    fs::write(spox_dir.join("config.toml"), config).unwrap();
+}
+
+const AUTH_SPEC: &str = r#"# auth Specification
+
+## Purpose
+This spec defines authentication requirements for the system.
+
+## Requirements
+
+### Requirement: User Login
+The system SHALL allow users to login.
+
+#### Scenario: Successful login
+- **WHEN** user provides valid credentials
+- **THEN** user is authenticated
+"#;
+
+/// A spec without a Requirements section.
+const BROKEN_SPEC: &str = r#"# auth Specification
+
+## Purpose
+This spec defines authentication requirements for the system.
+"#;
+
+/// A running watch process whose stdout lines are read on a thread.
+struct Watch {
+    child: Child,
+    lines: Receiver<String>,
+}
+
+impl Watch {
+    fn spawn(root: &std::path::Path, args: &[&str]) -> Self {
+        let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin!("spox"))
+            .current_dir(root)
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let (tx, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines().map_while(|l| l.ok()) {
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self { child, lines }
+    }
+
+    /// Read lines until one contains `text`, panicking after the timeout.
+    fn expect(&self, text: &str) {
+        let deadline = Instant::now() + TIMEOUT;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.lines.recv_timeout(remaining) {
+                Ok(line) if line.contains(text) => return,
+                Ok(_) => {}
+                Err(_) => panic!("timed out waiting for {:?}", text),
+            }
+        }
+    }
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+// =============================================================================
+// Test: spec validate --watch
+// =============================================================================
+
+#[test]
+fn test_spec_validate_watch_revalidates_changed_spec() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+    let spec_path = root.join("specs/auth/spec.md");
+    fs::create_dir_all(spec_path.parent().unwrap()).unwrap();
+    fs::write(&spec_path, AUTH_SPEC).unwrap();
+
+    let watch = Watch::spawn(root, &["spec", "validate", "--watch"]);
+    watch.expect("PASS");
+    watch.expect("Watching for changes");
+
+    fs::write(&spec_path, BROKEN_SPEC).unwrap();
+    watch.expect("FAIL");
+    watch.expect("Watching for changes");
+}
+
+#[test]
+fn test_validate_watch_conflicts_with_output() {
+    let temp_dir = TempDir::new().unwrap();
+    create_config(temp_dir.path());
+
+    spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["spec", "validate", "--watch", "--output", "sarif"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_validate_watch_rejects_json_format() {
+    let temp_dir = TempDir::new().unwrap();
+    create_config(temp_dir.path());
+
+    spox_cmd()
+        .current_dir(temp_dir.path())
+        .args(["--format", "json", "change", "validate", "--watch"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--watch"));
+}
+
+// =============================================================================
+// Test: show --watch
+// =============================================================================
+
+#[test]
+fn test_show_watch_redraws_dashboard() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    create_config(root);
+    fs::create_dir_all(root.join("specs")).unwrap();
+
+    let watch = Watch::spawn(root, &["show", "--watch"]);
+    watch.expect("Watching for changes");
+
+    let spec_path = root.join("specs/auth/spec.md");
+    fs::create_dir_all(spec_path.parent().unwrap()).unwrap();
+    fs::write(&spec_path, AUTH_SPEC).unwrap();
+    watch.expect("auth");
+}